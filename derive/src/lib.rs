@@ -0,0 +1,127 @@
+//! Derive macros for `odbc-api`. Use them via the `derive` feature of `odbc-api` rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Lit, Meta, NestedMeta,
+};
+
+/// Implements `odbc_api::FromRow` for a struct with named fields. Each field is read from the
+/// column with the same name, unless a different name is specified with `#[odbc(name = "...")]`.
+#[proc_macro_derive(FromRow, attributes(odbc))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_row(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_row(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "FromRow can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "FromRow can only be derived for structs",
+            ))
+        }
+    };
+
+    let idents: Vec<_> = fields.iter().map(|f| f.ident.clone().unwrap()).collect();
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let column_names = fields
+        .iter()
+        .map(|f| {
+            column_name(f).map(|name| name.unwrap_or_else(|| f.ident.as_ref().unwrap().to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let indices = 0..fields.len();
+    let buffer_indices = 0..fields.len();
+    let columns: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("column_{}", index))
+        .collect();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::odbc_api::FromRow for #name #ty_generics #where_clause {
+            const COLUMN_NAMES: &'static [&'static str] = &[#(#column_names),*];
+
+            fn buffer_kinds(
+                cursor: &impl ::odbc_api::ResultSetMetadata,
+                column_numbers: &[u16],
+            ) -> ::std::result::Result<
+                ::std::vec::Vec<::odbc_api::buffers::BufferKind>,
+                ::odbc_api::Error,
+            > {
+                ::std::result::Result::Ok(::std::vec![
+                    #(
+                        <#types as ::odbc_api::FromColumn>::buffer_kind(
+                            cursor,
+                            column_numbers[#indices],
+                        )?
+                    ),*
+                ])
+            }
+
+            fn from_rows(
+                buffer: &::odbc_api::buffers::ColumnarBuffer<::odbc_api::buffers::AnyColumnBuffer>,
+            ) -> ::std::result::Result<::std::vec::Vec<Self>, ::odbc_api::Error> {
+                #(
+                    let mut #columns = <#types as ::odbc_api::FromColumn>::from_column(
+                        buffer.column(#buffer_indices),
+                        Self::COLUMN_NAMES[#buffer_indices],
+                    )?
+                    .into_iter();
+                )*
+                ::std::result::Result::Ok(
+                    (0..buffer.num_rows())
+                        .map(|_| Self {
+                            #( #idents: #columns.next().unwrap() ),*
+                        })
+                        .collect()
+                )
+            }
+        }
+    })
+}
+
+/// Column name specified with `#[odbc(name = "...")]`, if any.
+fn column_name(field: &syn::Field) -> Result<Option<String>, Error> {
+    let mut name = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("odbc")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            other => return Err(Error::new(other.span(), "expected #[odbc(name = \"...\")]")),
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("name") => {
+                    if let Lit::Str(lit) = pair.lit {
+                        name = Some(lit.value());
+                    } else {
+                        return Err(Error::new(pair.lit.span(), "column name must be a string"));
+                    }
+                }
+                other => {
+                    return Err(Error::new(
+                        other.span(),
+                        "unknown attribute, expected `name = \"...\"`",
+                    ))
+                }
+            }
+        }
+    }
+    Ok(name)
+}
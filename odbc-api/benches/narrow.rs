@@ -0,0 +1,60 @@
+//! Compares executing statements with the default UTF-16 statement text, with passing the statement
+//! text as UTF-8 to the narrow ODBC functions (see [`Connection::set_narrow_query_text`]).
+//!
+//! Requires a running database. Execute with `cargo bench --bench narrow`. The Microsoft SQL
+//! Server instance of the integration tests is used, unless another connection string is specified
+//! using the `ODBC_BENCH_CONNECTION_STRING` environment variable.
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use odbc_api::{Connection, Environment, Error};
+
+const MSSQL_CONNECTION: &str =
+    "Driver={ODBC Driver 17 for SQL Server};Server=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;";
+
+const TABLE_NAME: &str = "BenchNarrow";
+const NUM_STATEMENTS: usize = 10_000;
+
+fn main() -> Result<(), Error> {
+    let connection_string =
+        env::var("ODBC_BENCH_CONNECTION_STRING").unwrap_or_else(|_| MSSQL_CONNECTION.to_owned());
+    let environment = Environment::new()?;
+    let mut conn = environment.connect_with_connection_string(&connection_string)?;
+
+    conn.set_narrow_query_text(false);
+    let wide = insert_rows(&conn)?;
+    conn.set_narrow_query_text(true);
+    let narrow = insert_rows(&conn)?;
+
+    println!(
+        "Executed {} statements.\nWide (UTF-16): {:?}\nNarrow (UTF-8): {:?}",
+        NUM_STATEMENTS, wide, narrow
+    );
+    Ok(())
+}
+
+/// Creates an empty table and executes one `INSERT` statement for each row, so the time is
+/// dominated by passing statement text to the driver.
+fn insert_rows(conn: &Connection<'_>) -> Result<Duration, Error> {
+    conn.execute(&format!("DROP TABLE IF EXISTS {}", TABLE_NAME), ())?;
+    conn.execute(
+        &format!("CREATE TABLE {} (a INTEGER, b VARCHAR(50))", TABLE_NAME),
+        (),
+    )?;
+    let statements: Vec<String> = (0..NUM_STATEMENTS)
+        .map(|id| {
+            format!(
+                "INSERT INTO {} (a, b) VALUES ({}, 'Row number {}')",
+                TABLE_NAME, id, id
+            )
+        })
+        .collect();
+    let start = Instant::now();
+    for statement in &statements {
+        conn.execute(statement, ())?;
+    }
+    Ok(start.elapsed())
+}
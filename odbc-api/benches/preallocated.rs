@@ -0,0 +1,64 @@
+//! Compares executing many ad-hoc queries with [`Connection::execute`], which allocates a new
+//! statement handle for each query, with executing them on a single statement handle using
+//! [`Preallocated::execute_and_reset`].
+//!
+//! Requires a running database. Execute with `cargo bench --bench preallocated`. The Microsoft SQL
+//! Server instance of the integration tests is used, unless another connection string is specified
+//! using the `ODBC_BENCH_CONNECTION_STRING` environment variable.
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use odbc_api::{Connection, Cursor, Environment, Error, Preallocated};
+
+const MSSQL_CONNECTION: &str =
+    "Driver={ODBC Driver 17 for SQL Server};Server=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;";
+
+const NUM_QUERIES: usize = 10_000;
+const QUERY: &str = "SELECT ?";
+
+fn main() -> Result<(), Error> {
+    let connection_string =
+        env::var("ODBC_BENCH_CONNECTION_STRING").unwrap_or_else(|_| MSSQL_CONNECTION.to_owned());
+    let environment = Environment::new()?;
+    let conn = environment.connect_with_connection_string(&connection_string)?;
+
+    let allocating = query_allocating(&conn)?;
+    let preallocated = query_preallocated(&conn)?;
+
+    println!(
+        "Executed {} queries.\nNew statement handle each query: {:?}\nOne preallocated statement \
+        handle: {:?}",
+        NUM_QUERIES, allocating, preallocated
+    );
+    Ok(())
+}
+
+/// Allocates and frees a statement handle for each query.
+fn query_allocating(conn: &Connection<'_>) -> Result<Duration, Error> {
+    let start = Instant::now();
+    for id in 0..NUM_QUERIES as i32 {
+        let cursor = conn.execute(QUERY, &id)?.unwrap();
+        consume(cursor)?;
+    }
+    Ok(start.elapsed())
+}
+
+/// Executes all queries on the same statement handle, resetting it in between.
+fn query_preallocated(conn: &Connection<'_>) -> Result<Duration, Error> {
+    let mut statement: Preallocated<'_> = conn.preallocate()?;
+    let start = Instant::now();
+    for id in 0..NUM_QUERIES as i32 {
+        statement.execute_and_reset(QUERY, &id, |cursor| consume(cursor.unwrap()))?;
+    }
+    Ok(start.elapsed())
+}
+
+/// Fetches the single value of the result set, so both variants do the same work per query.
+fn consume(mut cursor: impl Cursor) -> Result<(), Error> {
+    let value: Option<i32> = cursor.fetch_one_scalar()?;
+    assert!(value.is_some());
+    Ok(())
+}
@@ -0,0 +1,109 @@
+//! Compares inserting rows in batches with a prepared statement, which binds the parameter buffer
+//! anew for each execution, with binding the buffer only once using [`Prepared::bind_parameters`].
+//!
+//! Requires a running database. Execute with `cargo bench --bench prebound`. The Microsoft SQL
+//! Server instance of the integration tests is used, unless another connection string is specified
+//! using the `ODBC_BENCH_CONNECTION_STRING` environment variable.
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use odbc_api::{
+    buffers::{
+        buffer_from_description, AnyColumnBuffer, AnyColumnViewMut, BufferDescription, BufferKind,
+        ColumnarBuffer,
+    },
+    Connection, Environment, Error, Prepared,
+};
+
+const MSSQL_CONNECTION: &str =
+    "Driver={ODBC Driver 17 for SQL Server};Server=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;";
+
+const TABLE_NAME: &str = "BenchPrebound";
+const NUM_ROWS: usize = 100_000;
+const BATCH_SIZE: usize = 1_000;
+
+fn main() -> Result<(), Error> {
+    let connection_string =
+        env::var("ODBC_BENCH_CONNECTION_STRING").unwrap_or_else(|_| MSSQL_CONNECTION.to_owned());
+    let environment = Environment::new()?;
+    let conn = environment.connect_with_connection_string(&connection_string)?;
+
+    let rebinding = insert_rebinding(&conn)?;
+    let prebound = insert_prebound(&conn)?;
+
+    println!(
+        "Inserted {} rows in batches of {}.\nRebinding each execution: {:?}\nPrebound: {:?}",
+        NUM_ROWS, BATCH_SIZE, rebinding, prebound
+    );
+    Ok(())
+}
+
+/// Binds the parameter buffer anew, each time the prepared statement is executed.
+fn insert_rebinding(conn: &Connection<'_>) -> Result<Duration, Error> {
+    let mut prepared = prepare_insert(conn)?;
+    let mut buffer = parameter_buffer();
+    let start = Instant::now();
+    for first_id in (0..NUM_ROWS).step_by(BATCH_SIZE) {
+        fill_batch(&mut buffer, first_id);
+        prepared.execute(&buffer)?;
+    }
+    Ok(start.elapsed())
+}
+
+/// Binds the parameter buffer once and only refills it between executions.
+fn insert_prebound(conn: &Connection<'_>) -> Result<Duration, Error> {
+    let prepared = prepare_insert(conn)?;
+    let mut buffer = parameter_buffer();
+    let mut prebound = prepared.bind_parameters(&mut buffer)?;
+    let start = Instant::now();
+    for first_id in (0..NUM_ROWS).step_by(BATCH_SIZE) {
+        fill_batch(prebound.params_mut(), first_id);
+        prebound.execute()?;
+    }
+    Ok(start.elapsed())
+}
+
+/// Creates an empty table and prepares a statement inserting into it.
+fn prepare_insert<'c>(conn: &'c Connection<'_>) -> Result<Prepared<'c>, Error> {
+    conn.execute(&format!("DROP TABLE IF EXISTS {}", TABLE_NAME), ())?;
+    conn.execute(
+        &format!("CREATE TABLE {} (a INTEGER, b VARCHAR(20))", TABLE_NAME),
+        (),
+    )?;
+    conn.prepare(&format!("INSERT INTO {} (a, b) VALUES (?, ?)", TABLE_NAME))
+}
+
+fn parameter_buffer() -> ColumnarBuffer<AnyColumnBuffer> {
+    buffer_from_description(
+        BATCH_SIZE,
+        [
+            BufferDescription {
+                kind: BufferKind::I32,
+                nullable: false,
+            },
+            BufferDescription {
+                kind: BufferKind::Text { max_str_len: 20 },
+                nullable: true,
+            },
+        ]
+        .into_iter(),
+    )
+}
+
+fn fill_batch(buffer: &mut ColumnarBuffer<AnyColumnBuffer>, first_id: usize) {
+    buffer.set_num_rows(BATCH_SIZE);
+    if let AnyColumnViewMut::I32(ids) = buffer.column_mut(0) {
+        for (row_index, id) in ids.iter_mut().enumerate() {
+            *id = (first_id + row_index) as i32;
+        }
+    }
+    if let AnyColumnViewMut::Text(mut texts) = buffer.column_mut(1) {
+        for row_index in 0..BATCH_SIZE {
+            let text = format!("Row {}", first_id + row_index);
+            texts.set_value(row_index, Some(text.as_bytes()));
+        }
+    }
+}
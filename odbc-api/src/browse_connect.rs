@@ -0,0 +1,104 @@
+use std::thread::panicking;
+
+use widestring::U16String;
+
+use crate::{
+    handles::{self, OutputStringBuffer},
+    Connection, Error,
+};
+
+/// Maximum length of the connection string returned by `SQLBrowseConnect` in characters. Calling
+/// it a second time with the same input is not an option to recover from truncation, since every
+/// call advances the browsing process. So we just pick a generous size and report truncation as an
+/// error.
+const BROWSE_RESULT_STRING_LEN: usize = 4096;
+
+/// Outcome of a single step of browsing for the attributes required to connect to a data source.
+/// See [`crate::Environment::browse_connect`].
+pub enum BrowseConnect<'c> {
+    /// The driver requires more attributes before it can establish the connection.
+    NeedData(BrowseConnection<'c>),
+    /// All required attributes have been specified and the connection is established.
+    Connected(Connection<'c>),
+}
+
+/// A connection handle in the process of discovering the attributes required to connect to a data
+/// source. Created by [`crate::Environment::browse_connect`]. Dropping it ends the browsing
+/// process without connecting.
+pub struct BrowseConnection<'c> {
+    /// Always `Some`, until ownership of the handle is transferred to the next step.
+    connection: Option<handles::Connection<'c>>,
+    /// Attributes the driver requires (or accepts) on the next level.
+    attributes: String,
+}
+
+impl<'c> BrowseConnection<'c> {
+    /// Performs one step of `SQLBrowseConnect` on a freshly allocated connection handle, or on a
+    /// handle which is already browsing.
+    pub(crate) fn step(
+        mut connection: handles::Connection<'c>,
+        connection_string: &str,
+    ) -> Result<BrowseConnect<'c>, Error> {
+        let connection_string = U16String::from_str(connection_string);
+        let mut out = OutputStringBuffer::with_buffer_size(BROWSE_RESULT_STRING_LEN);
+        // On error the handle falls back into the allocated state, so it can simply be freed.
+        let need_data = connection
+            .browse_connect(&connection_string, &mut out)
+            .into_result(&connection)?;
+        if need_data {
+            let browse_connection = BrowseConnection {
+                connection: Some(connection),
+                attributes: out.to_utf8(),
+            };
+            if out.is_truncated() {
+                // Dropping `browse_connection` ends the browsing process.
+                return Err(Error::BrowseConnectAttributesTruncated {
+                    max_len: BROWSE_RESULT_STRING_LEN,
+                });
+            }
+            Ok(BrowseConnect::NeedData(browse_connection))
+        } else {
+            Ok(BrowseConnect::Connected(Connection::new(connection)))
+        }
+    }
+
+    /// Attributes the driver asks for in order to advance the connection process. The string
+    /// consists of `;` separated entries with the syntax `[*]KEYWORD[:Prompt]=?` or
+    /// `[*]KEYWORD[:Prompt]={choice1,choice2,...}`. E.g.:
+    ///
+    /// ```text
+    /// SERVER:Server={localhost,db.example.com};UID:Login ID=?;PWD:Password=?;*DATABASE:Database=?
+    /// ```
+    ///
+    /// * `KEYWORD` is the attribute name to use in the connection string passed to [`Self::browse`].
+    /// * `Prompt` is a user friendly, localized description of the attribute.
+    /// * `?` indicates that any value may be specified, curly braces list the valid choices.
+    /// * A leading asterisk (`*`) marks an attribute as optional.
+    pub fn attributes(&self) -> &str {
+        &self.attributes
+    }
+
+    /// Specifies values for the attributes requested by [`Self::attributes`], using the usual
+    /// connection string syntax (e.g. `UID=SA;PWD=secret;`). Attributes specified in earlier steps
+    /// need not be repeated.
+    pub fn browse(mut self, connection_string: &str) -> Result<BrowseConnect<'c>, Error> {
+        let connection = self.connection.take().unwrap();
+        Self::step(connection, connection_string)
+    }
+}
+
+impl<'c> Drop for BrowseConnection<'c> {
+    fn drop(&mut self) {
+        // A connection handle which is still browsing, must be disconnected before it can be
+        // freed.
+        if let Some(connection) = &mut self.connection {
+            if let Err(e) = connection.disconnect().into_result(connection) {
+                // Avoid panicking, if we already have a panic. We don't want to mask the original
+                // error.
+                if !panicking() {
+                    panic!("Unexpected error ending browse connect: {:?}", e)
+                }
+            }
+        }
+    }
+}
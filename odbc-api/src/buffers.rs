@@ -1,18 +1,22 @@
 //! This module contains buffers intended to be bound to ODBC statement handles.
 
 mod any_column_buffer;
+#[cfg(feature = "arrow")]
+mod arrow;
 mod bin_column;
 mod column_with_indicator;
 mod columnar;
 mod description;
 mod indicator;
 mod item;
+mod max_str_len;
 mod text_column;
+mod value;
 
 pub use self::{
     any_column_buffer::{
         buffer_from_description, buffer_from_description_and_indices, AnyColumnBuffer,
-        AnyColumnView, AnyColumnViewMut,
+        AnyColumnView, AnyColumnViewMut, BoolColumnIt, Utf8ColumnIt,
     },
     bin_column::{BinColumn, BinColumnIt, BinColumnWriter},
     column_with_indicator::{NullableSlice, NullableSliceMut},
@@ -20,6 +24,10 @@ pub use self::{
     description::{BufferDescription, BufferKind},
     indicator::Indicator,
     item::Item,
+    max_str_len::MaxStrLen,
     text_column::{CharColumn, TextColumn, TextColumnIt, TextColumnWriter, WCharColumn},
+    value::{Rows, Value},
     // text_row_set::TextRowSet,
 };
+#[cfg(feature = "arrow")]
+pub use self::arrow::{arrow_data_type, arrow_schema, to_arrow_array, to_record_batch};
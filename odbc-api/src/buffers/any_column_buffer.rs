@@ -1,21 +1,22 @@
-use std::{collections::HashSet, ffi::c_void};
+use std::{borrow::Cow, char::decode_utf16, collections::HashSet, ffi::c_void};
 
-use odbc_sys::{CDataType, Date, Time, Timestamp};
+use odbc_sys::{CDataType, Date, SqlDataType, Time, Timestamp};
 
 use crate::{
     handles::{CData, CDataMut, HasDataType},
-    Bit, DataType,
+    Bit, DataType, Guid,
 };
 
 use super::{
     column_with_indicator::{
-        OptBitColumn, OptDateColumn, OptF32Column, OptF64Column, OptI16Column, OptI32Column,
-        OptI64Column, OptI8Column, OptTimeColumn, OptTimestampColumn, OptU8Column,
+        OptBitColumn, OptDateColumn, OptF32Column, OptF64Column, OptGuidColumn, OptI16Column,
+        OptI32Column, OptI64Column, OptI8Column, OptTimeColumn, OptTimestampColumn, OptU16Column,
+        OptU32Column, OptU64Column, OptU8Column,
     },
     columnar::{ColumnBuffer, ColumnProjections},
     BinColumn, BinColumnIt, BinColumnWriter, BufferDescription, BufferKind, CharColumn,
-    ColumnarBuffer, NullableSlice, NullableSliceMut, TextColumn, TextColumnIt, TextColumnWriter,
-    WCharColumn,
+    ColumnarBuffer, Indicator, Item, NullableSlice, NullableSliceMut, TextColumn, TextColumnIt,
+    TextColumnWriter, WCharColumn,
 };
 
 /// Since buffer shapes are same for all time / timestamps independent of the precision and we do
@@ -43,7 +44,11 @@ pub enum AnyColumnBuffer {
     I32(Vec<i32>),
     I64(Vec<i64>),
     U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+    U64(Vec<u64>),
     Bit(Vec<Bit>),
+    Guid(Vec<Guid>),
     NullableDate(OptDateColumn),
     NullableTime(OptTimeColumn),
     NullableTimestamp(OptTimestampColumn),
@@ -54,7 +59,11 @@ pub enum AnyColumnBuffer {
     NullableI32(OptI32Column),
     NullableI64(OptI64Column),
     NullableU8(OptU8Column),
+    NullableU16(OptU16Column),
+    NullableU32(OptU32Column),
+    NullableU64(OptU64Column),
     NullableBit(OptBitColumn),
+    NullableGuid(OptGuidColumn),
 }
 
 impl AnyColumnBuffer {
@@ -96,9 +105,21 @@ impl AnyColumnBuffer {
                 AnyColumnBuffer::I64(vec![i64::default(); max_rows as usize])
             }
             (BufferKind::U8, false) => AnyColumnBuffer::U8(vec![u8::default(); max_rows as usize]),
+            (BufferKind::U16, false) => {
+                AnyColumnBuffer::U16(vec![u16::default(); max_rows as usize])
+            }
+            (BufferKind::U32, false) => {
+                AnyColumnBuffer::U32(vec![u32::default(); max_rows as usize])
+            }
+            (BufferKind::U64, false) => {
+                AnyColumnBuffer::U64(vec![u64::default(); max_rows as usize])
+            }
             (BufferKind::Bit, false) => {
                 AnyColumnBuffer::Bit(vec![Bit::default(); max_rows as usize])
             }
+            (BufferKind::Guid, false) => {
+                AnyColumnBuffer::Guid(vec![Guid::default(); max_rows as usize])
+            }
             (BufferKind::Date, true) => {
                 AnyColumnBuffer::NullableDate(OptDateColumn::new(max_rows as usize))
             }
@@ -129,9 +150,21 @@ impl AnyColumnBuffer {
             (BufferKind::U8, true) => {
                 AnyColumnBuffer::NullableU8(OptU8Column::new(max_rows as usize))
             }
+            (BufferKind::U16, true) => {
+                AnyColumnBuffer::NullableU16(OptU16Column::new(max_rows as usize))
+            }
+            (BufferKind::U32, true) => {
+                AnyColumnBuffer::NullableU32(OptU32Column::new(max_rows as usize))
+            }
+            (BufferKind::U64, true) => {
+                AnyColumnBuffer::NullableU64(OptU64Column::new(max_rows as usize))
+            }
             (BufferKind::Bit, true) => {
                 AnyColumnBuffer::NullableBit(OptBitColumn::new(max_rows as usize))
             }
+            (BufferKind::Guid, true) => {
+                AnyColumnBuffer::NullableGuid(OptGuidColumn::new(max_rows as usize))
+            }
         }
     }
 
@@ -157,7 +190,11 @@ impl AnyColumnBuffer {
             AnyColumnBuffer::I32(col) => col,
             AnyColumnBuffer::I64(col) => col,
             AnyColumnBuffer::Bit(col) => col,
+            AnyColumnBuffer::Guid(col) => col,
             AnyColumnBuffer::U8(col) => col,
+            AnyColumnBuffer::U16(col) => col,
+            AnyColumnBuffer::U32(col) => col,
+            AnyColumnBuffer::U64(col) => col,
             AnyColumnBuffer::NullableF64(col) => col,
             AnyColumnBuffer::NullableF32(col) => col,
             AnyColumnBuffer::NullableDate(col) => col,
@@ -168,7 +205,11 @@ impl AnyColumnBuffer {
             AnyColumnBuffer::NullableI32(col) => col,
             AnyColumnBuffer::NullableI64(col) => col,
             AnyColumnBuffer::NullableBit(col) => col,
+            AnyColumnBuffer::NullableGuid(col) => col,
             AnyColumnBuffer::NullableU8(col) => col,
+            AnyColumnBuffer::NullableU16(col) => col,
+            AnyColumnBuffer::NullableU32(col) => col,
+            AnyColumnBuffer::NullableU64(col) => col,
         }
     }
 
@@ -187,7 +228,11 @@ impl AnyColumnBuffer {
             AnyColumnBuffer::I32(col) => col,
             AnyColumnBuffer::I64(col) => col,
             AnyColumnBuffer::Bit(col) => col,
+            AnyColumnBuffer::Guid(col) => col,
             AnyColumnBuffer::U8(col) => col,
+            AnyColumnBuffer::U16(col) => col,
+            AnyColumnBuffer::U32(col) => col,
+            AnyColumnBuffer::U64(col) => col,
             AnyColumnBuffer::NullableF64(col) => col,
             AnyColumnBuffer::NullableF32(col) => col,
             AnyColumnBuffer::NullableDate(col) => col,
@@ -198,7 +243,11 @@ impl AnyColumnBuffer {
             AnyColumnBuffer::NullableI32(col) => col,
             AnyColumnBuffer::NullableI64(col) => col,
             AnyColumnBuffer::NullableBit(col) => col,
+            AnyColumnBuffer::NullableGuid(col) => col,
             AnyColumnBuffer::NullableU8(col) => col,
+            AnyColumnBuffer::NullableU16(col) => col,
+            AnyColumnBuffer::NullableU32(col) => col,
+            AnyColumnBuffer::NullableU64(col) => col,
         }
     }
 }
@@ -256,7 +305,21 @@ impl HasDataType for AnyColumnBuffer {
             // stuff if the database has type is signed. I guess. Let's bind it as SmallInt by
             // default, just to be on the safe side.
             AnyColumnBuffer::U8(_) | AnyColumnBuffer::NullableU8(_) => DataType::SmallInt,
+            // Same reasoning as for U8. Bind each unsigned type as the next larger signed type.
+            AnyColumnBuffer::U16(_) | AnyColumnBuffer::NullableU16(_) => DataType::Integer,
+            AnyColumnBuffer::U32(_) | AnyColumnBuffer::NullableU32(_) => DataType::BigInt,
+            // There is no larger signed integer type, so we fall back to a numeric able to hold
+            // all 20 digits of `u64::MAX`.
+            AnyColumnBuffer::U64(_) | AnyColumnBuffer::NullableU64(_) => DataType::Numeric {
+                precision: 20,
+                scale: 0,
+            },
             AnyColumnBuffer::Bit(_) | AnyColumnBuffer::NullableBit(_) => DataType::Bit,
+            AnyColumnBuffer::Guid(_) | AnyColumnBuffer::NullableGuid(_) => DataType::Other {
+                data_type: SqlDataType::EXT_GUID,
+                column_size: 36,
+                decimal_digits: 0,
+            },
         }
     }
 }
@@ -308,6 +371,48 @@ pub fn buffer_from_description_and_indices(
     ColumnarBuffer::new(columns)
 }
 
+impl ColumnarBuffer<AnyColumnBuffer> {
+    /// Allocates a buffer with one nullable column for each hint, sizing each variadic column
+    /// independently. Useful for wide schemas with mixed column widths, e.g. if one column holds
+    /// short codes and another one long descriptions, since neither column needs to be allocated
+    /// for the length of the other. The n-th hint is bound to the n-th column of the result set,
+    /// i.e. the column with the one based column number `n`.
+    ///
+    /// # Parameters
+    ///
+    /// * `capacity`: Maximum number of rows the buffer can hold.
+    /// * `hints`: Buffer kind of each column. The maximum element length of variadic columns is
+    ///   taken from the kind, i.e. `max_str_len` for `Text` and `WText` and `length` for `Binary`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::buffers::{BufferKind, ColumnarBuffer};
+    ///
+    /// let buffer = ColumnarBuffer::with_capacity_hints(
+    ///     1000,
+    ///     [
+    ///         BufferKind::I32,
+    ///         // Short codes
+    ///         BufferKind::Text { max_str_len: 3 },
+    ///         // Long descriptions
+    ///         BufferKind::Text { max_str_len: 4000 },
+    ///     ],
+    /// );
+    /// assert_eq!(3, buffer.num_cols());
+    /// ```
+    pub fn with_capacity_hints(
+        capacity: usize,
+        hints: impl IntoIterator<Item = BufferKind>,
+    ) -> Self {
+        let descs = hints.into_iter().map(|kind| BufferDescription {
+            nullable: true,
+            kind,
+        });
+        buffer_from_description(capacity, descs)
+    }
+}
+
 /// A borrowed view on the valid rows in a column of a [`crate::buffers::ColumnarBuffer`].
 ///
 /// For columns of fixed size types, which are guaranteed to not contain null, a direct access to
@@ -330,7 +435,11 @@ pub enum AnyColumnView<'a> {
     I32(&'a [i32]),
     I64(&'a [i64]),
     U8(&'a [u8]),
+    U16(&'a [u16]),
+    U32(&'a [u32]),
+    U64(&'a [u64]),
     Bit(&'a [Bit]),
+    Guid(&'a [Guid]),
     NullableDate(NullableSlice<'a, Date>),
     NullableTime(NullableSlice<'a, Time>),
     NullableTimestamp(NullableSlice<'a, Timestamp>),
@@ -341,7 +450,177 @@ pub enum AnyColumnView<'a> {
     NullableI32(NullableSlice<'a, i32>),
     NullableI64(NullableSlice<'a, i64>),
     NullableU8(NullableSlice<'a, u8>),
+    NullableU16(NullableSlice<'a, u16>),
+    NullableU32(NullableSlice<'a, u32>),
+    NullableU64(NullableSlice<'a, u64>),
     NullableBit(NullableSlice<'a, Bit>),
+    NullableGuid(NullableSlice<'a, Guid>),
+}
+
+impl<'a> AnyColumnView<'a> {
+    /// `Some` if the column holds text in the system encoding. Allows to check the type of the
+    /// column once, rather than matching each value.
+    ///
+    /// ```
+    /// use odbc_api::buffers::AnyColumnView;
+    ///
+    /// fn total_len(column: AnyColumnView<'_>) -> usize {
+    ///     column
+    ///         .as_text()
+    ///         .expect("Column must be bound as text.")
+    ///         .map(|text| text.map(|bytes| bytes.len()).unwrap_or(0))
+    ///         .sum()
+    /// }
+    /// ```
+    pub fn as_text(self) -> Option<TextColumnIt<'a, u8>> {
+        match self {
+            AnyColumnView::Text(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// `Some` if the column holds UTF-16 encoded text.
+    pub fn as_w_text(self) -> Option<TextColumnIt<'a, u16>> {
+        match self {
+            AnyColumnView::WText(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// `Some` if the column holds text, independent of whether it has been bound as narrow
+    /// ([`BufferKind::Text`]) or wide ([`BufferKind::WText`]) characters. The values are decoded
+    /// to UTF-8. This allows to choose the encoding individually for each column, yet process all
+    /// text columns in the same way.
+    ///
+    /// ```
+    /// use odbc_api::buffers::AnyColumnView;
+    ///
+    /// fn to_strings(column: AnyColumnView<'_>) -> Vec<Option<String>> {
+    ///     column
+    ///         .as_utf8()
+    ///         .expect("Column must be bound as text.")
+    ///         .map(|text| text.map(|text| text.into_owned()))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn as_utf8(self) -> Option<Utf8ColumnIt<'a>> {
+        match self {
+            AnyColumnView::Text(values) => Some(Utf8ColumnIt::Narrow(values)),
+            AnyColumnView::WText(values) => Some(Utf8ColumnIt::Wide(values)),
+            _ => None,
+        }
+    }
+
+    /// `Some` if the column holds binary data.
+    pub fn as_binary(self) -> Option<BinColumnIt<'a>> {
+        match self {
+            AnyColumnView::Binary(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// `Some` if the column holds booleans, independent of whether it is nullable
+    /// ([`BufferKind::Bit`]). Yields `None` for `NULL` values.
+    ///
+    /// ```
+    /// use odbc_api::buffers::AnyColumnView;
+    ///
+    /// fn count_true(column: AnyColumnView<'_>) -> usize {
+    ///     column
+    ///         .as_bools()
+    ///         .expect("Column must be bound as bit.")
+    ///         .filter(|value| *value == Some(true))
+    ///         .count()
+    /// }
+    /// ```
+    pub fn as_bools(self) -> Option<BoolColumnIt<'a>> {
+        match self {
+            AnyColumnView::Bit(values) => Some(BoolColumnIt::Required(values.iter())),
+            AnyColumnView::NullableBit(values) => Some(BoolColumnIt::Nullable(values)),
+            _ => None,
+        }
+    }
+
+    /// `Some` if the column holds non nullable values of type `I`, e.g. `view.as_slice::<i32>()`.
+    /// See [`Item::as_slice`].
+    pub fn as_slice<I: Item>(self) -> Option<&'a [I]> {
+        I::as_slice(self)
+    }
+
+    /// `Some` if the column holds nullable values of type `I`, e.g.
+    /// `view.as_nullable_slice::<i32>()`. See [`Item::as_nullable_slice`].
+    pub fn as_nullable_slice<I: Item>(self) -> Option<NullableSlice<'a, I>> {
+        I::as_nullable_slice(self)
+    }
+}
+
+/// Iterator over the values of a text column, decoded to UTF-8. See [`AnyColumnView::as_utf8`].
+///
+/// Narrow text is expected to be UTF-8 already. Invalid sequences in narrow text, as well as
+/// unpaired surrogates in wide text, are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+#[derive(Debug)]
+pub enum Utf8ColumnIt<'a> {
+    /// Text bound as narrow characters. Valid values are borrowed from the buffer.
+    Narrow(TextColumnIt<'a, u8>),
+    /// Text bound as wide characters. Values are transcoded from UTF-16.
+    Wide(TextColumnIt<'a, u16>),
+}
+
+impl<'a> Iterator for Utf8ColumnIt<'a> {
+    type Item = Option<Cow<'a, str>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Utf8ColumnIt::Narrow(values) => {
+                values.next().map(|text| text.map(String::from_utf8_lossy))
+            }
+            Utf8ColumnIt::Wide(values) => values.next().map(|text| {
+                text.map(|text| {
+                    Cow::Owned(
+                        decode_utf16(text.as_slice().iter().copied())
+                            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                            .collect(),
+                    )
+                })
+            }),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Utf8ColumnIt::Narrow(values) => values.size_hint(),
+            Utf8ColumnIt::Wide(values) => values.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Utf8ColumnIt<'a> {}
+
+/// Iterator over the values of a bit column as booleans. See [`AnyColumnView::as_bools`].
+#[derive(Debug)]
+pub enum BoolColumnIt<'a> {
+    /// Column without indicators. Never yields `None` as value.
+    Required(std::slice::Iter<'a, Bit>),
+    /// Column with indicators. `NULL` is yielded as `None`.
+    Nullable(NullableSlice<'a, Bit>),
+}
+
+impl<'a> Iterator for BoolColumnIt<'a> {
+    type Item = Option<bool>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            BoolColumnIt::Required(values) => values.next().map(|bit| Some(bit.as_bool())),
+            BoolColumnIt::Nullable(values) => values.next().map(|bit| bit.map(|bit| bit.as_bool())),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            BoolColumnIt::Required(values) => values.size_hint(),
+            BoolColumnIt::Nullable(values) => values.size_hint(),
+        }
+    }
 }
 
 /// A mutable borrowed view on the valid rows in a column of a [`ColumnarBuffer`].
@@ -366,7 +645,11 @@ pub enum AnyColumnViewMut<'a> {
     I32(&'a mut [i32]),
     I64(&'a mut [i64]),
     U8(&'a mut [u8]),
+    U16(&'a mut [u16]),
+    U32(&'a mut [u32]),
+    U64(&'a mut [u64]),
     Bit(&'a mut [Bit]),
+    Guid(&'a mut [Guid]),
     NullableDate(NullableSliceMut<'a, Date>),
     NullableTime(NullableSliceMut<'a, Time>),
     NullableTimestamp(NullableSliceMut<'a, Timestamp>),
@@ -377,7 +660,138 @@ pub enum AnyColumnViewMut<'a> {
     NullableI32(NullableSliceMut<'a, i32>),
     NullableI64(NullableSliceMut<'a, i64>),
     NullableU8(NullableSliceMut<'a, u8>),
+    NullableU16(NullableSliceMut<'a, u16>),
+    NullableU32(NullableSliceMut<'a, u32>),
+    NullableU64(NullableSliceMut<'a, u64>),
     NullableBit(NullableSliceMut<'a, Bit>),
+    NullableGuid(NullableSliceMut<'a, Guid>),
+}
+
+/// Generates a setter for a fixed sized type, which works on both the nullable and the non nullable
+/// variant of the column view.
+macro_rules! impl_set_fixed_sized {
+    ($method:ident, $type:ty, $variant:ident, $nullable_variant:ident) => {
+        #[doc = concat!(
+            "Sets the value at `index` of a column holding `",
+            stringify!($type),
+            "`. Use `None` to insert `NULL`.\n\n",
+            "# Panics\n\n",
+            "If the column holds values of a different type, if `None` is passed for a column ",
+            "which is not nullable, or if `index` is out of bounds."
+        )]
+        pub fn $method(&mut self, index: usize, value: Option<$type>) {
+            match self {
+                AnyColumnViewMut::$variant(values) => {
+                    values[index] = value.expect(concat!(
+                        "NULL must not be inserted into a non nullable column of ",
+                        stringify!($type)
+                    ))
+                }
+                AnyColumnViewMut::$nullable_variant(values) => values.set_cell(index, value),
+                _ => panic!(concat!(
+                    "Column type mismatch. Tried to set a value of type ",
+                    stringify!($type),
+                    "."
+                )),
+            }
+        }
+    };
+}
+
+/// Setters for individual cells. These allow filling a [`ColumnarBuffer`] with typed values
+/// element-wise, e.g. to perform a bulk insert.
+///
+/// Setting a value does not change the number of valid rows in the buffer. Call
+/// [`ColumnarBuffer::set_num_rows`] to specify how many rows are to be inserted. If the buffer is
+/// passed as a parameter to a statement, the number of rows is used as the size of the parameter
+/// set (`SQL_ATTR_PARAMSET_SIZE`). The views returned by [`ColumnarBuffer::column_mut`] only cover
+/// the rows in `0..num_rows`, so set the number of rows before filling the buffer.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{
+///     buffers::{buffer_from_description, BufferDescription, BufferKind},
+///     Connection, Error,
+/// };
+///
+/// fn insert_birth_years(
+///     conn: &Connection,
+///     names: &[&str],
+///     years: &[Option<i16>],
+/// ) -> Result<(), Error> {
+///     let descriptions = [
+///         BufferDescription { kind: BufferKind::Text { max_str_len: 255 }, nullable: true },
+///         BufferDescription { kind: BufferKind::I16, nullable: true },
+///     ];
+///     let mut buffer = buffer_from_description(names.len(), descriptions.iter().copied());
+///     // Number of rows inserted, i.e. the parameter set size.
+///     buffer.set_num_rows(names.len());
+///     for (index, (name, year)) in names.iter().zip(years).enumerate() {
+///         buffer.column_mut(0).set_text(index, Some(name.as_bytes()));
+///         buffer.column_mut(1).set_i16(index, *year);
+///     }
+///     conn.execute("INSERT INTO Birthdays (name, year) VALUES (?, ?)", &buffer)?;
+///     Ok(())
+/// }
+/// ```
+impl<'a> AnyColumnViewMut<'a> {
+    impl_set_fixed_sized!(set_date, Date, Date, NullableDate);
+    impl_set_fixed_sized!(set_time, Time, Time, NullableTime);
+    impl_set_fixed_sized!(set_timestamp, Timestamp, Timestamp, NullableTimestamp);
+    impl_set_fixed_sized!(set_f64, f64, F64, NullableF64);
+    impl_set_fixed_sized!(set_f32, f32, F32, NullableF32);
+    impl_set_fixed_sized!(set_i8, i8, I8, NullableI8);
+    impl_set_fixed_sized!(set_i16, i16, I16, NullableI16);
+    impl_set_fixed_sized!(set_i32, i32, I32, NullableI32);
+    impl_set_fixed_sized!(set_i64, i64, I64, NullableI64);
+    impl_set_fixed_sized!(set_u8, u8, U8, NullableU8);
+    impl_set_fixed_sized!(set_u16, u16, U16, NullableU16);
+    impl_set_fixed_sized!(set_u32, u32, U32, NullableU32);
+    impl_set_fixed_sized!(set_u64, u64, U64, NullableU64);
+    impl_set_fixed_sized!(set_bit, Bit, Bit, NullableBit);
+    impl_set_fixed_sized!(set_guid, Guid, Guid, NullableGuid);
+
+    /// Sets the value at `index` of a text column. Use `None` to insert `NULL`.
+    ///
+    /// # Panics
+    ///
+    /// If the column is not a [`Self::Text`] column, if the text is longer than the maximum string
+    /// length of the buffer, or if `index` is out of bounds. Use [`TextColumnWriter::append`] to
+    /// grow the buffer for larger values.
+    pub fn set_text(&mut self, index: usize, value: Option<&[u8]>) {
+        match self {
+            AnyColumnViewMut::Text(writer) => writer.set_value(index, value),
+            _ => panic!("Column type mismatch. Tried to set a value of type text."),
+        }
+    }
+
+    /// Sets the value at `index` of a UTF-16 text column. Use `None` to insert `NULL`.
+    ///
+    /// # Panics
+    ///
+    /// If the column is not a [`Self::WText`] column, if the text is longer than the maximum string
+    /// length of the buffer, or if `index` is out of bounds.
+    pub fn set_wtext(&mut self, index: usize, value: Option<&[u16]>) {
+        match self {
+            AnyColumnViewMut::WText(writer) => writer.set_value(index, value),
+            _ => panic!("Column type mismatch. Tried to set a value of type wide text."),
+        }
+    }
+
+    /// Sets the value at `index` of a binary column. Use `None` to insert `NULL`.
+    ///
+    /// # Panics
+    ///
+    /// If the column is not a [`Self::Binary`] column, if the value is longer than the maximum
+    /// element length of the buffer, or if `index` is out of bounds. Use
+    /// [`BinColumnWriter::append`] to grow the buffer for larger values.
+    pub fn set_binary(&mut self, index: usize, value: Option<&[u8]>) {
+        match self {
+            AnyColumnViewMut::Binary(writer) => writer.set_value(index, value),
+            _ => panic!("Column type mismatch. Tried to set a value of type binary."),
+        }
+    }
 }
 
 unsafe impl<'a> ColumnProjections<'a> for AnyColumnBuffer {
@@ -402,7 +816,11 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::I32(col) => col.capacity(),
             AnyColumnBuffer::I64(col) => col.capacity(),
             AnyColumnBuffer::U8(col) => col.capacity(),
+            AnyColumnBuffer::U16(col) => col.capacity(),
+            AnyColumnBuffer::U32(col) => col.capacity(),
+            AnyColumnBuffer::U64(col) => col.capacity(),
             AnyColumnBuffer::Bit(col) => col.capacity(),
+            AnyColumnBuffer::Guid(col) => col.capacity(),
             AnyColumnBuffer::NullableDate(col) => col.capacity(),
             AnyColumnBuffer::NullableTime(col) => col.capacity(),
             AnyColumnBuffer::NullableTimestamp(col) => col.capacity(),
@@ -413,7 +831,11 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::NullableI32(col) => col.capacity(),
             AnyColumnBuffer::NullableI64(col) => col.capacity(),
             AnyColumnBuffer::NullableU8(col) => col.capacity(),
+            AnyColumnBuffer::NullableU16(col) => col.capacity(),
+            AnyColumnBuffer::NullableU32(col) => col.capacity(),
+            AnyColumnBuffer::NullableU64(col) => col.capacity(),
             AnyColumnBuffer::NullableBit(col) => col.capacity(),
+            AnyColumnBuffer::NullableGuid(col) => col.capacity(),
         }
     }
 
@@ -432,7 +854,11 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::I32(col) => AnyColumnView::I32(&col[0..valid_rows]),
             AnyColumnBuffer::I64(col) => AnyColumnView::I64(&col[0..valid_rows]),
             AnyColumnBuffer::U8(col) => AnyColumnView::U8(&col[0..valid_rows]),
+            AnyColumnBuffer::U16(col) => AnyColumnView::U16(&col[0..valid_rows]),
+            AnyColumnBuffer::U32(col) => AnyColumnView::U32(&col[0..valid_rows]),
+            AnyColumnBuffer::U64(col) => AnyColumnView::U64(&col[0..valid_rows]),
             AnyColumnBuffer::Bit(col) => AnyColumnView::Bit(&col[0..valid_rows]),
+            AnyColumnBuffer::Guid(col) => AnyColumnView::Guid(&col[0..valid_rows]),
             AnyColumnBuffer::NullableDate(col) => AnyColumnView::NullableDate(col.iter(valid_rows)),
             AnyColumnBuffer::NullableTime(col) => AnyColumnView::NullableTime(col.iter(valid_rows)),
             AnyColumnBuffer::NullableTimestamp(col) => {
@@ -445,7 +871,11 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::NullableI32(col) => AnyColumnView::NullableI32(col.iter(valid_rows)),
             AnyColumnBuffer::NullableI64(col) => AnyColumnView::NullableI64(col.iter(valid_rows)),
             AnyColumnBuffer::NullableU8(col) => AnyColumnView::NullableU8(col.iter(valid_rows)),
+            AnyColumnBuffer::NullableU16(col) => AnyColumnView::NullableU16(col.iter(valid_rows)),
+            AnyColumnBuffer::NullableU32(col) => AnyColumnView::NullableU32(col.iter(valid_rows)),
+            AnyColumnBuffer::NullableU64(col) => AnyColumnView::NullableU64(col.iter(valid_rows)),
             AnyColumnBuffer::NullableBit(col) => AnyColumnView::NullableBit(col.iter(valid_rows)),
+            AnyColumnBuffer::NullableGuid(col) => AnyColumnView::NullableGuid(col.iter(valid_rows)),
         }
     }
 
@@ -464,7 +894,11 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::I32(col) => AnyColumnViewMut::I32(&mut col[0..num_rows]),
             AnyColumnBuffer::I64(col) => AnyColumnViewMut::I64(&mut col[0..num_rows]),
             AnyColumnBuffer::U8(col) => AnyColumnViewMut::U8(&mut col[0..num_rows]),
+            AnyColumnBuffer::U16(col) => AnyColumnViewMut::U16(&mut col[0..num_rows]),
+            AnyColumnBuffer::U32(col) => AnyColumnViewMut::U32(&mut col[0..num_rows]),
+            AnyColumnBuffer::U64(col) => AnyColumnViewMut::U64(&mut col[0..num_rows]),
             AnyColumnBuffer::Bit(col) => AnyColumnViewMut::Bit(&mut col[0..num_rows]),
+            AnyColumnBuffer::Guid(col) => AnyColumnViewMut::Guid(&mut col[0..num_rows]),
             AnyColumnBuffer::NullableDate(col) => {
                 AnyColumnViewMut::NullableDate(col.writer_n(num_rows))
             }
@@ -495,9 +929,21 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::NullableU8(col) => {
                 AnyColumnViewMut::NullableU8(col.writer_n(num_rows))
             }
+            AnyColumnBuffer::NullableU16(col) => {
+                AnyColumnViewMut::NullableU16(col.writer_n(num_rows))
+            }
+            AnyColumnBuffer::NullableU32(col) => {
+                AnyColumnViewMut::NullableU32(col.writer_n(num_rows))
+            }
+            AnyColumnBuffer::NullableU64(col) => {
+                AnyColumnViewMut::NullableU64(col.writer_n(num_rows))
+            }
             AnyColumnBuffer::NullableBit(col) => {
                 AnyColumnViewMut::NullableBit(col.writer_n(num_rows))
             }
+            AnyColumnBuffer::NullableGuid(col) => {
+                AnyColumnViewMut::NullableGuid(col.writer_n(num_rows))
+            }
         }
     }
 
@@ -517,7 +963,11 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::I32(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyColumnBuffer::I64(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyColumnBuffer::U8(col) => Self::fill_default_slice(&mut col[from..to]),
+            AnyColumnBuffer::U16(col) => Self::fill_default_slice(&mut col[from..to]),
+            AnyColumnBuffer::U32(col) => Self::fill_default_slice(&mut col[from..to]),
+            AnyColumnBuffer::U64(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyColumnBuffer::Bit(col) => Self::fill_default_slice(&mut col[from..to]),
+            AnyColumnBuffer::Guid(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyColumnBuffer::NullableDate(col) => col.fill_null(from, to),
             AnyColumnBuffer::NullableTime(col) => col.fill_null(from, to),
             AnyColumnBuffer::NullableTimestamp(col) => col.fill_null(from, to),
@@ -528,7 +978,92 @@ unsafe impl ColumnBuffer for AnyColumnBuffer {
             AnyColumnBuffer::NullableI32(col) => col.fill_null(from, to),
             AnyColumnBuffer::NullableI64(col) => col.fill_null(from, to),
             AnyColumnBuffer::NullableU8(col) => col.fill_null(from, to),
+            AnyColumnBuffer::NullableU16(col) => col.fill_null(from, to),
+            AnyColumnBuffer::NullableU32(col) => col.fill_null(from, to),
+            AnyColumnBuffer::NullableU64(col) => col.fill_null(from, to),
             AnyColumnBuffer::NullableBit(col) => col.fill_null(from, to),
+            AnyColumnBuffer::NullableGuid(col) => col.fill_null(from, to),
         }
     }
+
+    fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
+        match self {
+            AnyColumnBuffer::Binary(col) => col.has_truncated_values(num_rows),
+            AnyColumnBuffer::Text(col) => col.has_truncated_values(num_rows),
+            AnyColumnBuffer::WText(col) => col.has_truncated_values(num_rows),
+            // All other buffers are fixed sized and can not truncate values.
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::iter;
+
+    use crate::{
+        buffers::{
+            buffer_from_description, AnyColumnView, BufferDescription, BufferKind, CharColumn,
+            WCharColumn,
+        },
+        Bit,
+    };
+
+    use super::Utf8ColumnIt;
+
+    #[test]
+    fn narrow_and_wide_text_decoded_to_utf8() {
+        let mut narrow = CharColumn::new(2, 5);
+        narrow.set_value(0, Some("Hallo".as_bytes()));
+        narrow.set_value(1, None);
+        let mut wide = WCharColumn::new(2, 5);
+        let text: Vec<u16> = "Grüße".encode_utf16().collect();
+        wide.set_value(0, Some(&text));
+        wide.set_value(1, None);
+
+        let narrow = to_strings(Utf8ColumnIt::Narrow(unsafe { narrow.iter(2) }));
+        let wide = to_strings(Utf8ColumnIt::Wide(unsafe { wide.iter(2) }));
+
+        assert_eq!([Some("Hallo".to_string()), None], narrow.as_slice());
+        assert_eq!([Some("Grüße".to_string()), None], wide.as_slice());
+    }
+
+    #[test]
+    fn lone_surrogate_replaced() {
+        let mut wide = WCharColumn::new(1, 3);
+        // High surrogate without a low surrogate following it.
+        wide.set_value(0, Some(&[0x61, 0xD800, 0x62]));
+
+        let values = to_strings(Utf8ColumnIt::Wide(unsafe { wide.iter(1) }));
+
+        assert_eq!([Some("a\u{FFFD}b".to_string())], values.as_slice());
+    }
+
+    #[test]
+    fn bits_as_bools() {
+        let bits = [Bit(0), Bit(1), Bit(2)];
+
+        let bools: Vec<_> = AnyColumnView::Bit(&bits).as_bools().unwrap().collect();
+
+        assert_eq!([Some(false), Some(true), Some(true)], bools.as_slice());
+        assert!(AnyColumnView::I32(&[]).as_bools().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Column type mismatch")]
+    fn set_value_of_wrong_type_in_column_view() {
+        let desc = BufferDescription {
+            kind: BufferKind::I32,
+            nullable: true,
+        };
+        let mut buffer = buffer_from_description(1, iter::once(desc));
+        buffer.set_num_rows(1);
+        buffer.column_mut(0).set_i64(0, Some(42));
+    }
+
+    fn to_strings(values: Utf8ColumnIt<'_>) -> Vec<Option<String>> {
+        values
+            .map(|text| text.map(|text| text.into_owned()))
+            .collect()
+    }
 }
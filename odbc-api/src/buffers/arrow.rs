@@ -0,0 +1,366 @@
+//! Conversion of fetched columnar buffers into Arrow record batches. Requires the `arrow` feature.
+
+use std::{char::decode_utf16, sync::Arc};
+
+use ::arrow::{
+    array::{ArrayRef, BinaryArray, BooleanArray, PrimitiveArray, StringArray},
+    datatypes::{
+        ArrowPrimitiveType, DataType as ArrowDataType, Date32Type, Field, Float32Type, Float64Type,
+        Int16Type, Int32Type, Int64Type, Int8Type, Schema, SchemaRef, Time32SecondType, TimeUnit,
+        TimestampNanosecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use odbc_sys::{Date, Time, Timestamp};
+
+use super::{AnyColumnBuffer, AnyColumnView, BufferDescription, BufferKind, ColumnarBuffer};
+
+/// The Arrow data type the values of a buffer of this kind are converted into by
+/// [`to_record_batch`]. Text in system encoding is assumed to be UTF-8. Dates are represented as
+/// days since the unix epoch, times in seconds since midnight and timestamps in nanoseconds since
+/// the unix epoch. GUIDs are represented as strings in their canonical `8-4-4-4-12` form.
+///
+/// Nanoseconds since the epoch can only represent timestamps between the years 1677 and 2262.
+/// Converting a timestamp outside of this range fails with an error.
+pub fn arrow_data_type(kind: BufferKind) -> ArrowDataType {
+    match kind {
+        BufferKind::Binary { .. } => ArrowDataType::Binary,
+        BufferKind::Text { .. } | BufferKind::WText { .. } => ArrowDataType::Utf8,
+        BufferKind::F64 => ArrowDataType::Float64,
+        BufferKind::F32 => ArrowDataType::Float32,
+        BufferKind::Date => ArrowDataType::Date32,
+        BufferKind::Time => ArrowDataType::Time32(TimeUnit::Second),
+        BufferKind::Timestamp => ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
+        BufferKind::I8 => ArrowDataType::Int8,
+        BufferKind::I16 => ArrowDataType::Int16,
+        BufferKind::I32 => ArrowDataType::Int32,
+        BufferKind::I64 => ArrowDataType::Int64,
+        BufferKind::U8 => ArrowDataType::UInt8,
+        BufferKind::U16 => ArrowDataType::UInt16,
+        BufferKind::U32 => ArrowDataType::UInt32,
+        BufferKind::U64 => ArrowDataType::UInt64,
+        BufferKind::Bit => ArrowDataType::Boolean,
+        BufferKind::Guid => ArrowDataType::Utf8,
+    }
+}
+
+/// Arrow schema matching the record batches created by [`to_record_batch`] from a buffer created
+/// with `descriptions`.
+///
+/// # Parameters
+///
+/// * `names`: Field name for each column, e.g. obtained with
+///   [`crate::ResultSetMetadata::column_names`].
+/// * `descriptions`: Descriptions used to allocate the buffer, in the same order as `names`.
+pub fn arrow_schema(
+    names: impl IntoIterator<Item = String>,
+    descriptions: impl IntoIterator<Item = BufferDescription>,
+) -> Schema {
+    let fields = names
+        .into_iter()
+        .zip(descriptions)
+        .map(|(name, desc)| Field::new(&name, arrow_data_type(desc.kind), desc.nullable))
+        .collect();
+    Schema::new(fields)
+}
+
+/// Copies the valid rows of a fetched batch into an Arrow record batch. `NULL`s indicated by
+/// the ODBC indicator buffers are translated into the null bitmaps of the Arrow arrays.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{
+///     buffers::{arrow_schema, buffer_from_description, to_record_batch, BufferDescription},
+///     Cursor, Error, ResultSetMetadata,
+/// };
+/// use arrow::record_batch::RecordBatch;
+/// use std::sync::Arc;
+///
+/// fn fetch_record_batches(
+///     cursor: impl Cursor,
+///     descriptions: Vec<BufferDescription>,
+/// ) -> Result<Vec<RecordBatch>, Error> {
+///     let names = cursor.column_names()?.collect::<Result<Vec<_>, _>>()?;
+///     let schema = Arc::new(arrow_schema(names, descriptions.iter().copied()));
+///     let buffer = buffer_from_description(1000, descriptions.into_iter());
+///     let mut row_set_cursor = cursor.bind_buffer(buffer)?;
+///     let mut batches = Vec::new();
+///     while let Some(batch) = row_set_cursor.fetch()? {
+///         batches.push(to_record_batch(schema.clone(), batch).unwrap());
+///     }
+///     Ok(batches)
+/// }
+/// ```
+pub fn to_record_batch(
+    schema: SchemaRef,
+    buffer: &ColumnarBuffer<AnyColumnBuffer>,
+) -> Result<RecordBatch, ArrowError> {
+    let arrays = (0..buffer.num_cols())
+        .map(|buffer_index| to_arrow_array(buffer.column(buffer_index)))
+        .collect::<Result<_, _>>()?;
+    RecordBatch::try_new(schema, arrays)
+}
+
+/// Copies the values of a single column into an Arrow array. See [`arrow_data_type`] for the type
+/// of the returned array. Fails if a date or timestamp can not be represented by its Arrow type.
+pub fn to_arrow_array(column: AnyColumnView<'_>) -> Result<ArrayRef, ArrowError> {
+    let array = match column {
+        AnyColumnView::Text(values) => Arc::new(
+            values
+                .map(|text| text.map(String::from_utf8_lossy))
+                .collect::<StringArray>(),
+        ),
+        AnyColumnView::WText(values) => Arc::new(
+            values
+                .map(|text| {
+                    text.map(|text| {
+                        decode_utf16(text.iter().copied())
+                            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                            .collect::<String>()
+                    })
+                })
+                .collect::<StringArray>(),
+        ),
+        AnyColumnView::Binary(values) => Arc::new(BinaryArray::from_opt_vec(values.collect())),
+        AnyColumnView::Date(values) => {
+            try_primitive::<Date32Type>(values.iter().map(|date| days_since_epoch(date).map(Some)))?
+        }
+        AnyColumnView::Time(values) => {
+            primitive::<Time32SecondType>(values.iter().map(|time| Some(seconds_of_day(time))))
+        }
+        AnyColumnView::Timestamp(values) => try_primitive::<TimestampNanosecondType>(
+            values
+                .iter()
+                .map(|ts| nanoseconds_since_epoch(ts).map(Some)),
+        )?,
+        AnyColumnView::F64(values) => primitive::<Float64Type>(values.iter().copied().map(Some)),
+        AnyColumnView::F32(values) => primitive::<Float32Type>(values.iter().copied().map(Some)),
+        AnyColumnView::I8(values) => primitive::<Int8Type>(values.iter().copied().map(Some)),
+        AnyColumnView::I16(values) => primitive::<Int16Type>(values.iter().copied().map(Some)),
+        AnyColumnView::I32(values) => primitive::<Int32Type>(values.iter().copied().map(Some)),
+        AnyColumnView::I64(values) => primitive::<Int64Type>(values.iter().copied().map(Some)),
+        AnyColumnView::U8(values) => primitive::<UInt8Type>(values.iter().copied().map(Some)),
+        AnyColumnView::U16(values) => primitive::<UInt16Type>(values.iter().copied().map(Some)),
+        AnyColumnView::U32(values) => primitive::<UInt32Type>(values.iter().copied().map(Some)),
+        AnyColumnView::U64(values) => primitive::<UInt64Type>(values.iter().copied().map(Some)),
+        AnyColumnView::Bit(values) => Arc::new(
+            values
+                .iter()
+                .map(|bit| Some(bit.as_bool()))
+                .collect::<BooleanArray>(),
+        ),
+        AnyColumnView::NullableDate(values) => {
+            try_primitive::<Date32Type>(values.map(|date| date.map(days_since_epoch).transpose()))?
+        }
+        AnyColumnView::NullableTime(values) => {
+            primitive::<Time32SecondType>(values.map(|time| time.map(seconds_of_day)))
+        }
+        AnyColumnView::NullableTimestamp(values) => try_primitive::<TimestampNanosecondType>(
+            values.map(|ts| ts.map(nanoseconds_since_epoch).transpose()),
+        )?,
+        AnyColumnView::NullableF64(values) => primitive::<Float64Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableF32(values) => primitive::<Float32Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableI8(values) => primitive::<Int8Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableI16(values) => primitive::<Int16Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableI32(values) => primitive::<Int32Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableI64(values) => primitive::<Int64Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableU8(values) => primitive::<UInt8Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableU16(values) => primitive::<UInt16Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableU32(values) => primitive::<UInt32Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableU64(values) => primitive::<UInt64Type>(values.map(|v| v.copied())),
+        AnyColumnView::NullableBit(values) => Arc::new(
+            values
+                .map(|bit| bit.map(|bit| bit.as_bool()))
+                .collect::<BooleanArray>(),
+        ),
+        AnyColumnView::Guid(values) => Arc::new(
+            values
+                .iter()
+                .map(|guid| Some(guid.to_string()))
+                .collect::<StringArray>(),
+        ),
+        AnyColumnView::NullableGuid(values) => Arc::new(
+            values
+                .map(|guid| guid.map(|guid| guid.to_string()))
+                .collect::<StringArray>(),
+        ),
+    };
+    Ok(array)
+}
+
+/// `None` is recorded as null in the validity bitmap of the array.
+fn primitive<T: ArrowPrimitiveType>(values: impl Iterator<Item = Option<T::Native>>) -> ArrayRef {
+    Arc::new(values.collect::<PrimitiveArray<T>>())
+}
+
+/// Like [`primitive`], but stops at the first value which could not be converted.
+fn try_primitive<T: ArrowPrimitiveType>(
+    values: impl Iterator<Item = Result<Option<T::Native>, ArrowError>>,
+) -> Result<ArrayRef, ArrowError> {
+    Ok(Arc::new(values.collect::<Result<PrimitiveArray<T>, _>>()?))
+}
+
+fn days_since_epoch(date: &Date) -> Result<i32, ArrowError> {
+    days_from_civil(date.year.into(), date.month.into(), date.day.into())
+        .try_into()
+        .map_err(|_| {
+            ArrowError::CastError(format!(
+                "Date {}-{:02}-{:02} can not be represented as days since the unix epoch.",
+                date.year, date.month, date.day
+            ))
+        })
+}
+
+fn seconds_of_day(time: &Time) -> i32 {
+    (time.hour as i32 * 60 + time.minute as i32) * 60 + time.second as i32
+}
+
+fn nanoseconds_since_epoch(ts: &Timestamp) -> Result<i64, ArrowError> {
+    let days = days_from_civil(ts.year.into(), ts.month.into(), ts.day.into());
+    let seconds = ((days * 24 + ts.hour as i64) * 60 + ts.minute as i64) * 60 + ts.second as i64;
+    // Seconds can not overflow for any year representable by ODBC, but nanoseconds can.
+    seconds
+        .checked_mul(1_000_000_000)
+        .and_then(|nanoseconds| nanoseconds.checked_add(ts.fraction.into()))
+        .ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Timestamp {}-{:02}-{:02} {:02}:{:02}:{:02} is out of range for nanoseconds since \
+                the unix epoch.",
+                ts.year, ts.month, ts.day, ts.hour, ts.minute, ts.second
+            ))
+        })
+}
+
+/// Number of days since 1970-01-01 in the proleptic gregorian calendar. See
+/// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::iter;
+
+    use ::arrow::array::{Array, Float64Array, Int32Array};
+
+    use super::*;
+    use crate::buffers::buffer_from_description;
+
+    #[test]
+    fn nullable_columns_to_record_batch() {
+        let descriptions = [
+            BufferDescription {
+                kind: BufferKind::I32,
+                nullable: true,
+            },
+            BufferDescription {
+                kind: BufferKind::F64,
+                nullable: true,
+            },
+            BufferDescription {
+                kind: BufferKind::Text { max_str_len: 10 },
+                nullable: true,
+            },
+        ];
+        let mut buffer = buffer_from_description(3, descriptions.iter().copied());
+        buffer.set_num_rows(3);
+        let rows = [
+            (Some(1), None, Some("one")),
+            (None, Some(2.5), None),
+            (Some(3), Some(3.5), Some("three")),
+        ];
+        for (index, &(int, float, text)) in rows.iter().enumerate() {
+            buffer.column_mut(0).set_i32(index, int);
+            buffer.column_mut(1).set_f64(index, float);
+            buffer
+                .column_mut(2)
+                .set_text(index, text.map(str::as_bytes));
+        }
+        let names = ["a", "b", "c"].iter().map(|name| name.to_string());
+        let schema = Arc::new(arrow_schema(names, descriptions.iter().copied()));
+
+        let batch = to_record_batch(schema, &buffer).unwrap();
+
+        assert_eq!(3, batch.num_rows());
+        let ints = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(1), None, Some(3)],
+            ints.iter().collect::<Vec<_>>()
+        );
+        let floats = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(
+            vec![None, Some(2.5), Some(3.5)],
+            floats.iter().collect::<Vec<_>>()
+        );
+        let texts = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            vec![Some("one"), None, Some("three")],
+            texts.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn required_column_has_no_nulls() {
+        let desc = BufferDescription {
+            kind: BufferKind::I32,
+            nullable: false,
+        };
+        let mut buffer = buffer_from_description(2, iter::once(desc));
+        buffer.set_num_rows(2);
+        buffer.column_mut(0).set_i32(0, Some(1));
+        buffer.column_mut(0).set_i32(1, Some(2));
+
+        let array = to_arrow_array(buffer.column(0)).unwrap();
+
+        assert_eq!(0, array.null_count());
+        assert_eq!(&ArrowDataType::Int32, array.data_type());
+    }
+
+    #[test]
+    fn dates_are_days_since_epoch() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(-1, days_from_civil(1969, 12, 31));
+        assert_eq!(18_993, days_from_civil(2022, 1, 1));
+    }
+
+    #[test]
+    fn timestamp_out_of_nanosecond_range_is_an_error() {
+        let timestamp = |year| Timestamp {
+            year,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            fraction: 5,
+        };
+
+        assert_eq!(
+            18_993 * 86_400 * 1_000_000_000 + 5,
+            nanoseconds_since_epoch(&timestamp(2022)).unwrap()
+        );
+        assert!(nanoseconds_since_epoch(&timestamp(3000)).is_err());
+        assert!(nanoseconds_since_epoch(&timestamp(1600)).is_err());
+    }
+}
@@ -54,6 +54,31 @@ impl BinColumn {
         }
     }
 
+    /// Indicator value at the specified position. Useful to detect truncation of data.
+    ///
+    /// # Safety
+    ///
+    /// The column buffer does not know how many elements were in the last row group, and therefore
+    /// can not guarantee the accessed element to be valid and in a defined state. It also can not
+    /// panic on accessing an undefined element. It will panic however if `row_index` is larger or
+    /// equal to the maximum number of elements in the buffer.
+    pub unsafe fn indicator_at(&self, row_index: usize) -> Indicator {
+        Indicator::from_isize(self.indicators[row_index])
+    }
+
+    /// Indicator of the first value within the first `num_rows` elements, which has been truncated
+    /// because it did not fit into the buffer. `None` if no value has been truncated.
+    ///
+    /// # Panics
+    ///
+    /// If `num_rows` is larger than the capacity of the buffer.
+    pub fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
+        self.indicators[..num_rows]
+            .iter()
+            .map(|&indicator| Indicator::from_isize(indicator))
+            .find(|indicator| indicator.is_truncated(self.max_len))
+    }
+
     /// Changes the maximum element length the buffer can hold. This operation is useful if you find
     /// an unexpected large input during insertion. All values in the buffer will be set to NULL.
     ///
@@ -219,6 +244,32 @@ pub struct BinColumnIt<'c> {
     col: &'c BinColumn,
 }
 
+impl<'c> BinColumnIt<'c> {
+    /// Access the element at the specified position within the fetched row set, independent of how
+    /// far the iterator has advanced. `None` for `NULL`, an empty slice for a value of length zero.
+    /// Truncated values are cut at the maximum element length, use [`Self::indicator_at`] to detect
+    /// truncation.
+    ///
+    /// # Panics
+    ///
+    /// If `row_index` is not smaller than the number of rows in the row set.
+    pub fn at(&self, row_index: usize) -> Option<&'c [u8]> {
+        assert!(row_index < self.num_rows);
+        unsafe { self.col.value_at(row_index) }
+    }
+
+    /// Indicator value of the element at the specified position within the fetched row set. If it
+    /// is larger than the maximum element length of the buffer, the value has been truncated.
+    ///
+    /// # Panics
+    ///
+    /// If `row_index` is not smaller than the number of rows in the row set.
+    pub fn indicator_at(&self, row_index: usize) -> Indicator {
+        assert!(row_index < self.num_rows);
+        unsafe { self.col.indicator_at(row_index) }
+    }
+}
+
 impl<'c> Iterator for BinColumnIt<'c> {
     type Item = Option<&'c [u8]>;
 
@@ -260,6 +311,12 @@ impl<'a> BinColumnWriter<'a> {
         }
     }
 
+    /// Change a single value in the column at the specified index. Panics if the value is larger
+    /// than the maximum element length of the buffer.
+    pub fn set_value(&mut self, index: usize, value: Option<&[u8]>) {
+        self.column.set_value(index, value)
+    }
+
     /// Changes the maximum element length the buffer can hold. This operation is useful if you find
     /// an unexpected large input during insertion. All values in the buffer will be set to NULL.
     ///
@@ -388,3 +445,37 @@ unsafe impl CDataMut for BinColumn {
         self.values.as_mut_ptr() as *mut c_void
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::buffers::Indicator;
+
+    use super::BinColumn;
+
+    #[test]
+    fn random_access_within_row_set() {
+        let mut column = BinColumn::new(4, 3);
+        column.set_value(0, None);
+        column.set_value(1, Some(&[]));
+        column.set_value(2, Some(&[1, 2, 3]));
+        // Simulate truncation, i.e. the driver reporting a value larger than the buffer.
+        column.indicators[3] = 5;
+
+        let view = unsafe { column.iter(4) };
+        assert_eq!(None, view.at(0));
+        assert_eq!(Some(&[][..]), view.at(1));
+        assert_eq!(Some(&[1, 2, 3][..]), view.at(2));
+        assert_eq!(Indicator::Null, view.indicator_at(0));
+        assert_eq!(Indicator::Length(0), view.indicator_at(1));
+        assert_eq!(Indicator::Length(5), view.indicator_at(3));
+        assert_eq!(3, view.at(3).unwrap().len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn access_beyond_row_set() {
+        let column = BinColumn::new(4, 3);
+        let view = unsafe { column.iter(2) };
+        view.at(2);
+    }
+}
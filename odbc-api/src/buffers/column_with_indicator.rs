@@ -1,6 +1,7 @@
 use crate::{
     fixed_sized::{Bit, Pod},
     handles::{CData, CDataMut},
+    Guid,
 };
 use odbc_sys::{Date, Time, Timestamp, NULL_DATA};
 use std::{
@@ -19,7 +20,11 @@ pub type OptI16Column = ColumnWithIndicator<i16>;
 pub type OptI32Column = ColumnWithIndicator<i32>;
 pub type OptI64Column = ColumnWithIndicator<i64>;
 pub type OptU8Column = ColumnWithIndicator<u8>;
+pub type OptU16Column = ColumnWithIndicator<u16>;
+pub type OptU32Column = ColumnWithIndicator<u32>;
+pub type OptU64Column = ColumnWithIndicator<u64>;
 pub type OptBitColumn = ColumnWithIndicator<Bit>;
+pub type OptGuidColumn = ColumnWithIndicator<Guid>;
 
 /// Column buffer for fixed sized type, also binding an indicator buffer to handle NULL.
 #[derive(Debug)]
@@ -93,6 +98,28 @@ impl<'a, T> NullableSlice<'a, T> {
     pub fn len(&self) -> usize {
         self.values.len()
     }
+
+    /// Copies the remaining entries into a vector, so they can outlive the buffer. Entries with an
+    /// indicator of `NULL_DATA` become `None`, all others are considered present, whatever their
+    /// indicator value. Use [`NullableSliceMut::write`] to fill a buffer from such a vector.
+    ///
+    /// ```
+    /// use odbc_api::buffers::AnyColumnView;
+    ///
+    /// fn owned_i32_column(view: AnyColumnView<'_>) -> Option<Vec<Option<i32>>> {
+    ///     view.as_nullable_slice::<i32>().map(|slice| slice.to_vec())
+    /// }
+    /// ```
+    pub fn to_vec(&self) -> Vec<Option<T>>
+    where
+        T: Copy,
+    {
+        self.indicators
+            .iter()
+            .zip(self.values)
+            .map(|(&ind, &value)| if ind == NULL_DATA { None } else { Some(value) })
+            .collect()
+    }
 }
 
 impl<'a, T> Iterator for NullableSlice<'a, T> {
@@ -203,6 +230,16 @@ impl<'a, T> NullableSliceMut<'a, T> {
 }
 
 impl<'a, T> NullableSliceMut<'a, T> {
+    /// Sets the value at the specified index. Use `None` to specify a `NULL` value.
+    pub fn set_cell(&mut self, index: usize, cell: Option<T>) {
+        if let Some(value) = cell {
+            self.indicators[index] = 0;
+            self.values[index] = value;
+        } else {
+            self.indicators[index] = NULL_DATA;
+        }
+    }
+
     /// Writes the elements returned by the iterator into the buffer, starting at the beginning.
     /// Writes elements until the iterator returns `None` or the buffer can not hold more elements.
     pub fn write(&mut self, it: impl Iterator<Item = Option<T>>) {
@@ -216,3 +253,26 @@ impl<'a, T> NullableSliceMut<'a, T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use odbc_sys::NULL_DATA;
+
+    use super::ColumnWithIndicator;
+
+    #[test]
+    fn nullable_slice_to_vec() {
+        let mut column = ColumnWithIndicator::<i32>::new(4);
+        column
+            .writer_n(4)
+            .write([Some(1), None, Some(3), None].iter().copied());
+        // Drivers may report the length of the value as indicator. Anything but `NULL_DATA`
+        // indicates a present value.
+        column.indicators[2] = 4;
+        assert_eq!(NULL_DATA, column.indicators[3]);
+
+        let values = unsafe { column.iter(4) }.to_vec();
+
+        assert_eq!(vec![Some(1), None, Some(3), None], values);
+    }
+}
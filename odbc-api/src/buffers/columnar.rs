@@ -1,16 +1,20 @@
 use std::{
-    cmp::min,
+    borrow::Cow,
     collections::HashSet,
+    mem::size_of,
     str::{from_utf8, Utf8Error},
 };
 
 use crate::{
     handles::{CDataMut, HasDataType, Statement},
     parameter::WithDataType,
+    prebound::{BoundLocation, ParameterMutCollection},
     Cursor, Error, ParameterRefCollection, ResultSetMetadata, RowSetBuffer,
 };
 
-use super::{Indicator, TextColumn};
+use super::{Indicator, MaxStrLen, TextColumn};
+
+use log::warn;
 
 /// Projections for ColumnBuffers, allowing for reading writing data while bound as a rowset or
 /// parameter buffer without invalidating invariants of the type.
@@ -208,6 +212,18 @@ where
         }
         Ok(())
     }
+
+    fn find_truncation(&self) -> Option<(u16, Indicator)> {
+        self.columns.iter().find_map(|(col_number, column)| {
+            column
+                .has_truncated_values(*self.num_rows)
+                .map(|indicator| (*col_number, indicator))
+        })
+    }
+
+    fn num_bound_columns(&self) -> Option<usize> {
+        Some(self.columns.len())
+    }
 }
 
 unsafe impl<C> ParameterRefCollection for &ColumnarBuffer<C>
@@ -227,6 +243,45 @@ where
     }
 }
 
+unsafe impl<C> ParameterRefCollection for &mut ColumnarBuffer<C>
+where
+    C: ColumnBuffer + HasDataType,
+{
+    fn parameter_set_size(&self) -> usize {
+        *self.num_rows
+    }
+
+    unsafe fn bind_parameters_to(&mut self, stmt: &mut impl Statement) -> Result<(), Error> {
+        (&**self).bind_parameters_to(stmt)
+    }
+}
+
+/// Allows to bind a columnar buffer once to a prepared statement and to fill it anew for each
+/// execution. See [`crate::Prepared::bind_parameters`]. Column buffers may be reallocated through
+/// the mutable projection, e.g. to hold longer text. These are reported as changed locations, so
+/// the buffer is bound anew before the next execution.
+unsafe impl<C> ParameterMutCollection for &mut ColumnarBuffer<C>
+where
+    C: ColumnBuffer + HasDataType,
+{
+    type Mut = ColumnarBuffer<C>;
+
+    fn as_mut(&mut self) -> &mut ColumnarBuffer<C> {
+        self
+    }
+
+    fn bound_locations(&self, locations: &mut Vec<BoundLocation>) {
+        locations.clear();
+        locations.extend(self.columns.iter().map(|(_, column)| {
+            (
+                column.value_ptr(),
+                column.indicator_ptr(),
+                column.buffer_length(),
+            )
+        }));
+    }
+}
+
 /// A columnar buffer intended to be bound with [crate::Cursor::bind_buffer] in order to obtain
 /// results from a cursor.
 ///
@@ -272,6 +327,14 @@ pub unsafe trait ColumnBuffer: for<'a> ColumnProjections<'a> + CDataMut {
 
     /// Current capacity of the column
     fn capacity(&self) -> usize;
+
+    /// Indicator of the first value within the first `num_rows` elements, which has been truncated
+    /// because it did not fit into the buffer. `None` if no value has been truncated. Fixed sized
+    /// buffers can not truncate values, so the default implementation always returns `None`.
+    fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
+        let _ = num_rows;
+        None
+    }
 }
 
 unsafe impl<'a, T> ColumnProjections<'a> for WithDataType<T>
@@ -302,6 +365,10 @@ where
     fn capacity(&self) -> usize {
         self.value.capacity()
     }
+
+    fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
+        self.value.has_truncated_values(num_rows)
+    }
 }
 
 /// This row set binds a string buffer to each column, which is large enough to hold the maximum
@@ -392,36 +459,53 @@ impl TextRowSet {
     ///   data the length in characters is multiplied by 4 in order to have enough space for 4 byte
     ///   utf-8 characters. This is a pessimization for some data sources (e.g. SQLite 3) which do
     ///   interpret the size of a `VARCHAR(5)` column as 5 bytes rather than 5 characters.
-    /// * `max_str_limit`: Some queries make it hard to estimate a sensible upper bound and
+    /// * `max_str_len`: Some queries make it hard to estimate a sensible upper bound and
     ///   sometimes drivers are just not that good at it. This argument allows you to specify an
-    ///   upper bound for the length of character data.
+    ///   upper bound for the length of character data, and what to do with columns exceeding it, or
+    ///   of unknown length. See [`MaxStrLen`]. `Option<usize>` is accepted as well, with `None`
+    ///   meaning no upper bound.
     pub fn for_cursor(
         batch_size: usize,
         cursor: &impl ResultSetMetadata,
-        max_str_len: Option<usize>,
+        max_str_len: impl Into<MaxStrLen>,
     ) -> Result<TextRowSet, Error> {
-        let num_cols: u16 = cursor.num_result_cols()?.try_into().unwrap();
-        let buffers = (1..(num_cols + 1))
-            .map(|col_index| {
-                // Ask driver for buffer length
-                let reported_len =
-                    if let Some(encoded_len) = cursor.col_data_type(col_index)?.utf8_len() {
-                        encoded_len
-                    } else {
-                        cursor.col_display_size(col_index)? as usize
-                    };
-                // Apply upper bound if specified
-                let max_str_len = max_str_len
-                    .map(|limit| min(limit, reported_len))
-                    .unwrap_or(reported_len);
-                Ok((col_index, TextColumn::new(batch_size, max_str_len)))
-            })
-            .collect::<Result<_, Error>>()?;
-        Ok(TextRowSet {
-            row_capacity: batch_size,
-            num_rows: Box::new(0),
-            columns: buffers,
-        })
+        let max_str_lens = max_str_lens_for_cursor(cursor, max_str_len)?;
+        Ok(Self::from_max_str_lens(
+            batch_size,
+            max_str_lens.into_iter(),
+        ))
+    }
+
+    /// Like [`Self::for_cursor`], but rather than specifying the number of rows, the batch size is
+    /// chosen as the largest number of rows which fits into `memory_limit` bytes. This protects
+    /// from excessive memory usage in case the result set contains unexpectedly wide columns.
+    ///
+    /// The buffer holds at least one row, even if a single row requires more than `memory_limit`
+    /// bytes. A warning is logged in that case.
+    ///
+    /// # Parameters
+    ///
+    /// * `memory_limit`: Upper bound for the size of the buffers holding values and indicators in
+    ///   bytes.
+    /// * `cursor`: Used to query the display size for each column of the row set. See
+    ///   [`Self::for_cursor`].
+    /// * `max_str_len`: Upper bound for the length of character data. See [`Self::for_cursor`].
+    pub fn for_cursor_with_memory_limit(
+        memory_limit: usize,
+        cursor: &impl ResultSetMetadata,
+        max_str_len: impl Into<MaxStrLen>,
+    ) -> Result<TextRowSet, Error> {
+        let max_str_lens = max_str_lens_for_cursor(cursor, max_str_len)?;
+        // Each value has space for a terminating zero and is accompanied by an indicator.
+        let row_size: usize = max_str_lens
+            .iter()
+            .map(|&max_str_len| max_str_len + 1 + size_of::<isize>())
+            .sum();
+        let batch_size = batch_size_within_memory_limit(memory_limit, row_size);
+        Ok(Self::from_max_str_lens(
+            batch_size,
+            max_str_lens.into_iter(),
+        ))
     }
 
     /// Creates a text buffer large enough to hold `batch_size` rows with one column for each item
@@ -452,11 +536,19 @@ impl TextRowSet {
         unsafe { self.columns[buffer_index].1.value_at(row_index) }
     }
 
-    /// Access the element at the specified position in the row set.
+    /// Access the element at the specified position in the row set. `None` for `NULL`. An error is
+    /// returned if the value is not valid UTF-8.
     pub fn at_as_str(&self, col_index: usize, row_index: usize) -> Result<Option<&str>, Utf8Error> {
         self.at(col_index, row_index).map(from_utf8).transpose()
     }
 
+    /// Access the element at the specified position in the row set. `None` for `NULL`. Invalid
+    /// UTF-8 sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`. This may happen if the
+    /// text has been encoded by the driver using a code page other than UTF-8.
+    pub fn at_as_str_lossy(&self, col_index: usize, row_index: usize) -> Option<Cow<'_, str>> {
+        self.at(col_index, row_index).map(String::from_utf8_lossy)
+    }
+
     /// Indicator value at the specified position. Useful to detect truncation of data.
     ///
     /// # Example
@@ -511,12 +603,53 @@ impl TextRowSet {
     }
 }
 
+/// Maximum string length of each column of the result set, if fetched as text. See
+/// [`TextRowSet::for_cursor`].
+fn max_str_lens_for_cursor(
+    cursor: &impl ResultSetMetadata,
+    max_str_len: impl Into<MaxStrLen>,
+) -> Result<Vec<usize>, Error> {
+    let max_str_len = max_str_len.into();
+    let num_cols: u16 = cursor.num_result_cols()?.try_into().unwrap();
+    (1..(num_cols + 1))
+        .map(|col_index| {
+            // Ask driver for buffer length
+            let reported_len =
+                if let Some(encoded_len) = cursor.col_data_type(col_index)?.utf8_len() {
+                    encoded_len
+                } else {
+                    cursor.col_display_size(col_index)? as usize
+                };
+            // Apply upper bound if specified
+            max_str_len.buffer_len(col_index, reported_len)
+        })
+        .collect()
+}
+
+/// Largest number of rows of `row_size` bytes each, which fit into `memory_limit` bytes. At least
+/// one, since a batch size of zero is not valid.
+fn batch_size_within_memory_limit(memory_limit: usize, row_size: usize) -> usize {
+    if row_size > memory_limit {
+        warn!(
+            "A single row requires {} bytes, exceeding the memory limit of {} bytes.",
+            row_size, memory_limit
+        );
+    }
+    // Result sets without any columns do not require any memory.
+    (memory_limit / row_size.max(1)).max(1)
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::buffers::buffer_from_description_and_indices;
+    use std::iter;
+
+    use crate::buffers::{buffer_from_description_and_indices, TextRowSet};
 
-    use super::super::{BufferDescription, BufferKind};
+    use super::{
+        super::{BufferDescription, BufferKind},
+        batch_size_within_memory_limit,
+    };
 
     #[test]
     #[should_panic(expected = "Column indices must be unique.")]
@@ -527,4 +660,41 @@ mod tests {
         };
         buffer_from_description_and_indices(1, [(1, bd), (2, bd), (1, bd)].iter().cloned());
     }
+
+    #[test]
+    fn text_row_set_at_as_str() {
+        let mut buffer = TextRowSet::from_max_str_lens(4, iter::once(5));
+        buffer.append(iter::once(Some(&b"abc"[..])));
+        buffer.append(iter::once(Some(&b"a\xffc"[..])));
+        buffer.append(iter::once(None));
+        buffer.append(iter::once(Some(&b""[..])));
+
+        assert_eq!(Some("abc"), buffer.at_as_str(0, 0).unwrap());
+        assert!(buffer.at_as_str(0, 1).is_err());
+        assert_eq!(None, buffer.at_as_str(0, 2).unwrap());
+        assert_eq!(Some(""), buffer.at_as_str(0, 3).unwrap());
+    }
+
+    #[test]
+    fn text_row_set_at_as_str_lossy() {
+        let mut buffer = TextRowSet::from_max_str_lens(4, iter::once(5));
+        buffer.append(iter::once(Some(&b"abc"[..])));
+        buffer.append(iter::once(Some(&b"a\xffc"[..])));
+        buffer.append(iter::once(None));
+        buffer.append(iter::once(Some(&b""[..])));
+
+        assert_eq!(Some("abc"), buffer.at_as_str_lossy(0, 0).as_deref());
+        assert_eq!(Some("a\u{FFFD}c"), buffer.at_as_str_lossy(0, 1).as_deref());
+        assert_eq!(None, buffer.at_as_str_lossy(0, 2));
+        assert_eq!(Some(""), buffer.at_as_str_lossy(0, 3).as_deref());
+    }
+
+    #[test]
+    fn batch_size_within_memory_limit_is_at_least_one() {
+        assert_eq!(10, batch_size_within_memory_limit(100, 10));
+        assert_eq!(1, batch_size_within_memory_limit(100, 1000));
+        assert_eq!(1, batch_size_within_memory_limit(0, 10));
+        // No columns and no memory
+        assert_eq!(1, batch_size_within_memory_limit(0, 0));
+    }
 }
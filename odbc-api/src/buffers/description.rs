@@ -1,8 +1,8 @@
-use std::mem::size_of;
+use std::{cmp::min, mem::size_of};
 
-use odbc_sys::{Date, Time, Timestamp};
+use odbc_sys::{Date, SqlDataType, Time, Timestamp};
 
-use crate::{Bit, DataType};
+use crate::{Bit, DataType, Guid};
 
 /// Used to describe a column of a [`crate::buffers::ColumnarBuffer`].
 ///
@@ -12,7 +12,7 @@ use crate::{Bit, DataType};
 /// the kind of processing which is supposed to be applied to the data may be even more important
 /// if choosing the a buffer for the cursor type. I.e. if you intend to print a date to standard out
 /// it may be more reasonable to bind it as `Text` rather than `Date`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct BufferDescription {
     /// This indicates whether or not the buffer will be able to represent NULL values. This will
     /// cause an indicator buffer to be bound if the selected buffer kind does not already require
@@ -22,7 +22,70 @@ pub struct BufferDescription {
     pub kind: BufferKind,
 }
 
+/// Element length used by [`BufferDescription::from_data_type`] for variadic columns without an
+/// upper bound, if no maximum string length is specified.
+const DEFAULT_MAX_STR_LEN: usize = 4096;
+
 impl BufferDescription {
+    /// Describes a nullable buffer suitable to fetch values of the given SQL data type, e.g. as
+    /// reported by [`crate::ResultSetMetadata::col_data_type`]. The buffer kind is chosen by
+    /// [`BufferKind::from_data_type`]. Types without a specialized buffer are fetched as text, using
+    /// their display size (if known) as maximum string length.
+    ///
+    /// # Parameters
+    ///
+    /// * `data_type`: SQL data type of the column the buffer is bound to.
+    /// * `max_str_len`: Upper bound for the element length of text and binary buffers. Also used
+    ///   as element length, if the length is unknown or reported as zero, which some drivers do for
+    ///   variadic types without an upper bound (e.g. `VARCHAR(MAX)`). If `None` the length reported
+    ///   by the driver is used as is, or `4096` if it is unknown or zero.
+    ///
+    /// Wide character columns (e.g. `NVARCHAR`) are fetched as UTF-8 text. Since their length is
+    /// reported in characters, it is multiplied by four, the maximum length of a character in
+    /// UTF-8, before the upper bound is applied.
+    ///
+    /// ```
+    /// use odbc_api::{DataType, buffers::{BufferDescription, BufferKind}};
+    ///
+    /// let desc = BufferDescription::from_data_type(DataType::Varchar { length: 4000 }, Some(255));
+    /// assert_eq!(BufferKind::Text { max_str_len: 255 }, desc.kind);
+    /// assert!(desc.nullable);
+    /// ```
+    pub fn from_data_type(data_type: DataType, max_str_len: Option<usize>) -> Self {
+        let kind = BufferKind::from_data_type(data_type).unwrap_or(BufferKind::Text {
+            max_str_len: data_type.display_size().unwrap_or(0),
+        });
+        let apply_limit = |length: usize| match max_str_len {
+            Some(limit) if length == 0 => limit,
+            Some(limit) => min(limit, length),
+            None if length == 0 => DEFAULT_MAX_STR_LEN,
+            None => length,
+        };
+        let kind = match kind {
+            BufferKind::Binary { length } => BufferKind::Binary {
+                length: apply_limit(length),
+            },
+            BufferKind::Text { max_str_len } => {
+                let max_str_len = match data_type {
+                    // Length is reported in characters, which may take up to four bytes in UTF-8.
+                    DataType::WVarchar { .. } | DataType::WChar { .. } => max_str_len * 4,
+                    _ => max_str_len,
+                };
+                BufferKind::Text {
+                    max_str_len: apply_limit(max_str_len),
+                }
+            }
+            BufferKind::WText { max_str_len } => BufferKind::WText {
+                max_str_len: apply_limit(max_str_len),
+            },
+            other => other,
+        };
+        BufferDescription {
+            nullable: true,
+            kind,
+        }
+    }
+
     /// Returns the element size of such a buffer if bound as a columnar row. Can be used to
     /// estimate memory for columnar bindings.
     pub fn bytes_per_row(&self) -> usize {
@@ -42,7 +105,11 @@ impl BufferDescription {
             BufferKind::I32 => size_of::<i32>() + opt_indicator,
             BufferKind::I64 => size_of::<i64>() + opt_indicator,
             BufferKind::U8 => size_of::<u8>() + opt_indicator,
+            BufferKind::U16 => size_of::<u16>() + opt_indicator,
+            BufferKind::U32 => size_of::<u32>() + opt_indicator,
+            BufferKind::U64 => size_of::<u64>() + opt_indicator,
             BufferKind::Bit => size_of::<Bit>() + opt_indicator,
+            BufferKind::Guid => size_of::<Guid>() + opt_indicator,
         }
     }
 }
@@ -89,8 +156,16 @@ pub enum BufferKind {
     I64,
     /// Unsigned 8 Bit integer
     U8,
+    /// Unsigned 16 Bit integer
+    U16,
+    /// Unsigned 32 Bit integer
+    U32,
+    /// Unsigned 64 Bit integer
+    U64,
     /// Can either be zero or one
     Bit,
+    /// Describes a buffer holding [`crate::Guid`] values, e.g. of `UNIQUEIDENTIFIER` columns.
+    Guid,
 }
 
 impl BufferKind {
@@ -206,12 +281,48 @@ impl BufferKind {
             | DataType::Numeric { precision: _, scale: _ }
             | DataType::Decimal { precision: _, scale: _ }
             | DataType::Time { precision: _ } => BufferKind::Text { max_str_len: data_type.display_size().unwrap() },
+            DataType::Other { data_type: SqlDataType::EXT_GUID, .. } => BufferKind::Guid,
             DataType::Unknown
             | DataType::Float { precision: _ }
             | DataType::Other { data_type: _, column_size: _, decimal_digits: _ } => return None,
         };
         Some(buffer_kind)
     }
+
+    /// Like [`Self::from_data_type`], but takes into account whether the column is unsigned (see
+    /// [`crate::ResultSetMetadata::is_unsigned_column`]). Integer columns are mapped to unsigned
+    /// buffers of the same width in that case, so values beyond the range of the signed type can
+    /// be fetched.
+    ///
+    /// ```
+    /// use odbc_api::{DataType, buffers::BufferKind};
+    ///
+    /// assert_eq!(
+    ///     BufferKind::from_data_type_and_sign(DataType::TinyInt, true),
+    ///     Some(BufferKind::U8)
+    /// );
+    /// assert_eq!(
+    ///     BufferKind::from_data_type_and_sign(DataType::TinyInt, false),
+    ///     Some(BufferKind::I8)
+    /// );
+    /// assert_eq!(
+    ///     BufferKind::from_data_type_and_sign(DataType::BigInt, true),
+    ///     Some(BufferKind::U64)
+    /// );
+    /// ```
+    pub fn from_data_type_and_sign(data_type: DataType, is_unsigned: bool) -> Option<Self> {
+        if !is_unsigned {
+            return Self::from_data_type(data_type);
+        }
+        let buffer_kind = match data_type {
+            DataType::TinyInt => BufferKind::U8,
+            DataType::SmallInt => BufferKind::U16,
+            DataType::Integer => BufferKind::U32,
+            DataType::BigInt => BufferKind::U64,
+            other => return Self::from_data_type(other),
+        };
+        Some(buffer_kind)
+    }
 }
 
 #[cfg(test)]
@@ -219,6 +330,96 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn buffer_description_from_data_type() {
+        let kind =
+            |data_type, max_str_len| BufferDescription::from_data_type(data_type, max_str_len).kind;
+
+        assert_eq!(BufferKind::I32, kind(DataType::Integer, None));
+        assert_eq!(BufferKind::F64, kind(DataType::Double, Some(10)));
+        assert_eq!(BufferKind::Date, kind(DataType::Date, None));
+        assert_eq!(
+            BufferKind::Timestamp,
+            kind(DataType::Timestamp { precision: 3 }, None)
+        );
+        // Decimals with scale are fetched as text, integers as integers.
+        assert_eq!(
+            BufferKind::Text { max_str_len: 12 },
+            kind(
+                DataType::Decimal {
+                    precision: 10,
+                    scale: 2
+                },
+                None
+            )
+        );
+        assert_eq!(
+            BufferKind::I64,
+            kind(
+                DataType::Numeric {
+                    precision: 15,
+                    scale: 0
+                },
+                None
+            )
+        );
+        // Lengths of variadic types are capped.
+        assert_eq!(
+            BufferKind::Text { max_str_len: 42 },
+            kind(DataType::Varchar { length: 42 }, None)
+        );
+        assert_eq!(
+            BufferKind::Text { max_str_len: 10 },
+            kind(DataType::WVarchar { length: 42 }, Some(10))
+        );
+        // Wide characters may take up to four bytes each in UTF-8.
+        assert_eq!(
+            BufferKind::Text { max_str_len: 168 },
+            kind(DataType::WVarchar { length: 42 }, None)
+        );
+        assert_eq!(
+            BufferKind::Text { max_str_len: 100 },
+            kind(DataType::WChar { length: 42 }, Some(100))
+        );
+        assert_eq!(
+            BufferKind::Binary { length: 10 },
+            kind(DataType::Varbinary { length: 42 }, Some(10))
+        );
+        // Zero length is reported for variadic types without upper bound.
+        assert_eq!(
+            BufferKind::Text { max_str_len: 100 },
+            kind(DataType::Varchar { length: 0 }, Some(100))
+        );
+        assert_eq!(
+            BufferKind::Binary { length: 100 },
+            kind(DataType::LongVarbinary { length: 0 }, Some(100))
+        );
+        // Without an upper bound, a default is used rather than allocating empty buffers.
+        assert_eq!(
+            BufferKind::Text { max_str_len: 4096 },
+            kind(DataType::Varchar { length: 0 }, None)
+        );
+        assert_eq!(
+            BufferKind::Binary { length: 4096 },
+            kind(DataType::Varbinary { length: 0 }, None)
+        );
+        // Types without specialized buffers are fetched as text. If the display size is unknown, the
+        // upper bound is used as string length.
+        let other = DataType::Other {
+            data_type: SqlDataType(-154),
+            column_size: 16,
+            decimal_digits: 0,
+        };
+        assert_eq!(
+            BufferKind::Text { max_str_len: 255 },
+            kind(other, Some(255))
+        );
+        assert_eq!(
+            BufferKind::Text { max_str_len: 5 },
+            kind(DataType::Unknown, Some(5))
+        );
+    }
+
     #[test]
     #[cfg(target_pointer_width = "64")] // Indicator size is platform dependent.
     fn bytes_per_row() {
@@ -239,5 +440,11 @@ mod tests {
         assert_eq!(4, bpr(BufferKind::I32, false));
         assert_eq!(8, bpr(BufferKind::I64, false));
         assert_eq!(1, bpr(BufferKind::U8, false));
+        assert_eq!(2, bpr(BufferKind::U16, false));
+        assert_eq!(4, bpr(BufferKind::U32, false));
+        assert_eq!(8, bpr(BufferKind::U64, false));
+        assert_eq!(8 + 8, bpr(BufferKind::U64, true));
+        assert_eq!(16, bpr(BufferKind::Guid, false));
+        assert_eq!(16 + 8, bpr(BufferKind::Guid, true));
     }
 }
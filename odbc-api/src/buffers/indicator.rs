@@ -27,6 +27,17 @@ impl Indicator {
         }
     }
 
+    /// `true` if the indicator reports a value larger than `buffer_length` bytes, or if the driver
+    /// could not report the length of the value at all. In both cases the value in a buffer of
+    /// that length has been truncated.
+    pub fn is_truncated(self, buffer_length: usize) -> bool {
+        match self {
+            Indicator::Null => false,
+            Indicator::NoTotal => true,
+            Indicator::Length(length) => length > buffer_length,
+        }
+    }
+
     /// Creates an indicator value as required by the ODBC C API.
     pub fn to_isize(self) -> isize {
         match self {
@@ -1,7 +1,7 @@
 use odbc_sys::{Date, Time, Timestamp};
 
 use super::{AnyColumnView, AnyColumnViewMut, BufferKind, NullableSlice, NullableSliceMut};
-use crate::Bit;
+use crate::{Bit, Guid};
 
 /// Can either be extracted as a slice or a [`NullableSlice`] from an [`AnyColumnView`]. This allows
 /// the user to avoid matching on all possibile variants of an [`AnyColumnView`] in case the
@@ -64,6 +64,9 @@ macro_rules! impl_item {
 impl_item!(f64, F64, NullableF64);
 impl_item!(f32, F32, NullableF32);
 impl_item!(u8, U8, NullableU8);
+impl_item!(u16, U16, NullableU16);
+impl_item!(u32, U32, NullableU32);
+impl_item!(u64, U64, NullableU64);
 impl_item!(i8, I8, NullableI8);
 impl_item!(i16, I16, NullableI16);
 impl_item!(i32, I32, NullableI32);
@@ -72,3 +75,4 @@ impl_item!(Date, Date, NullableDate);
 impl_item!(Bit, Bit, NullableBit);
 impl_item!(Time, Time, NullableTime);
 impl_item!(Timestamp, Timestamp, NullableTimestamp);
+impl_item!(Guid, Guid, NullableGuid);
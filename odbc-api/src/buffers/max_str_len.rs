@@ -0,0 +1,106 @@
+use std::cmp::min;
+
+use crate::Error;
+
+/// Policy deciding the length of text buffers, based on the length of a column reported by the
+/// driver. Used e.g. by [`crate::buffers::TextRowSet::for_cursor`].
+///
+/// Some drivers report a length of `0` for columns with no fixed upper bound, e.g. `TEXT` or
+/// `CLOB`. Such lengths are considered unknown.
+///
+/// `Option<usize>` converts into this type for backwards compatibility. `None` becomes
+/// [`MaxStrLen::Unbounded`] and `Some(limit)` becomes [`MaxStrLen::Clamp`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MaxStrLen {
+    /// Take the driver for its word, and use the reported length for each column. Columns of
+    /// unknown length get a buffer of length `0`, i.e. all their values are truncated.
+    Unbounded,
+    /// Use the reported length, but at most the specified number of elements. Columns of unknown
+    /// length get a buffer of exactly this length. Longer values are truncated silently.
+    Clamp(usize),
+    /// Like [`MaxStrLen::Clamp`], but emits [`Error::ColumnExceedsMaxStrLen`] for any column which
+    /// is reported to hold values longer than the limit, rather than clamping it.
+    ClampOrError(usize),
+}
+
+impl MaxStrLen {
+    /// Length of the buffer for a column, given the length reported by the driver.
+    ///
+    /// # Parameters
+    ///
+    /// * `column`: Number of the column starting at 1. Only used to report errors.
+    /// * `reported_len`: Length of the column as reported by the driver. `0` for unknown.
+    pub fn buffer_len(self, column: u16, reported_len: usize) -> Result<usize, Error> {
+        match self {
+            MaxStrLen::Unbounded => Ok(reported_len),
+            MaxStrLen::Clamp(limit) | MaxStrLen::ClampOrError(limit) if reported_len == 0 => {
+                Ok(limit)
+            }
+            MaxStrLen::Clamp(limit) => Ok(min(limit, reported_len)),
+            MaxStrLen::ClampOrError(limit) => {
+                if reported_len > limit {
+                    Err(Error::ColumnExceedsMaxStrLen {
+                        column,
+                        reported_len,
+                        max_str_len: limit,
+                    })
+                } else {
+                    Ok(reported_len)
+                }
+            }
+        }
+    }
+}
+
+impl From<Option<usize>> for MaxStrLen {
+    fn from(max_str_len: Option<usize>) -> Self {
+        match max_str_len {
+            Some(limit) => MaxStrLen::Clamp(limit),
+            None => MaxStrLen::Unbounded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+
+    use super::MaxStrLen;
+
+    #[test]
+    fn unbounded() {
+        assert_eq!(1000, MaxStrLen::Unbounded.buffer_len(1, 1000).unwrap());
+        assert_eq!(0, MaxStrLen::Unbounded.buffer_len(1, 0).unwrap());
+    }
+
+    #[test]
+    fn clamp() {
+        assert_eq!(10, MaxStrLen::Clamp(10).buffer_len(1, 1000).unwrap());
+        assert_eq!(5, MaxStrLen::Clamp(10).buffer_len(1, 5).unwrap());
+        assert_eq!(10, MaxStrLen::Clamp(10).buffer_len(1, 0).unwrap());
+    }
+
+    #[test]
+    fn clamp_or_error() {
+        assert_eq!(5, MaxStrLen::ClampOrError(10).buffer_len(1, 5).unwrap());
+        assert_eq!(10, MaxStrLen::ClampOrError(10).buffer_len(1, 0).unwrap());
+        match MaxStrLen::ClampOrError(10).buffer_len(2, 1000) {
+            Err(Error::ColumnExceedsMaxStrLen {
+                column,
+                reported_len,
+                max_str_len,
+            }) => {
+                assert_eq!(2, column);
+                assert_eq!(1000, reported_len);
+                assert_eq!(10, max_str_len);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn from_option() {
+        assert_eq!(MaxStrLen::Unbounded, MaxStrLen::from(None));
+        assert_eq!(MaxStrLen::Clamp(42), MaxStrLen::from(Some(42)));
+    }
+}
@@ -95,6 +95,20 @@ impl<C> TextColumn<C> {
         Indicator::from_isize(self.indicators[row_index])
     }
 
+    /// Indicator of the first value within the first `num_rows` elements, which has been truncated
+    /// because it did not fit into the buffer. `None` if no value has been truncated.
+    ///
+    /// # Panics
+    ///
+    /// If `num_rows` is larger than the capacity of the buffer.
+    pub fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
+        let max_bytes = self.max_str_len * size_of::<C>();
+        self.indicators[..num_rows]
+            .iter()
+            .map(|&indicator| Indicator::from_isize(indicator))
+            .find(|indicator| indicator.is_truncated(max_bytes))
+    }
+
     /// Changes the maximum string length the buffer can hold. This operation is useful if you find
     /// an unexpected large input string during insertion.
     ///
@@ -336,6 +350,10 @@ where
     fn capacity(&self) -> usize {
         self.indicators.len()
     }
+
+    fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
+        TextColumn::has_truncated_values(self, num_rows)
+    }
 }
 
 /// Iterator over a text column. See [`TextColumn::iter`]
@@ -419,13 +437,20 @@ where
     }
 
     /// Changes the maximum string length the buffer can hold. This operation is useful if you find
-    /// an unexpected large input during insertion. All values in the buffer will be set to NULL.
+    /// an unexpected large input during insertion. Values already written to the rows accessible
+    /// through this writer are preserved, so you can grow the buffer in the middle of filling a
+    /// batch. Shrinking the buffer truncates values which are longer than `new_max_len`.
+    ///
+    /// This reallocates the underlying memory. A columnar buffer is bound to a statement anew each
+    /// time it is passed as parameters to `execute`, so there is no need to rebind anything
+    /// manually. Since a buffer can not be borrowed mutably while it is bound to a statement, there
+    /// is no way to invalidate the pointers of a live binding.
     ///
     /// # Parameters
     ///
     /// * `new_max_len`: New maximum string length without terminating zero.
     pub fn set_max_len(&mut self, new_max_len: usize) {
-        self.column.set_max_len(new_max_len)
+        self.column.resize_max_str(new_max_len, self.to)
     }
 
     /// Changes the maximum string length the buffer can hold. This operation is useful if you find
@@ -0,0 +1,194 @@
+use std::{char::decode_utf16, vec};
+
+use odbc_sys::{Date, Time, Timestamp};
+
+use crate::{Bit, Cursor, Error, Guid, RowSetCursor};
+
+use super::{AnyColumnBuffer, AnyColumnView, ColumnarBuffer};
+
+/// A single value of a result set, owning its data. See [`RowSetCursor::rows`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// `NULL`
+    Null,
+    /// Text fetched either in system encoding or as UTF-16. Invalid sequences are replaced with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    Text(String),
+    Binary(Vec<u8>),
+    Date(Date),
+    Time(Time),
+    Timestamp(Timestamp),
+    F64(f64),
+    F32(f32),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    Bit(Bit),
+    Guid(Guid),
+}
+
+impl Value {
+    /// `true` if the value is `NULL`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+}
+
+/// Iterator over the rows of a result set, fetched block wise. See [`RowSetCursor::rows`].
+pub struct Rows<C: Cursor> {
+    cursor: RowSetCursor<C, ColumnarBuffer<AnyColumnBuffer>>,
+    /// Remaining rows of the row set fetched last.
+    batch: vec::IntoIter<Vec<Value>>,
+    /// `true` once the cursor has been consumed entirely or an error occurred.
+    done: bool,
+}
+
+impl<C: Cursor> Iterator for Rows<C> {
+    type Item = Result<Vec<Value>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(row) = self.batch.next() {
+                return Some(Ok(row));
+            }
+            if self.done {
+                return None;
+            }
+            match self.cursor.fetch() {
+                Ok(Some(batch)) => self.batch = rows_of(batch).into_iter(),
+                Ok(None) => {
+                    self.done = true;
+                    return None;
+                }
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+impl<C: Cursor> RowSetCursor<C, ColumnarBuffer<AnyColumnBuffer>> {
+    /// Iterate over the result set row by row, rather than row set by row set. Values are still
+    /// fetched in blocks into the bound buffer and copied into owned [`Value`]s afterwards. This is
+    /// convenient for scripting, if performance is not paramount.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{
+    ///     buffers::{buffer_from_description, BufferDescription, BufferKind, Value},
+    ///     Cursor, Error,
+    /// };
+    /// use std::iter;
+    ///
+    /// fn collect_ids(cursor: impl Cursor) -> Result<Vec<Vec<Value>>, Error> {
+    ///     let desc = BufferDescription {
+    ///         kind: BufferKind::I32,
+    ///         nullable: true,
+    ///     };
+    ///     let buffer = buffer_from_description(100, iter::once(desc));
+    ///     cursor.bind_buffer(buffer)?.rows().collect()
+    /// }
+    /// ```
+    pub fn rows(self) -> Rows<C> {
+        Rows {
+            cursor: self,
+            batch: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+}
+
+/// Copies all rows of the batch into owned values.
+fn rows_of(batch: &ColumnarBuffer<AnyColumnBuffer>) -> Vec<Vec<Value>> {
+    let mut rows: Vec<Vec<Value>> = (0..batch.num_rows())
+        .map(|_| Vec::with_capacity(batch.num_cols()))
+        .collect();
+    for buffer_index in 0..batch.num_cols() {
+        for (row, value) in rows.iter_mut().zip(values_of(batch.column(buffer_index))) {
+            row.push(value);
+        }
+    }
+    rows
+}
+
+/// Copies the values of a column view into owned values.
+fn values_of(column: AnyColumnView<'_>) -> Vec<Value> {
+    fn non_null<T: Copy>(values: &[T], to_value: fn(T) -> Value) -> Vec<Value> {
+        values.iter().copied().map(to_value).collect()
+    }
+
+    fn nullable<'a, T: Copy + 'a>(
+        values: impl Iterator<Item = Option<&'a T>>,
+        to_value: fn(T) -> Value,
+    ) -> Vec<Value> {
+        values
+            .map(|value| value.copied().map(to_value).unwrap_or(Value::Null))
+            .collect()
+    }
+
+    match column {
+        AnyColumnView::Text(values) => values
+            .map(|text| {
+                text.map(|text| Value::Text(String::from_utf8_lossy(text).into_owned()))
+                    .unwrap_or(Value::Null)
+            })
+            .collect(),
+        AnyColumnView::WText(values) => values
+            .map(|text| {
+                text.map(|text| {
+                    Value::Text(
+                        decode_utf16(text.as_slice().iter().copied())
+                            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                            .collect(),
+                    )
+                })
+                .unwrap_or(Value::Null)
+            })
+            .collect(),
+        AnyColumnView::Binary(values) => values
+            .map(|bytes| {
+                bytes
+                    .map(|bytes| Value::Binary(bytes.to_vec()))
+                    .unwrap_or(Value::Null)
+            })
+            .collect(),
+        AnyColumnView::Date(values) => non_null(values, Value::Date),
+        AnyColumnView::Time(values) => non_null(values, Value::Time),
+        AnyColumnView::Timestamp(values) => non_null(values, Value::Timestamp),
+        AnyColumnView::F64(values) => non_null(values, Value::F64),
+        AnyColumnView::F32(values) => non_null(values, Value::F32),
+        AnyColumnView::I8(values) => non_null(values, Value::I8),
+        AnyColumnView::I16(values) => non_null(values, Value::I16),
+        AnyColumnView::I32(values) => non_null(values, Value::I32),
+        AnyColumnView::I64(values) => non_null(values, Value::I64),
+        AnyColumnView::U8(values) => non_null(values, Value::U8),
+        AnyColumnView::U16(values) => non_null(values, Value::U16),
+        AnyColumnView::U32(values) => non_null(values, Value::U32),
+        AnyColumnView::U64(values) => non_null(values, Value::U64),
+        AnyColumnView::Bit(values) => non_null(values, Value::Bit),
+        AnyColumnView::Guid(values) => non_null(values, Value::Guid),
+        AnyColumnView::NullableDate(values) => nullable(values, Value::Date),
+        AnyColumnView::NullableTime(values) => nullable(values, Value::Time),
+        AnyColumnView::NullableTimestamp(values) => nullable(values, Value::Timestamp),
+        AnyColumnView::NullableF64(values) => nullable(values, Value::F64),
+        AnyColumnView::NullableF32(values) => nullable(values, Value::F32),
+        AnyColumnView::NullableI8(values) => nullable(values, Value::I8),
+        AnyColumnView::NullableI16(values) => nullable(values, Value::I16),
+        AnyColumnView::NullableI32(values) => nullable(values, Value::I32),
+        AnyColumnView::NullableI64(values) => nullable(values, Value::I64),
+        AnyColumnView::NullableU8(values) => nullable(values, Value::U8),
+        AnyColumnView::NullableU16(values) => nullable(values, Value::U16),
+        AnyColumnView::NullableU32(values) => nullable(values, Value::U32),
+        AnyColumnView::NullableU64(values) => nullable(values, Value::U64),
+        AnyColumnView::NullableBit(values) => nullable(values, Value::Bit),
+        AnyColumnView::NullableGuid(values) => nullable(values, Value::Guid),
+    }
+}
@@ -0,0 +1,76 @@
+use std::mem::ManuallyDrop;
+
+use crate::{
+    handles::{SqlResult, Statement, StatementImpl},
+    CursorImpl, Error, ParameterRefCollection, Preallocated,
+};
+
+/// Cancels the execution of a statement, while another thread is blocked executing it. Obtained
+/// from [`Preallocated::split_cancel_handle`].
+///
+/// Cancellation is the one ODBC operation which is explicitly allowed to be called on a statement
+/// handle concurrently to another function executing on it. This is why, in contrast to the
+/// statement itself, the cancel handle can be sent to and shared between threads.
+///
+/// The lifetime `'s` borrows the statement the handle refers to, so the handle can not outlive it.
+pub struct CancelHandle<'s> {
+    /// Refers to the same handle as the statement the cancel handle has been split from. Never
+    /// dropped, since the handle is owned by that statement.
+    statement: ManuallyDrop<StatementImpl<'s>>,
+}
+
+// Safe: The only function ever called on the statement is `SQLCancel`, which may be invoked from
+// any thread, even concurrently to another function executing on the same statement. In
+// particular, diagnostics are never read from the statement, since that would race with the thread
+// executing on it.
+unsafe impl<'s> Send for CancelHandle<'s> {}
+unsafe impl<'s> Sync for CancelHandle<'s> {}
+
+impl<'s> CancelHandle<'s> {
+    /// # Safety
+    ///
+    /// `statement` must stay valid for the lifetime `'s`.
+    pub(crate) unsafe fn new(statement: &impl Statement) -> Self {
+        Self {
+            statement: ManuallyDrop::new(StatementImpl::new(statement.as_sys())),
+        }
+    }
+
+    /// Cancels the processing on the statement. The call executing the statement returns an error
+    /// with SQLSTATE `HY008` ([`crate::handles::State::OPERATION_CANCELED`]). Has no effect if
+    /// nothing is executing on the statement.
+    ///
+    /// If `SQLCancel` itself fails, [`Error::FailedCanceling`] is returned. The diagnostics of the
+    /// statement are not retrieved, because another thread may be executing on it.
+    pub fn cancel(&self) -> Result<(), Error> {
+        match self.statement.cancel() {
+            SqlResult::Success(()) | SqlResult::SuccessWithInfo(()) => Ok(()),
+            SqlResult::Error { .. } => Err(Error::FailedCanceling),
+        }
+    }
+}
+
+/// Executes queries on a statement, while a [`CancelHandle`] for it exists. Obtained from
+/// [`Preallocated::split_cancel_handle`].
+///
+/// Intentionally does not hand out a mutable reference to the [`Preallocated`] itself, since this
+/// would allow to replace, and thereby free, the statement the cancel handle refers to.
+pub struct CancellableStatement<'s, 'o> {
+    preallocated: &'s mut Preallocated<'o>,
+}
+
+impl<'s, 'o> CancellableStatement<'s, 'o> {
+    pub(crate) fn new(preallocated: &'s mut Preallocated<'o>) -> Self {
+        Self { preallocated }
+    }
+
+    /// Executes a statement. See [`Preallocated::execute`]. Returns an error with SQLSTATE `HY008`,
+    /// if the execution is canceled using the [`CancelHandle`].
+    pub fn execute(
+        &mut self,
+        query: &str,
+        params: impl ParameterRefCollection,
+    ) -> Result<Option<CursorImpl<&mut StatementImpl<'o>>>, Error> {
+        self.preallocated.execute(query, params)
+    }
+}
@@ -0,0 +1,179 @@
+use odbc_sys::SqlDataType;
+
+use crate::{buffers::TextRowSet, Cursor, DataType, Error, Nullability};
+
+/// Number of rows fetched at once, while reading the result of a catalog function.
+const CATALOG_BATCH_SIZE: usize = 100;
+
+/// Upper bound for the length of text values in the result of a catalog function. Some drivers
+/// report absurdly large display sizes for columns like `REMARKS`.
+const CATALOG_MAX_STR_LEN: usize = 4096;
+
+/// A table, view or other table like object in a data source. One row in the result of
+/// [`crate::Connection::tables`], see [`crate::Connection::list_tables`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableInfo {
+    /// `TABLE_CAT`. `None` if not applicable to the data source.
+    pub catalog: Option<String>,
+    /// `TABLE_SCHEM`. `None` if not applicable to the data source.
+    pub schema: Option<String>,
+    /// `TABLE_NAME`
+    pub name: String,
+    /// `TABLE_TYPE`. E.g. `TABLE` or `VIEW`.
+    pub table_type: String,
+    /// `REMARKS`. A description of the table.
+    pub remarks: Option<String>,
+}
+
+/// A column of a table. One row in the result of [`crate::Connection::columns`], see
+/// [`crate::Connection::list_columns`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnInfo {
+    /// `TABLE_CAT`. `None` if not applicable to the data source.
+    pub catalog: Option<String>,
+    /// `TABLE_SCHEM`. `None` if not applicable to the data source.
+    pub schema: Option<String>,
+    /// `TABLE_NAME`
+    pub table_name: String,
+    /// `COLUMN_NAME`
+    pub column_name: String,
+    /// `DATA_TYPE`. The SQL data type of the column.
+    pub data_type: SqlDataType,
+    /// `TYPE_NAME`. Data source dependent name of the data type, e.g. `VARCHAR` or `int`.
+    pub type_name: String,
+    /// `COLUMN_SIZE`. `None` for data types where a column size is not applicable.
+    pub column_size: Option<i32>,
+    /// `DECIMAL_DIGITS`. `None` for data types where decimal digits are not applicable.
+    pub decimal_digits: Option<i16>,
+    /// `NULLABLE`
+    pub nullability: Nullability,
+    /// `REMARKS`. A description of the column.
+    pub remarks: Option<String>,
+    /// `COLUMN_DEF`. Default value of the column.
+    pub column_default: Option<String>,
+    /// `ORDINAL_POSITION`. One based position of the column within the table.
+    pub ordinal_position: i32,
+}
+
+impl ColumnInfo {
+    /// The data type of the column, as it would be reported by [`crate::ResultSetMetadata`] for a
+    /// query selecting it. Combines `DATA_TYPE` with `COLUMN_SIZE` and `DECIMAL_DIGITS`.
+    pub fn to_data_type(&self) -> DataType {
+        DataType::new(
+            self.data_type,
+            self.column_size.unwrap_or(0).max(0) as usize,
+            self.decimal_digits.unwrap_or(0),
+        )
+    }
+}
+
+/// Reads the result of [`crate::Connection::tables`].
+pub fn table_infos(cursor: impl Cursor) -> Result<Vec<TableInfo>, Error> {
+    let rows = fetch_text(
+        cursor,
+        &[
+            "TABLE_CAT",
+            "TABLE_SCHEM",
+            "TABLE_NAME",
+            "TABLE_TYPE",
+            "REMARKS",
+        ],
+    )?;
+    let infos = rows
+        .into_iter()
+        .map(|mut row| TableInfo {
+            catalog: row[0].take(),
+            schema: row[1].take(),
+            name: row[2].take().unwrap_or_default(),
+            table_type: row[3].take().unwrap_or_default(),
+            remarks: row[4].take(),
+        })
+        .collect();
+    Ok(infos)
+}
+
+/// Reads the result of [`crate::Connection::columns`].
+pub fn column_infos(cursor: impl Cursor) -> Result<Vec<ColumnInfo>, Error> {
+    let rows = fetch_text(
+        cursor,
+        &[
+            "TABLE_CAT",
+            "TABLE_SCHEM",
+            "TABLE_NAME",
+            "COLUMN_NAME",
+            "DATA_TYPE",
+            "TYPE_NAME",
+            "COLUMN_SIZE",
+            "DECIMAL_DIGITS",
+            "NULLABLE",
+            "REMARKS",
+            "COLUMN_DEF",
+            "ORDINAL_POSITION",
+        ],
+    )?;
+    let infos = rows
+        .into_iter()
+        .map(|mut row| ColumnInfo {
+            catalog: row[0].take(),
+            schema: row[1].take(),
+            table_name: row[2].take().unwrap_or_default(),
+            column_name: row[3].take().unwrap_or_default(),
+            data_type: SqlDataType(parse(&row[4]).unwrap_or(0)),
+            type_name: row[5].take().unwrap_or_default(),
+            column_size: parse(&row[6]),
+            decimal_digits: parse(&row[7]),
+            nullability: parse(&row[8])
+                .map(|nullable| Nullability::new(odbc_sys::Nullability(nullable)))
+                .unwrap_or_default(),
+            remarks: row[9].take(),
+            column_default: row[10].take(),
+            ordinal_position: parse(&row[11]).unwrap_or(0),
+        })
+        .collect();
+    Ok(infos)
+}
+
+/// Parses a numerical value fetched as text. `None` for `NULL`.
+fn parse<T: std::str::FromStr>(value: &Option<String>) -> Option<T> {
+    value.as_deref().and_then(|text| text.trim().parse().ok())
+}
+
+/// Fetches all rows of `cursor` as text. The values of each row are returned in order of
+/// `column_names`. Columns are looked up by name, since not all drivers place them at the
+/// positions demanded by the ODBC standard.
+fn fetch_text(
+    cursor: impl Cursor,
+    column_names: &[&str],
+) -> Result<Vec<Vec<Option<String>>>, Error> {
+    let names = cursor.column_names()?.collect::<Result<Vec<_>, _>>()?;
+    let buffer_indices = column_names
+        .iter()
+        .map(|&wanted| {
+            names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(wanted))
+                .ok_or_else(|| Error::ColumnNotFound {
+                    name: wanted.to_string(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut buffer =
+        TextRowSet::for_cursor(CATALOG_BATCH_SIZE, &cursor, Some(CATALOG_MAX_STR_LEN))?;
+    let mut row_set_cursor = cursor.bind_buffer(&mut buffer)?;
+    let mut rows = Vec::new();
+    while let Some(batch) = row_set_cursor.fetch()? {
+        for row_index in 0..batch.num_rows() {
+            let row = buffer_indices
+                .iter()
+                .map(|&buffer_index| {
+                    batch
+                        .at(buffer_index, row_index)
+                        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+                })
+                .collect();
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
@@ -1,12 +1,18 @@
 use crate::{
     buffers::{BufferDescription, BufferKind},
-    execute::{execute_columns, execute_tables, execute_with_parameters},
-    handles::{self, State, Statement, StatementImpl},
+    catalog::{column_infos, table_infos, ColumnInfo, TableInfo},
+    error::with_query_text,
+    execute::{
+        execute_columns, execute_statistics, execute_tables, execute_type_info,
+        execute_with_parameters, SqlText,
+    },
+    handles::{self, AccessMode, State, Statement, StatementImpl},
     parameter_collection::ParameterRefCollection,
+    sql_script::split_sql_script,
     statement_connection::StatementConnection,
-    CursorImpl, Error, Preallocated, Prepared,
+    CursorImpl, Error, PollingExecution, Preallocated, Prepared,
 };
-use odbc_sys::HDbc;
+use odbc_sys::{HDbc, SqlDataType};
 use std::{borrow::Cow, mem::ManuallyDrop, str, thread::panicking};
 use widestring::{U16Str, U16String};
 
@@ -54,11 +60,21 @@ impl<'conn> Drop for Connection<'conn> {
 /// source, including status, transaction state, and error information.
 pub struct Connection<'c> {
     connection: handles::Connection<'c>,
+    /// `true` if statement text is passed as UTF-8 to the narrow ODBC functions, rather than as
+    /// UTF-16 to the wide ones.
+    narrow_query_text: bool,
+    /// `true` if errors executing or preparing a statement carry its text. See
+    /// [`Connection::set_query_text_in_errors`].
+    query_text_in_errors: bool,
 }
 
 impl<'c> Connection<'c> {
     pub(crate) fn new(connection: handles::Connection<'c>) -> Self {
-        Self { connection }
+        Self {
+            connection,
+            narrow_query_text: false,
+            query_text_in_errors: false,
+        }
     }
 
     /// Transfers ownership of the handle to this open connection to the raw ODBC pointer.
@@ -70,7 +86,7 @@ impl<'c> Connection<'c> {
     /// Transfer ownership of this open connection to a wrapper around the raw ODBC pointer. The
     /// wrapper allows you to call ODBC functions on the handle, but doesn't care if the connection
     /// is in the right state.
-    /// 
+    ///
     /// You should not have a need to call this method if your usecase is covered by this library,
     /// but, in case it is not, this may help you to break out of the type structure which might be
     /// to rigid for you, while simultaniously abondoning its safeguards.
@@ -78,6 +94,59 @@ impl<'c> Connection<'c> {
         unsafe { handles::Connection::new(ManuallyDrop::new(self).connection.as_sys()) }
     }
 
+    /// By default statement text is transcoded to UTF-16 and passed to the wide variants of the
+    /// ODBC functions (e.g. `SQLExecDirectW`), which is correct on every platform. Setting this to
+    /// `true` passes the UTF-8 text as is to the narrow variants (e.g. `SQLExecDirect`) instead,
+    /// saving the transcoding. Only opt into this, if the driver is known to interpret narrow
+    /// strings as UTF-8, as is usually the case for drivers managed by unixODBC. Otherwise non
+    /// ASCII characters in the statement text are going to be garbled.
+    ///
+    /// Affects [`Self::execute`], [`Self::execute_returning_row_count`], [`Self::into_cursor`],
+    /// [`Self::prepare`] and statements created with [`Self::preallocate`] afterwards. Asynchronous
+    /// execution using [`Self::execute_polling`] always passes UTF-16.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let mut conn = env.connect("YourDatabase", "SA", "<YourStrong@Passw0rd>")?;
+    /// conn.set_narrow_query_text(true);
+    /// conn.execute("INSERT INTO Birthdays (year, name) VALUES (1999, 'Peter');", ())?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn set_narrow_query_text(&mut self, narrow: bool) {
+        self.narrow_query_text = narrow;
+    }
+
+    /// `true` if statement text is passed as UTF-8 to the narrow ODBC functions. See
+    /// [`Self::set_narrow_query_text`].
+    pub fn is_narrow_query_text(&self) -> bool {
+        self.narrow_query_text
+    }
+
+    /// By default errors executing a statement are reported exactly as returned by the driver.
+    /// Setting this to `true` wraps them into [`Error::FailedExecutingQuery`], which carries the
+    /// statement text (truncated to its first 1000 characters). This helps to tell which statement
+    /// failed, e.g. then executing many of them in a loop. The values of bound parameters are never
+    /// attached, since they may contain sensitive data.
+    ///
+    /// Affects [`Self::execute`], [`Self::execute_utf16`], [`Self::execute_returning_row_count`],
+    /// [`Self::into_cursor`], [`Self::prepare`], [`Self::prepare_utf16`], as well as executions of
+    /// the resulting [`Prepared`] statements and statements created with [`Self::preallocate`]
+    /// afterwards.
+    pub fn set_query_text_in_errors(&mut self, enabled: bool) {
+        self.query_text_in_errors = enabled;
+    }
+
+    /// `true` if errors executing a statement carry its text. See
+    /// [`Self::set_query_text_in_errors`].
+    pub fn is_query_text_in_errors(&self) -> bool {
+        self.query_text_in_errors
+    }
+
     /// Executes an sql statement using a wide string. See [`Self::execute`].
     pub fn execute_utf16(
         &self,
@@ -85,7 +154,13 @@ impl<'c> Connection<'c> {
         params: impl ParameterRefCollection,
     ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
         let lazy_statement = move || self.allocate_statement();
-        execute_with_parameters(lazy_statement, Some(query), params)
+        let query_text = self.query_text_in_errors.then(|| query.to_string_lossy());
+        let result = execute_with_parameters(
+            lazy_statement,
+            Some(SqlText::Wide(Cow::Borrowed(query))),
+            params,
+        );
+        with_query_text(result, query_text.as_deref())
     }
 
     /// Executes an SQL statement. This is the fastest way to submit an SQL statement for one-time
@@ -122,8 +197,155 @@ impl<'c> Connection<'c> {
         query: &str,
         params: impl ParameterRefCollection,
     ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        let lazy_statement = move || self.allocate_statement();
+        let sql = SqlText::new(query, self.narrow_query_text);
+        let result = execute_with_parameters(lazy_statement, Some(sql), params);
+        with_query_text(result, self.query_text_in_errors.then_some(query))
+    }
+
+    /// Executes an SQL statement, which does not create a result set (e.g. `INSERT`, `UPDATE` or
+    /// `DELETE`) and reports the number of rows affected by it.
+    ///
+    /// # Return
+    ///
+    /// The number of rows affected by the statement. `None` if the driver could not determine the
+    /// number, or if the statement created a result set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let conn = env.connect("YourDatabase", "SA", "<YourStrong@Passw0rd>")?;
+    /// if let Some(num_rows) = conn.execute_returning_row_count("DELETE FROM Birthdays;", ())? {
+    ///     println!("Deleted {} birthdays.", num_rows);
+    /// }
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn execute_returning_row_count(
+        &self,
+        query: &str,
+        params: impl ParameterRefCollection,
+    ) -> Result<Option<usize>, Error> {
+        if params.parameter_set_size() == 0 {
+            // Nothing is executed, so no rows are affected.
+            return Ok(Some(0));
+        }
+        let sql = SqlText::new(query, self.narrow_query_text);
+        let mut statement = self.allocate_statement()?;
+        let result = execute_with_parameters(|| Ok(&mut statement), Some(sql), params);
+        let has_cursor =
+            with_query_text(result, self.query_text_in_errors.then_some(query))?.is_some();
+        if has_cursor {
+            return Ok(None);
+        }
+        let row_count = statement.row_count().into_result(&statement)?;
+        // Negative values (usually `-1`) indicate that the row count is not available.
+        Ok(row_count.try_into().ok())
+    }
+
+    /// Executes an SQL script consisting of several statements separated by semicolons (`;`), e.g.
+    /// the content of a migration file. The script is split using [`crate::split_sql_script`], so
+    /// semicolons within string literals, quoted identifiers and comments do not terminate a
+    /// statement. The statements are executed in order, one at a time, using the same statement
+    /// handle. Result sets created by any of the statements are closed, without fetching them,
+    /// before executing the next statement.
+    ///
+    /// The splitter does not understand compound statements (e.g. procedure bodies enclosed in
+    /// `BEGIN ... END;`) or quotes escaped with a backslash. See the limitations documented at
+    /// [`crate::split_sql_script`].
+    ///
+    /// # Return
+    ///
+    /// The number of statements executed. Execution stops at the first failing statement, which is
+    /// reported as [`Error::FailedExecutingScriptStatement`], together with its position in the
+    /// script.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    /// use std::fs::read_to_string;
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let conn = env.connect("YourDatabase", "SA", "<YourStrong@Passw0rd>")?;
+    /// let script = read_to_string("migration.sql").unwrap();
+    /// conn.execute_batch(&script)?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn execute_batch(&self, script: &str) -> Result<usize, Error> {
+        let statements = split_sql_script(script);
+        let mut preallocated = self.preallocate()?;
+        for (index, &statement) in statements.iter().enumerate() {
+            // Dropping the cursor (if any) closes it.
+            preallocated.execute(statement, ()).map_err(|error| {
+                // The statement is reported as part of this error already.
+                let source = match error {
+                    Error::FailedExecutingQuery { source, .. } => source,
+                    other => Box::new(other),
+                };
+                Error::FailedExecutingScriptStatement {
+                    position: index + 1,
+                    statement: statement.to_string(),
+                    source,
+                }
+            })?;
+        }
+        Ok(statements.len())
+    }
+
+    /// Executes an SQL statement asynchronously. Instead of blocking until the execution is
+    /// finished, the statement is executed step by step by calling
+    /// [`crate::PollingExecution::poll`], which allows the application to do other work in
+    /// between, or to cancel the execution. Useful e.g. for keeping a GUI responsive during long
+    /// running queries.
+    ///
+    /// Support for asynchronous execution varies between drivers. If a driver does not support it,
+    /// the statement is executed synchronously the first time it is polled.
+    ///
+    /// # Parameters
+    ///
+    /// * `query`: The text representation of the SQL statement. E.g. "SELECT * FROM my_table;".
+    /// * `params`: `?` may be used as a placeholder in the statement text. You can use `()` to
+    ///   represent no parameters. Parameters are kept alive by the returned value until execution
+    ///   is finished.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    /// use std::task::Poll;
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let conn = env.connect("YourDatabase", "SA", "<YourStrong@Passw0rd>")?;
+    /// let mut execution = conn.execute_polling("SELECT year, name FROM Birthdays;", ())?;
+    /// let maybe_cursor = loop {
+    ///     match execution.poll()? {
+    ///         Poll::Pending => {
+    ///             // Do other work, or call `execution.cancel()` to abort the query.
+    ///         }
+    ///         Poll::Ready(maybe_cursor) => break maybe_cursor,
+    ///     }
+    /// };
+    /// if let Some(cursor) = maybe_cursor {
+    ///     // Use cursor to process query results.
+    /// }
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn execute_polling<P>(
+        &self,
+        query: &str,
+        params: P,
+    ) -> Result<PollingExecution<'_, P>, Error>
+    where
+        P: ParameterRefCollection,
+    {
         let query = U16String::from_str(query);
-        self.execute_utf16(&query, params)
+        PollingExecution::new(self.allocate_statement()?, &query, params)
     }
 
     /// In some use cases there you only execute a single statement, or the time to open a
@@ -180,9 +402,11 @@ impl<'c> Connection<'c> {
     ///   may be used as a placeholder in the statement text, to be replaced with parameters during
     ///   execution.
     pub fn prepare_utf16(&self, query: &U16Str) -> Result<Prepared<'_>, Error> {
+        let query_text = self.query_text_in_errors.then(|| query.to_string_lossy());
         let mut stmt = self.allocate_statement()?;
-        stmt.prepare(query).into_result(&stmt)?;
-        Ok(Prepared::new(stmt))
+        let result = stmt.prepare(query).into_result(&stmt);
+        with_query_text(result, query_text.as_deref())?;
+        Ok(Prepared::new(stmt, query_text))
     }
 
     /// Prepares an SQL statement. This is recommended for repeated execution of similar queries.
@@ -193,8 +417,13 @@ impl<'c> Connection<'c> {
     ///   may be used as a placeholder in the statement text, to be replaced with parameters during
     ///   execution.
     pub fn prepare(&self, query: &str) -> Result<Prepared<'_>, Error> {
-        let query = U16String::from_str(query);
-        self.prepare_utf16(&query)
+        let query_text = self.query_text_in_errors.then_some(query);
+        let mut stmt = self.allocate_statement()?;
+        let result = SqlText::new(query, self.narrow_query_text)
+            .prepare(&mut stmt)
+            .into_result(&stmt);
+        with_query_text(result, query_text)?;
+        Ok(Prepared::new(stmt, query_text.map(str::to_owned)))
     }
 
     /// Allocates an SQL statement handle. This is recommended if you want to sequentially execute
@@ -229,7 +458,11 @@ impl<'c> Connection<'c> {
     /// ```
     pub fn preallocate(&self) -> Result<Preallocated<'_>, Error> {
         let stmt = self.allocate_statement()?;
-        Ok(Preallocated::new(stmt))
+        Ok(Preallocated::new(
+            stmt,
+            self.narrow_query_text,
+            self.query_text_in_errors,
+        ))
     }
 
     /// Specify the transaction mode. By default, ODBC transactions are in auto-commit mode.
@@ -248,6 +481,35 @@ impl<'c> Connection<'c> {
             .into_result(&self.connection)
     }
 
+    /// Declares whether the application intends to write data using this connection. Setting
+    /// [`AccessMode::ReadOnly`] allows the driver to optimize, and many drivers make writes fail
+    /// in that mode. Yet this is advisory only: Some drivers ignore the access mode and still
+    /// execute writes. Do not rely on it to protect data.
+    ///
+    /// Some drivers only honor the access mode if it is set before connecting. Use
+    /// [`ConnectionOptions::access_mode`] in that case. Drivers which do not support the access
+    /// mode at all cause [`Error::UnsupportedOptionalFeature`] to be returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{AccessMode, Connection, Error};
+    ///
+    /// fn make_read_only(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     conn.set_access_mode(AccessMode::ReadOnly)
+    /// }
+    /// ```
+    pub fn set_access_mode(&self, mode: AccessMode) -> Result<(), Error> {
+        self.connection
+            .set_access_mode(mode)
+            .into_result(&self.connection)
+    }
+
+    /// Access mode of the connection as reported by the driver. See [`Self::set_access_mode`].
+    pub fn access_mode(&self) -> Result<AccessMode, Error> {
+        self.connection.access_mode().into_result(&self.connection)
+    }
+
     /// To commit a transaction in manual-commit mode.
     pub fn commit(&self) -> Result<(), Error> {
         self.connection.commit().into_result(&self.connection)
@@ -260,10 +522,28 @@ impl<'c> Connection<'c> {
 
     /// Indicates the state of the connection. If `true` the connection has been lost. If `false`,
     /// the connection is still active.
+    ///
+    /// The state is queried using the `SQL_ATTR_CONNECTION_DEAD` attribute. Drivers which do not
+    /// support this attribute cause an error to be returned, rather than a guess.
     pub fn is_dead(&self) -> Result<bool, Error> {
         self.connection.is_dead().into_result(&self.connection)
     }
 
+    /// Number of seconds to wait for a login request to complete, as reported by the driver. See
+    /// [`ConnectionOptions::login_timeout_sec`].
+    pub fn login_timeout_sec(&self) -> Result<u32, Error> {
+        self.connection
+            .login_timeout_sec()
+            .into_result(&self.connection)
+    }
+
+    /// Network packet size in bytes used by the connection. This may differ from the size
+    /// requested via [`ConnectionOptions::packet_size`], since drivers clamp it to the range
+    /// supported by the data source.
+    pub fn packet_size(&self) -> Result<u32, Error> {
+        self.connection.packet_size().into_result(&self.connection)
+    }
+
     /// Allows sending this connection to different threads. This Connection will still be only be
     /// used by one thread at a time, but it may be a different thread each time.
     ///
@@ -335,6 +615,29 @@ impl<'c> Connection<'c> {
         Ok(name.to_string().unwrap())
     }
 
+    /// The query text as it would be passed to the data source, after the driver translated it into
+    /// the native SQL dialect of the data source. E.g. ODBC escape sequences like `{fn UCASE(a)}`
+    /// or `{d '2021-03-20'}` are expanded. Nothing is executed. Useful for debugging.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_native(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let native = conn.native_sql("SELECT {fn UCASE(name)} FROM Movies")?;
+    ///     println!("{}", native);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn native_sql(&self, query: &str) -> Result<String, Error> {
+        let query = U16String::from_str(query);
+        let mut buf = Vec::new();
+        self.connection
+            .native_sql(&query, &mut buf)
+            .into_result(&self.connection)?;
+        let native = U16String::from_vec(buf);
+        Ok(native.to_string().unwrap())
+    }
+
     /// Maximum length of catalog names.
     pub fn max_catalog_name_len(&self) -> Result<u16, Error> {
         self.connection
@@ -379,9 +682,23 @@ impl<'c> Connection<'c> {
         Ok(name.to_string().unwrap())
     }
 
+    /// Switches the catalog (i.e. the database) used by the connection, without issuing a
+    /// data source specific statement like `USE`.
+    ///
+    /// Some drivers only allow switching the catalog before any statement has been executed on the
+    /// connection, or while no transaction is open. If the driver rejects the change, the
+    /// diagnostics it provides are returned as an error and the connection keeps using the previous
+    /// catalog.
+    pub fn set_current_catalog(&self, catalog_name: &str) -> Result<(), Error> {
+        let catalog_name = U16String::from_str(catalog_name);
+        self.connection
+            .set_current_catalog(&catalog_name)
+            .into_result(&self.connection)
+    }
+
     /// A cursor describing columns of all tables matching the patterns. Patterns support as
-    /// placeholder `%` for multiple characters or `_` for a single character. Use `\` to escape.The
-    /// returned cursor has the columns:
+    /// placeholder `%` for multiple characters or `_` for a single character. Use `\` to escape (see
+    /// [`escape_search_pattern`]). The returned cursor has the columns:
     /// `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `COLUMN_NAME`, `DATA_TYPE`, `TYPE_NAME`,
     /// `COLUMN_SIZE`, `BUFFER_LENGTH`, `DECIMAL_DIGITS`, `NUM_PREC_RADIX`, `NULLABLE`,
     /// `REMARKS`, `COLUMN_DEF`, `SQL_DATA_TYPE`, `SQL_DATETIME_SUB`, `CHAR_OCTET_LENGTH`,
@@ -404,13 +721,98 @@ impl<'c> Connection<'c> {
         )
     }
 
+    /// A cursor describing the indexes of a table, together with statistics about the table. The
+    /// returned cursor has the columns:
+    /// `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `NON_UNIQUE`, `INDEX_QUALIFIER`, `INDEX_NAME`,
+    /// `TYPE`, `ORDINAL_POSITION`, `COLUMN_NAME`, `ASC_OR_DESC`, `CARDINALITY`, `PAGES`,
+    /// `FILTER_CONDITION`.
+    ///
+    /// There is one row for each column of each index. In addition to that there may be a row with
+    /// `TYPE` equal to `SQL_TABLE_STAT` (`0`) holding the cardinality and number of pages of the
+    /// table itself. For this row `INDEX_NAME`, `COLUMN_NAME` and `ORDINAL_POSITION` are `NULL`.
+    ///
+    /// # Parameters
+    ///
+    /// * `catalog_name`: Catalog of the table. Not a search pattern. `None` if not applicable to
+    ///   the data source.
+    /// * `schema_name`: Schema of the table. Not a search pattern. `None` if not applicable to the
+    ///   data source.
+    /// * `table_name`: Name of the table. Not a search pattern.
+    /// * `unique_only`: If `true` only unique indexes are returned, otherwise all indexes.
+    /// * `quick`: If `true` `CARDINALITY` and `PAGES` are only returned if they are readily
+    ///   available, otherwise the driver is requested to retrieve them unconditionally.
+    pub fn statistics(
+        &self,
+        catalog_name: Option<&str>,
+        schema_name: Option<&str>,
+        table_name: &str,
+        unique_only: bool,
+        quick: bool,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let statement = self.allocate_statement()?;
+
+        let catalog_name = catalog_name.map(U16String::from_str);
+        let schema_name = schema_name.map(U16String::from_str);
+        execute_statistics(
+            statement,
+            catalog_name.as_deref(),
+            schema_name.as_deref(),
+            &U16String::from_str(table_name),
+            unique_only,
+            quick,
+        )
+    }
+
+    /// A cursor describing the data types supported by the data source, e.g. to find out which
+    /// types are available before generating DDL for a specific backend. The returned cursor has
+    /// the columns:
+    /// `TYPE_NAME`, `DATA_TYPE`, `COLUMN_SIZE`, `LITERAL_PREFIX`, `LITERAL_SUFFIX`,
+    /// `CREATE_PARAMS`, `NULLABLE`, `CASE_SENSITIVE`, `SEARCHABLE`, `UNSIGNED_ATTRIBUTE`,
+    /// `FIXED_PREC_SCALE`, `AUTO_UNIQUE_VALUE`, `LOCAL_TYPE_NAME`, `MINIMUM_SCALE`,
+    /// `MAXIMUM_SCALE`, `SQL_DATA_TYPE`, `SQL_DATETIME_SUB`, `NUM_PREC_RADIX`,
+    /// `INTERVAL_PRECISION`.
+    ///
+    /// `TYPE_NAME` is the data source dependent name to use in `CREATE TABLE` statements.
+    /// `CREATE_PARAMS` lists the keywords of the parameters to specify in parentheses after the
+    /// type name, e.g. `length` or `precision,scale`. There may be more than one row for a single
+    /// SQL data type. Rows are ordered by `DATA_TYPE` and then by how closely the type maps to the
+    /// corresponding ODBC SQL data type.
+    ///
+    /// # Parameters
+    ///
+    /// * `data_type`: SQL data type to describe. Pass [`crate::sys::SqlDataType::UNKNOWN_TYPE`],
+    ///   which has the same value as `SQL_ALL_TYPES`, in order to list every type supported by the
+    ///   data source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{sys::SqlDataType, Connection, Cursor, Error};
+    ///
+    /// fn print_integer_type_names(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let mut cursor = conn.type_info(SqlDataType::INTEGER)?;
+    ///     let mut type_name = Vec::new();
+    ///     while let Some(mut row) = cursor.next_row()? {
+    ///         row.get_text(1, &mut type_name)?;
+    ///         println!("{}", String::from_utf8_lossy(&type_name));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn type_info(
+        &self,
+        data_type: SqlDataType,
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        execute_type_info(self.allocate_statement()?, data_type)
+    }
+
     /// List tables, schemas, views and catalogs of a datasource.
     ///
     /// # Parameters
     ///
     /// * `catalog_name`: Filter result by catalog name. Accept search patterns. Use `%` to match
     ///   any number of characters. Use `_` to match exactly on character. Use `\` to escape
-    ///   characeters.
+    ///   characeters. [`escape_search_pattern`] escapes a name, so it is matched literally.
     /// * `schema_name`: Filter result by schema. Accepts patterns in the same way as
     ///   `catalog_name`.
     /// * `table_name`: Filter result by table. Accepts patterns in the same way as `catalog_name`.
@@ -483,6 +885,47 @@ impl<'c> Connection<'c> {
         )
     }
 
+    /// Like [`Self::tables`], but fetches the entire result set and returns one [`TableInfo`] for
+    /// each row. Columns are looked up by name, so this works even if a driver deviates from the
+    /// column order demanded by the standard.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_table_names(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     for table in conn.list_tables(None, None, None, Some("TABLE"))? {
+    ///         println!("{}", table.name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_tables(
+        &self,
+        catalog_name: Option<&str>,
+        schema_name: Option<&str>,
+        table_name: Option<&str>,
+        table_type: Option<&str>,
+    ) -> Result<Vec<TableInfo>, Error> {
+        let cursor = self.tables(catalog_name, schema_name, table_name, table_type)?;
+        table_infos(cursor)
+    }
+
+    /// Like [`Self::columns`], but fetches the entire result set and returns one [`ColumnInfo`]
+    /// for each row. Columns are looked up by name, so this works even if a driver deviates from
+    /// the column order demanded by the standard.
+    pub fn list_columns(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<Vec<ColumnInfo>, Error> {
+        let cursor = self.columns(catalog_name, schema_name, table_name, column_name)?;
+        column_infos(cursor)
+    }
+
     /// The buffer descriptions for all standard buffers (not including extensions) returned in the
     /// columns query (e.g. [`Connection::columns`]).
     ///
@@ -618,6 +1061,53 @@ impl<'c> Connection<'c> {
     }
 }
 
+/// Options to be applied to a connection, before it is established. See
+/// [`crate::Environment::connect_with_options`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConnectionOptions {
+    /// Number of seconds to wait for a login request to complete before returning to the
+    /// application. If `None` the driver default is used. `Some(0)` disables the timeout, so a
+    /// connection attempt waits indefinitely. Not all drivers honor this setting. Applies to
+    /// connecting with a data source name, a connection string or `SQLDriverConnect` alike, see
+    /// [`crate::ConnectionBuilder`].
+    pub login_timeout_sec: Option<u32>,
+    /// Network packet size in bytes. If `None` the driver default is used. Larger packets reduce
+    /// the number of round trips for bulk transfers over high latency links. Drivers clamp the
+    /// value to the range supported by the data source, use [`Connection::packet_size`] to see
+    /// the size actually in use. Not all drivers support setting this option, since it must be
+    /// negotiated before connecting.
+    pub packet_size: Option<u32>,
+    /// Pass statement text as UTF-8 to the narrow ODBC functions, rather than as UTF-16 to the
+    /// wide ones. Not a connection attribute, so [`Self::apply`] ignores it. See
+    /// [`Connection::set_narrow_query_text`].
+    pub narrow_query_text: bool,
+    /// Attach the statement text to errors executing it. Not a connection attribute, so
+    /// [`Self::apply`] ignores it. See [`Connection::set_query_text_in_errors`].
+    pub query_text_in_errors: bool,
+    /// Access mode of the connection. If `None` the driver default is used, which is usually
+    /// [`AccessMode::ReadWrite`]. Some drivers only honor the access mode if it is set before
+    /// connecting. See [`Connection::set_access_mode`].
+    pub access_mode: Option<AccessMode>,
+}
+
+impl ConnectionOptions {
+    /// Set the attributes corresponding to the connection options to an allocated connection
+    /// handle. Usually you would rather provide the options then creating the connection with
+    /// [`crate::Environment::connect_with_options`].
+    pub fn apply(&self, handle: &handles::Connection) -> Result<(), Error> {
+        if let Some(timeout) = self.login_timeout_sec {
+            handle.set_login_timeout_sec(timeout).into_result(handle)?;
+        }
+        if let Some(packet_size) = self.packet_size {
+            handle.set_packet_size(packet_size).into_result(handle)?;
+        }
+        if let Some(mode) = self.access_mode {
+            handle.set_access_mode(mode).into_result(handle)?;
+        }
+        Ok(())
+    }
+}
+
 /// You can use this method to escape a password so it is suitable to be appended to an ODBC
 /// connection string as the value for the `PWD` attribute. This method is only of interest for
 /// application in need to create their own connection strings.
@@ -653,23 +1143,99 @@ impl<'c> Connection<'c> {
 /// ```
 /// use odbc_api::escape_attribute_value;
 /// assert_eq!("abc", escape_attribute_value("abc"));
-/// assert_eq!("ab}c", escape_attribute_value("ab}c"));
+/// assert_eq!("{ab}}c}", escape_attribute_value("ab}c"));
 /// assert_eq!("{ab;c}", escape_attribute_value("ab;c"));
 /// assert_eq!("{a}}b;c}", escape_attribute_value("a}b;c"));
 /// assert_eq!("{ab+c}", escape_attribute_value("ab+c"));
+/// assert_eq!("{ab c}", escape_attribute_value("ab c"));
+/// // Values are always escaped, even if they are already enclosed in braces.
+/// assert_eq!("{{ab;c}}}", escape_attribute_value("{ab;c}"));
+/// assert_eq!("", escape_attribute_value(""));
 /// ```
 pub fn escape_attribute_value(unescaped: &str) -> Cow<'_, str> {
-    // Search the string for semicolon (';') if we do not find any, nothing is to do and we can work
-    // without an extra allocation.
+    // Search the string for characters which require escaping. If we do not find any, nothing is
+    // to do and we can work without an extra allocation.
     //
     // * We escape ';' because it severs as a separator between key=value pairs
+    // * We escape '{' and '}', because they would otherwise be mistaken for the delimiters of an
+    //   escaped value.
+    // * We escape whitespace, since drivers may otherwise trim it.
     // * We escape '+' because passwords with `+` must be escaped on PostgreSQL for some reason.
-    if unescaped.contains(&[';', '+'][..]) {
+    let needs_escaping = unescaped
+        .chars()
+        .any(|c| matches!(c, ';' | '+' | '{' | '}') || c.is_whitespace());
+    if needs_escaping {
         // Surround the string with curly braces ('{','}') and escape every closing curly brace by
         // repeating it.
-        let escaped = unescaped.replace("}", "}}");
+        let escaped = unescaped.replace('}', "}}");
         Cow::Owned(format!("{{{}}}", escaped))
     } else {
         Cow::Borrowed(unescaped)
     }
 }
+
+/// Escapes the wildcards of search patterns (`%` and `_`), as well as the escape character (`\`)
+/// itself, so `unescaped` is matched literally if passed as a pattern argument to catalog functions
+/// like [`Connection::tables`] or [`Connection::columns`].
+///
+/// `\` is assumed to be the escape character of the driver. This holds true for most drivers, but
+/// is not guaranteed by the ODBC standard.
+///
+/// Do not escape arguments to statements, which have been configured to treat arguments of catalog
+/// functions as identifiers rather than search patterns (see
+/// [`crate::handles::Statement::set_metadata_id`]). Wildcards have no special meaning in that case,
+/// and the arguments may not be `NULL`.
+///
+/// ```
+/// use odbc_api::escape_search_pattern;
+/// assert_eq!("abc", escape_search_pattern("abc"));
+/// assert_eq!("my\\_table", escape_search_pattern("my_table"));
+/// assert_eq!("100\\%", escape_search_pattern("100%"));
+/// assert_eq!("a\\\\b", escape_search_pattern("a\\b"));
+/// ```
+pub fn escape_search_pattern(unescaped: &str) -> Cow<'_, str> {
+    if unescaped.contains(&['%', '_', '\\'][..]) {
+        let mut escaped = String::with_capacity(unescaped.len() + 2);
+        for c in unescaped.chars() {
+            if matches!(c, '%' | '_' | '\\') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        Cow::Owned(escaped)
+    } else {
+        Cow::Borrowed(unescaped)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn escape_closing_brace() {
+        assert_eq!("{pa}}ss}", escape_attribute_value("pa}ss"));
+    }
+
+    #[test]
+    fn escape_semicolon() {
+        assert_eq!("{pass;word}", escape_attribute_value("pass;word"));
+    }
+
+    #[test]
+    fn escape_value_enclosed_in_braces() {
+        // A password may start and end with braces, so it must not be mistaken for an escaped one.
+        assert_eq!("{{secret}}}", escape_attribute_value("{secret}"));
+    }
+
+    #[test]
+    fn braced_value_with_single_closing_brace_is_escaped() {
+        assert_eq!("{{a}}b}}}", escape_attribute_value("{a}b}"));
+    }
+
+    #[test]
+    fn escape_empty_value() {
+        assert!(matches!(escape_attribute_value(""), Cow::Borrowed("")));
+    }
+}
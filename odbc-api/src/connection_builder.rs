@@ -0,0 +1,115 @@
+use widestring::U16String;
+
+use crate::{
+    environment::with_parent_window,
+    handles::{self, OutputStringBuffer},
+    Connection, ConnectionOptions, DriverCompleteOption, Environment, Error,
+};
+
+/// Creates connections which are all configured alike. Obtained via
+/// [`Environment::connection_builder`]. Useful if an application opens many connections to the same
+/// data source, so the options do not need to be repeated for each of them.
+///
+/// Options which must be set before connecting (e.g. the login timeout) are applied to the
+/// connection handle before the connection is established. Options which are not connection
+/// attributes (e.g. [`ConnectionOptions::narrow_query_text`]) are applied afterwards.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{ConnectionOptions, Environment};
+///
+/// let env = Environment::new()?;
+///
+/// let builder = env.connection_builder(ConnectionOptions {
+///     login_timeout_sec: Some(5),
+///     ..ConnectionOptions::default()
+/// });
+/// let first = builder.connect_with_connection_string("DSN=Orders;")?;
+/// let second = builder.connect_with_connection_string("DSN=Inventory;")?;
+/// # Ok::<(), odbc_api::Error>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct ConnectionBuilder<'env> {
+    environment: &'env Environment,
+    options: ConnectionOptions,
+}
+
+impl<'env> ConnectionBuilder<'env> {
+    pub(crate) fn new(environment: &'env Environment, options: ConnectionOptions) -> Self {
+        Self {
+            environment,
+            options,
+        }
+    }
+
+    /// Options applied to each connection created by this builder.
+    pub fn options(&self) -> &ConnectionOptions {
+        &self.options
+    }
+
+    /// Establishes a connection to a data source identified by its name, like
+    /// [`Environment::connect`], and applies the options of this builder to it.
+    pub fn connect(
+        &self,
+        data_source_name: &str,
+        user: &str,
+        pwd: &str,
+    ) -> Result<Connection<'env>, Error> {
+        let data_source_name = U16String::from_str(data_source_name);
+        let user = U16String::from_str(user);
+        let pwd = U16String::from_str(pwd);
+        let mut connection = self.environment.allocate_connection()?;
+        self.options.apply(&connection)?;
+        connection
+            .connect(&data_source_name, &user, &pwd)
+            .into_result(&connection)?;
+        Ok(self.finish(connection))
+    }
+
+    /// Establishes a connection using a connection string, like
+    /// [`Environment::connect_with_connection_string`], and applies the options of this builder to
+    /// it.
+    pub fn connect_with_connection_string(
+        &self,
+        connection_string: impl AsRef<str>,
+    ) -> Result<Connection<'env>, Error> {
+        let connection_string = U16String::from_str(connection_string.as_ref());
+        let mut connection = self.environment.allocate_connection()?;
+        self.options.apply(&connection)?;
+        connection
+            .connect_with_connection_string(&connection_string)
+            .into_result(&connection)?;
+        Ok(self.finish(connection))
+    }
+
+    /// Establishes a connection using a connection string, which may be completed by the driver,
+    /// like [`Environment::driver_connect`], and applies the options of this builder to it.
+    pub fn driver_connect(
+        &self,
+        connection_string: &str,
+        completed_connection_string: Option<&mut OutputStringBuffer>,
+        driver_completion: DriverCompleteOption,
+    ) -> Result<Connection<'env>, Error> {
+        let may_prompt = !matches!(driver_completion, DriverCompleteOption::NoPrompt);
+        let connection = with_parent_window(may_prompt, |hwnd| unsafe {
+            // Safe: `hwnd` is either null or a window which outlives the call.
+            self.environment.driver_connect_handle(
+                connection_string,
+                completed_connection_string,
+                driver_completion,
+                hwnd,
+                &self.options,
+            )
+        })?;
+        Ok(self.finish(connection))
+    }
+
+    /// Applies the options which take effect after the connection has been established.
+    fn finish(&self, connection: handles::Connection<'env>) -> Connection<'env> {
+        let mut connection = Connection::new(connection);
+        connection.set_narrow_query_text(self.options.narrow_query_text);
+        connection.set_query_text_in_errors(self.options.query_text_in_errors);
+        connection
+    }
+}
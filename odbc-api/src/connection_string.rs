@@ -0,0 +1,148 @@
+use std::fmt;
+
+use crate::escape_attribute_value;
+
+/// Builds a connection string from individual attributes, escaping their values consistently. See
+/// [`escape_attribute_value`].
+///
+/// Attribute keys are compared case insensitive. Setting an attribute which has been set before
+/// replaces the earlier value, i.e. the last value wins. The attribute keeps its original position
+/// within the connection string.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api::ConnectionString;
+///
+/// let connection_string = ConnectionString::new()
+///     .driver("ODBC Driver 17 for SQL Server")
+///     .server("localhost")
+///     .uid("SA")
+///     .pwd("abc;123}");
+///
+/// assert_eq!(
+///     "DRIVER={ODBC Driver 17 for SQL Server};SERVER=localhost;UID=SA;PWD={abc;123}}};",
+///     connection_string.as_str()
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionString {
+    /// Key and unescaped value of each attribute.
+    attributes: Vec<(String, String)>,
+    /// Rendered connection string. Updated with every change to `attributes`.
+    text: String,
+}
+
+impl ConnectionString {
+    /// An empty connection string without any attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `DRIVER` attribute.
+    pub fn driver(self, driver: &str) -> Self {
+        self.attribute("DRIVER", driver)
+    }
+
+    /// Sets the `DSN` attribute.
+    pub fn dsn(self, dsn: &str) -> Self {
+        self.attribute("DSN", dsn)
+    }
+
+    /// Sets the `SERVER` attribute.
+    pub fn server(self, server: &str) -> Self {
+        self.attribute("SERVER", server)
+    }
+
+    /// Sets the `DATABASE` attribute.
+    pub fn database(self, database: &str) -> Self {
+        self.attribute("DATABASE", database)
+    }
+
+    /// Sets the `UID` attribute.
+    pub fn uid(self, uid: &str) -> Self {
+        self.attribute("UID", uid)
+    }
+
+    /// Sets the `PWD` attribute.
+    pub fn pwd(self, pwd: &str) -> Self {
+        self.attribute("PWD", pwd)
+    }
+
+    /// Sets an arbitrary attribute. The value is escaped, the key is not.
+    ///
+    /// # Panics
+    ///
+    /// If `key` is empty or contains any of the characters `=`, `;`, `{` or `}`.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        assert!(
+            !key.is_empty() && !key.contains(&['=', ';', '{', '}'][..]),
+            "Invalid connection string attribute key: {:?}",
+            key
+        );
+        if let Some(attribute) = self
+            .attributes
+            .iter_mut()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(key))
+        {
+            attribute.1 = value.to_string();
+        } else {
+            self.attributes.push((key.to_string(), value.to_string()));
+        }
+        self.text = self
+            .attributes
+            .iter()
+            .map(|(key, value)| format!("{}={};", key, escape_attribute_value(value)))
+            .collect();
+        self
+    }
+
+    /// The connection string, e.g. to pass it to [`crate::Environment::connect_with_connection_string`].
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for ConnectionString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ConnectionString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConnectionString;
+
+    #[test]
+    fn escape_password() {
+        let cs = ConnectionString::new().uid("SA").pwd("a;b}c");
+        assert_eq!("UID=SA;PWD={a;b}}c};", cs.as_str());
+    }
+
+    #[test]
+    fn escape_value_enclosed_in_braces() {
+        let cs = ConnectionString::new().pwd("{secret}");
+        assert_eq!("PWD={{secret}}};", cs.as_str());
+    }
+
+    #[test]
+    fn last_value_wins() {
+        let cs = ConnectionString::new()
+            .driver("SQLite3")
+            .pwd("first")
+            .attribute("pwd", "second");
+        assert_eq!("DRIVER=SQLite3;PWD=second;", cs.as_str());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid connection string attribute key")]
+    fn reject_invalid_key() {
+        ConnectionString::new().attribute("UID;PWD", "SA");
+    }
+}
@@ -1,14 +1,23 @@
-use odbc_sys::HStmt;
+use odbc_sys::{FetchOrientation, HStmt};
 
 use crate::{
     borrow_mut_statement::BorrowMutStatement,
     buffers::Indicator,
-    handles::{State, Statement},
+    handles::{Lock, Operation, State, Statement},
     parameter::{VarBinarySliceMut, VarCharSliceMut},
-    Error, OutputParameter, ResultSetMetadata,
+    result_set_metadata::fetch_col_data_type,
+    DataType, Error, Nullable, OutputParameter, Prepared, ResultSetMetadata,
 };
 
-use std::{cmp::max, thread::panicking};
+use std::{
+    cell::RefCell,
+    cmp::max,
+    collections::HashMap,
+    io::{self, Read},
+    mem::ManuallyDrop,
+    ptr,
+    thread::panicking,
+};
 
 /// Cursors are used to process and iterate the result sets returned by executing queries.
 pub trait Cursor: ResultSetMetadata {
@@ -32,9 +41,82 @@ pub trait Cursor: ResultSetMetadata {
     /// driver in advance. Consider binding a buffer to the cursor first using
     /// [`Self::bind_buffer`].
     fn next_row(&mut self) -> Result<Option<CursorRow<'_, Self::Statement>>, Error> {
+        let ret = if fetch_next_row(self)? {
+            Some(CursorRow::new(unsafe { self.stmt_mut() }))
+        } else {
+            None
+        };
+        Ok(ret)
+    }
+
+    /// Fetches the first column of the only row in the result set into a fixed sized type like
+    /// `i64` or `f64`. Convenient for queries returning a single value, like
+    /// `SELECT COUNT(*) FROM t`.
+    ///
+    /// # Return
+    ///
+    /// `None` if the result set is empty, or the value is `NULL`. Fails with
+    /// [`Error::TooManyRows`] if the result set contains more than one row. Use
+    /// [`Self::fetch_first_scalar`] to ignore additional rows instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Cursor, Error};
+    ///
+    /// fn count_movies(conn: &Connection<'_>) -> Result<i64, Error> {
+    ///     let mut cursor = conn.execute("SELECT COUNT(*) FROM Movies", ())?.unwrap();
+    ///     Ok(cursor.fetch_one_scalar()?.unwrap_or(0))
+    /// }
+    /// ```
+    fn fetch_one_scalar<T>(&mut self) -> Result<Option<T>, Error>
+    where
+        T: Default,
+        Nullable<T>: OutputParameter,
+    {
+        let value = self.fetch_first_scalar()?;
+        if self.next_row()?.is_some() {
+            return Err(Error::TooManyRows);
+        }
+        Ok(value)
+    }
+
+    /// Fetches the first column of the first row in the result set. Any other rows are ignored.
+    ///
+    /// # Return
+    ///
+    /// `None` if the result set is empty, or the value is `NULL`.
+    fn fetch_first_scalar<T>(&mut self) -> Result<Option<T>, Error>
+    where
+        T: Default,
+        Nullable<T>: OutputParameter,
+    {
+        let mut value = Nullable::<T>::null();
+        if let Some(mut row) = self.next_row()? {
+            row.get_data(1, &mut value)?;
+        }
+        Ok(value.into_opt())
+    }
+
+    /// Moves the cursor to the specified position within the result set and returns the row
+    /// there. In contrast to [`Self::next_row`] this allows to e.g. jump to the last row or a row
+    /// with a specific number, without fetching all the rows before it.
+    ///
+    /// Any position other than [`ScrollPosition::Next`] requires a scrollable cursor. Scrollable
+    /// cursors must be requested before the statement is executed, using
+    /// [`crate::Preallocated::set_scrollable`].
+    ///
+    /// # Return
+    ///
+    /// `None` if the position lies before the first or after the last row of the result set.
+    fn scroll_to(
+        &mut self,
+        position: ScrollPosition,
+    ) -> Result<Option<CursorRow<'_, Self::Statement>>, Error> {
+        let (orientation, offset) = position.orientation_and_offset();
         let row_available = unsafe {
             self.stmt_mut()
-                .fetch()
+                .fetch_scroll(orientation, offset)
                 .map(|res| res.into_result(self.stmt_mut()))
                 .transpose()?
                 .is_some()
@@ -48,10 +130,75 @@ pub trait Cursor: ResultSetMetadata {
     }
 
     /// Binds this cursor to a buffer holding a row set.
+    ///
+    /// The buffer does not need to bind every column of the result set. Columns which are not
+    /// bound are ignored by [`RowSetCursor::fetch`], yet can still be retrieved row by row using
+    /// [`RowSetCursor::extra_column`]. Use [`Self::bind_buffer_checked`] to fail early, if that is
+    /// not intended.
     fn bind_buffer<B>(self, row_set_buffer: B) -> Result<RowSetCursor<Self, B>, Error>
     where
         Self: Sized,
         B: RowSetBuffer;
+
+    /// Like [`Self::bind_buffer`], but fails with [`Error::TooFewColumnsInBuffer`] if the buffer
+    /// binds fewer columns than there are in the result set. Buffers which do not report their
+    /// number of columns using [`RowSetBuffer::num_bound_columns`] are bound without any check.
+    fn bind_buffer_checked<B>(self, row_set_buffer: B) -> Result<RowSetCursor<Self, B>, Error>
+    where
+        Self: Sized,
+        B: RowSetBuffer,
+    {
+        if let Some(actual) = row_set_buffer.num_bound_columns() {
+            let expected = self.num_result_cols()?.try_into().unwrap_or(0);
+            if actual < expected {
+                return Err(Error::TooFewColumnsInBuffer { expected, actual });
+            }
+        }
+        self.bind_buffer(row_set_buffer)
+    }
+}
+
+/// Advances `cursor` to the next row. `false` if there are no more rows in the result set.
+fn fetch_next_row<C: Cursor + ?Sized>(cursor: &mut C) -> Result<bool, Error> {
+    unsafe {
+        cursor
+            .stmt_mut()
+            .fetch()
+            .map(|res| res.into_result(cursor.stmt_mut()))
+            .transpose()
+            .map(|row| row.is_some())
+    }
+}
+
+/// Position of a row within a scrollable result set. See [`crate::Cursor::scroll_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollPosition {
+    /// The row following the current one.
+    Next,
+    /// The row preceding the current one.
+    Prior,
+    /// The first row of the result set.
+    First,
+    /// The last row of the result set.
+    Last,
+    /// Row with the specified number. Rows are numbered starting with `1`. Negative numbers count
+    /// from the end of the result set, i.e. `-1` is the last row.
+    Absolute(isize),
+    /// Row at the specified offset from the current row.
+    Relative(isize),
+}
+
+impl ScrollPosition {
+    fn orientation_and_offset(self) -> (FetchOrientation, isize) {
+        match self {
+            ScrollPosition::Next => (FetchOrientation::Next, 0),
+            ScrollPosition::Prior => (FetchOrientation::Prior, 0),
+            ScrollPosition::First => (FetchOrientation::First, 0),
+            ScrollPosition::Last => (FetchOrientation::Last, 0),
+            ScrollPosition::Absolute(row) => (FetchOrientation::Absolute, row),
+            ScrollPosition::Relative(offset) => (FetchOrientation::Relative, offset),
+        }
+    }
 }
 
 /// An individual row of an result set. See [`crate::Cursor::next_row`].
@@ -200,11 +347,125 @@ where
     }
 }
 
+impl<'c, S> CursorRow<'c, S>
+where
+    S: Statement,
+{
+    /// Streams binary data of a field in the current row, rather than fetching it into memory all
+    /// at once. Useful to e.g. copy a large `VARBINARY(MAX)` value into a file. Column index
+    /// starts at `1`.
+    ///
+    /// A `NULL` value is read as an empty stream.
+    ///
+    /// Each `read` fetches as many bytes as fit into the buffer passed to it, so the buffer size
+    /// determines the number of round trips to the driver. Wrap the reader into a
+    /// [`std::io::BufReader`] with a capacity of [`RECOMMENDED_CHUNK_SIZE`] to fetch the value in
+    /// larger chunks. To bound the size of the value itself use e.g.
+    /// [`crate::Preallocated::set_max_length`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, RECOMMENDED_CHUNK_SIZE};
+    /// use std::{fs::File, io::{self, BufReader}};
+    ///
+    /// fn save_blob(conn: &Connection<'_>) -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut cursor = conn.execute("SELECT content FROM Files", ())?.unwrap();
+    ///     if let Some(mut row) = cursor.next_row()? {
+    ///         let mut file = File::create("content.bin")?;
+    ///         let mut reader =
+    ///             BufReader::with_capacity(RECOMMENDED_CHUNK_SIZE, row.binary_reader(1));
+    ///         io::copy(&mut reader, &mut file)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn binary_reader(&mut self, col_or_param_num: u16) -> BinaryColumnReader<'_, S> {
+        BinaryColumnReader {
+            statement: self.statement,
+            col_or_param_num,
+            finished: false,
+        }
+    }
+}
+
+/// Size of the chunks recommended for streaming long values with [`CursorRow::binary_reader`], e.g.
+/// as the capacity of a [`std::io::BufReader`]. Large enough to keep the number of calls to
+/// `SQLGetData` low, yet small enough not to matter for memory consumption.
+pub const RECOMMENDED_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Implements [`std::io::Read`] for binary data of a single field. Created by
+/// [`CursorRow::binary_reader`].
+///
+/// Each call to `read` fetches the next chunk of the value from the data source, using the buffer
+/// passed to `read` directly, so the value is never held in memory as a whole.
+pub struct BinaryColumnReader<'r, S> {
+    statement: &'r mut S,
+    col_or_param_num: u16,
+    /// `true` once the entire value has been fetched. Calling `SQLGetData` again after that would
+    /// return `SQL_NO_DATA`.
+    finished: bool,
+}
+
+impl<'r, S> Read for BinaryColumnReader<'r, S>
+where
+    S: Statement,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.finished || buf.is_empty() {
+            return Ok(0);
+        }
+        let buf_len = buf.len();
+        let mut target = VarBinarySliceMut::from_buffer(buf, Indicator::Null);
+        self.statement
+            .get_data(self.col_or_param_num, &mut target)
+            .into_result(self.statement)
+            .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+        let num_bytes = match target.indicator() {
+            Indicator::Null => {
+                self.finished = true;
+                0
+            }
+            // The driver does not know how many bytes are still missing, yet more are to come, as
+            // the buffer has been filled completely.
+            Indicator::NoTotal => buf_len,
+            // Length reports the number of bytes, which have not been fetched before this call.
+            Indicator::Length(remaining) => {
+                if remaining <= buf_len {
+                    self.finished = true;
+                    remaining
+                } else {
+                    buf_len
+                }
+            }
+        };
+        Ok(num_bytes)
+    }
+}
+
 /// Cursors are used to process and iterate the result sets returned by executing queries. Created
 /// by either a prepared query or direct execution. Usually utilized through the [`crate::Cursor`]
 /// trait.
 pub struct CursorImpl<Stmt: BorrowMutStatement> {
     statement: Stmt,
+    /// Column attributes are fixed for the lifetime of a result set, so we remember them rather
+    /// than asking the driver each time. A new cursor, and therefore an empty cache, is created
+    /// for each result set.
+    metadata_cache: RefCell<MetadataCache>,
+    /// Maximum number of rows fetched from each result set, regardless of whether the driver
+    /// honors `SQL_ATTR_MAX_ROWS`. `0` means unlimited. See [`crate::Prepared::set_max_rows`].
+    max_rows: usize,
+    /// Number of rows fetched from the current result set so far. Only tracked if `max_rows` is
+    /// not `0`.
+    num_rows_fetched: usize,
+}
+
+/// Column attributes of the current result set, which have already been queried from the driver.
+/// Keyed by one based column number.
+#[derive(Default)]
+struct MetadataCache {
+    data_types: HashMap<u16, DataType>,
+    octet_lengths: HashMap<u16, isize>,
 }
 
 impl<'o, S> Drop for CursorImpl<S>
@@ -232,6 +493,37 @@ where
     fn stmt_ref(&self) -> &Self::Statement {
         self.statement.borrow()
     }
+
+    fn col_octet_length(&self, column_number: u16) -> Result<isize, Error> {
+        if let Some(&octet_length) = self
+            .metadata_cache
+            .borrow()
+            .octet_lengths
+            .get(&column_number)
+        {
+            return Ok(octet_length);
+        }
+        let stmt = self.stmt_ref();
+        let octet_length = stmt.col_octet_length(column_number).into_result(stmt)?;
+        self.metadata_cache
+            .borrow_mut()
+            .octet_lengths
+            .insert(column_number, octet_length);
+        Ok(octet_length)
+    }
+
+    fn col_data_type(&self, column_number: u16) -> Result<DataType, Error> {
+        if let Some(&data_type) = self.metadata_cache.borrow().data_types.get(&column_number) {
+            return Ok(data_type);
+        }
+        // Do not hold on to the cache while fetching, it is borrowed again for the octet length.
+        let data_type = fetch_col_data_type(self, column_number)?;
+        self.metadata_cache
+            .borrow_mut()
+            .data_types
+            .insert(column_number, data_type);
+        Ok(data_type)
+    }
 }
 
 impl<S> Cursor for CursorImpl<S>
@@ -242,6 +534,16 @@ where
         self.statement.borrow_mut()
     }
 
+    fn next_row(&mut self) -> Result<Option<CursorRow<'_, Self::Statement>>, Error> {
+        if self.remaining_rows() == Some(0) || !fetch_next_row(self)? {
+            return Ok(None);
+        }
+        if self.max_rows != 0 {
+            self.num_rows_fetched += 1;
+        }
+        Ok(Some(CursorRow::new(self.statement.borrow_mut())))
+    }
+
     fn bind_buffer<B>(mut self, mut row_set_buffer: B) -> Result<RowSetCursor<Self, B>, Error>
     where
         B: RowSetBuffer,
@@ -268,7 +570,8 @@ where
                 .into_result(stmt)?;
             row_set_buffer.bind_to_cursor(&mut self)?;
         }
-        Ok(RowSetCursor::new(row_set_buffer, self))
+        let remaining_rows = self.remaining_rows();
+        Ok(RowSetCursor::new(row_set_buffer, self, remaining_rows))
     }
 }
 
@@ -287,12 +590,126 @@ where
     ///
     /// `statement` must be in Cursor state, for the invariants of this type to hold.
     pub unsafe fn new(statement: S) -> Self {
-        Self { statement }
+        Self {
+            statement,
+            metadata_cache: RefCell::new(MetadataCache::default()),
+            max_rows: 0,
+            num_rows_fetched: 0,
+        }
+    }
+
+    /// Stops fetching rows from each result set after `max_rows`, even if the driver ignores
+    /// `SQL_ATTR_MAX_ROWS`. `0` means unlimited.
+    pub(crate) fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Number of rows which may still be fetched from the current result set. `None` if unlimited.
+    fn remaining_rows(&self) -> Option<usize> {
+        (self.max_rows != 0).then(|| self.max_rows.saturating_sub(self.num_rows_fetched))
     }
 
     pub(crate) fn as_sys(&self) -> HStmt {
         self.statement.borrow().as_sys()
     }
+
+    /// Closes the cursor and returns the statement it has been created from. Dropping the cursor
+    /// closes it, too, but panics if closing fails. Use this method instead to handle errors
+    /// occurring while closing the cursor, e.g. a lost connection.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn query_and_close(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     if let Some(cursor) = conn.execute("SELECT title FROM Movies", ())? {
+    ///         // Consume cursor ...
+    ///         cursor.close()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn close(self) -> Result<S, Error> {
+        // We close the cursor ourselves, so we must not run the `Drop` implementation, which would
+        // try to close it a second time.
+        let mut cursor = ManuallyDrop::new(self);
+        let stmt = cursor.statement.borrow_mut();
+        let result = stmt.close_cursor().into_result(stmt);
+        // Safe: `cursor` is never dropped, so `statement` is moved out exactly once.
+        let statement = unsafe { ptr::read(&cursor.statement) };
+        // Safe: `cursor` is never dropped, so the cache is dropped exactly once.
+        unsafe { ptr::drop_in_place(&mut cursor.metadata_cache) };
+        result.map(|()| statement)
+    }
+
+    /// Closes the current result set and moves the cursor on to the next one. Statements like
+    /// stored procedures or batches may return several result sets. Results without any columns,
+    /// e.g. the row count of an `INSERT` within a stored procedure, are skipped.
+    ///
+    /// # Return
+    ///
+    /// The cursor positioned on the next result set, or `None` if there are no more result sets.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, Error};
+    ///
+    /// fn print_column_names(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let mut maybe_cursor = conn.execute("SELECT a FROM A; SELECT b FROM B;", ())?;
+    ///     while let Some(cursor) = maybe_cursor {
+    ///         for name in cursor.column_names()? {
+    ///             println!("{}", name?);
+    ///         }
+    ///         maybe_cursor = cursor.more_results()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn more_results(self) -> Result<Option<Self>, Error> {
+        // `SQLMoreResults` closes the current result set, so we must not run the `Drop`
+        // implementation, which would try to close it a second time.
+        let mut cursor = ManuallyDrop::new(self);
+        let stmt = cursor.statement.borrow_mut();
+        let result = next_result_set(stmt);
+        if result.is_err() {
+            // Discard any results left, so the statement can be executed again. Errors are
+            // ignored in favour of the one reported by `SQLMoreResults`.
+            let _ = stmt.close_cursor();
+        }
+        // Safe: `cursor` is never dropped, so `statement` is moved out exactly once.
+        let statement = unsafe { ptr::read(&cursor.statement) };
+        // Safe: `cursor` is never dropped, so the cache is dropped exactly once.
+        unsafe { ptr::drop_in_place(&mut cursor.metadata_cache) };
+        let max_rows = cursor.max_rows;
+        match result {
+            // Safe: `statement` is positioned on a result set, i.e. in cursor state.
+            Ok(true) => Ok(Some(
+                unsafe { CursorImpl::new(statement) }.with_max_rows(max_rows),
+            )),
+            Ok(false) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Advances `stmt` to the next result with columns. `false` if there is none.
+fn next_result_set(stmt: &mut impl Statement) -> Result<bool, Error> {
+    while stmt.more_results().into_result(stmt)? {
+        if stmt.num_result_cols().into_result(stmt)? != 0 {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+impl<'o> CursorImpl<Prepared<'o>> {
+    /// Closes the cursor and returns the prepared statement it has been created from. The prepared
+    /// statement can then be executed again, e.g. with different parameters, without the need to
+    /// prepare the query text a second time. See [`crate::Prepared::into_cursor`].
+    pub fn close_and_reuse(self) -> Result<Prepared<'o>, Error> {
+        self.close()
+    }
 }
 
 /// A Row set buffer binds row, or column wise buffers to a cursor in order to fill them with row
@@ -327,6 +744,21 @@ pub unsafe trait RowSetBuffer {
     /// It's the implementations responsibility to ensure that all bound buffers are valid until
     /// unbound or the statement handle is deleted.
     unsafe fn bind_to_cursor(&mut self, cursor: &mut impl Cursor) -> Result<(), Error>;
+
+    /// Column number and indicator of the first value in the rows fetched last, which has been
+    /// truncated because it did not fit into its buffer. `None` if no value has been truncated. Used
+    /// by [`RowSetCursor::fetch_with_truncation_check`]. The default implementation always returns
+    /// `None`.
+    fn find_truncation(&self) -> Option<(u16, Indicator)> {
+        None
+    }
+
+    /// Number of columns bound by this buffer. Used by [`Cursor::bind_buffer`] to verify that
+    /// every column of the result set is bound. The default implementation returns `None`, which
+    /// skips the check.
+    fn num_bound_columns(&self) -> Option<usize> {
+        None
+    }
 }
 
 unsafe impl<T: RowSetBuffer> RowSetBuffer for &mut T {
@@ -345,6 +777,14 @@ unsafe impl<T: RowSetBuffer> RowSetBuffer for &mut T {
     unsafe fn bind_to_cursor(&mut self, cursor: &mut impl Cursor) -> Result<(), Error> {
         (*self).bind_to_cursor(cursor)
     }
+
+    fn find_truncation(&self) -> Option<(u16, Indicator)> {
+        (**self).find_truncation()
+    }
+
+    fn num_bound_columns(&self) -> Option<usize> {
+        (**self).num_bound_columns()
+    }
 }
 
 /// A row set cursor iterates in blocks over row sets, filling them in buffers, instead of iterating
@@ -352,14 +792,38 @@ unsafe impl<T: RowSetBuffer> RowSetBuffer for &mut T {
 pub struct RowSetCursor<C: Cursor, B> {
     buffer: B,
     cursor: C,
+    /// Number of rows which may still be fetched, if the cursor limits the rows fetched on the
+    /// client side. `None` if unlimited.
+    remaining_rows: Option<usize>,
 }
 
 impl<C, B> RowSetCursor<C, B>
 where
     C: Cursor,
 {
-    fn new(buffer: B, cursor: C) -> Self {
-        Self { buffer, cursor }
+    fn new(buffer: B, cursor: C, remaining_rows: Option<usize>) -> Self {
+        Self {
+            buffer,
+            cursor,
+            remaining_rows,
+        }
+    }
+
+    /// Fetches the next row set into the buffer. `false` if there are no more rows, or the row
+    /// limit has been reached. Truncates the row set, if it holds rows beyond the limit.
+    fn fetch_row_set(&mut self) -> Result<bool, Error>
+    where
+        B: RowSetBuffer,
+    {
+        if self.remaining_rows == Some(0) || !fetch_next_row(&mut self.cursor)? {
+            return Ok(false);
+        }
+        if let Some(remaining_rows) = &mut self.remaining_rows {
+            let num_rows = self.buffer.mut_num_fetch_rows();
+            *num_rows = (*num_rows).min(*remaining_rows);
+            *remaining_rows -= *num_rows;
+        }
+        Ok(true)
     }
 
     /// Fills the bound buffer with the next row set.
@@ -368,15 +832,201 @@ where
     ///
     /// `None` if the result set is empty and all row sets have been extracted. `Some` with a
     /// reference to the internal buffer otherwise.
-    pub fn fetch(&mut self) -> Result<Option<&B>, Error> {
+    pub fn fetch(&mut self) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        if self.fetch_row_set()? {
+            Ok(Some(&self.buffer))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Fills the bound buffer with the next row set, like [`Self::fetch`]. Yet instead of silently
+    /// returning truncated values, an [`Error::TextTruncation`] is returned if any value did not
+    /// fit into its buffer. Use this for exports, which must not lose any data.
+    ///
+    /// # Return
+    ///
+    /// `None` if the result set is empty and all row sets have been extracted. `Some` with a
+    /// reference to the internal buffer otherwise.
+    pub fn fetch_with_truncation_check(&mut self) -> Result<Option<&B>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        if !self.fetch_row_set()? {
+            return Ok(None);
+        }
+        if let Some((column, indicator)) = self.buffer.find_truncation() {
+            let needed_len = match indicator {
+                Indicator::Length(length) => Some(length),
+                Indicator::Null | Indicator::NoTotal => None,
+            };
+            return Err(Error::TextTruncation { column, needed_len });
+        }
+        Ok(Some(&self.buffer))
+    }
+
+    /// Positions the cursor on a row of the row set fetched last and returns it. Use this to fetch
+    /// values of columns which are not bound to the buffer via [`CursorRow::get_data`] and its
+    /// siblings, e.g. large binary values you do not want to allocate buffers for, for each row in
+    /// the batch.
+    ///
+    /// Fetching unbound columns of a cursor which fetches more than one row at once requires the
+    /// driver to support `SQL_GD_BLOCK` (see `SQL_GETDATA_EXTENSIONS` in the documentation of
+    /// `SQLGetInfo`). Many drivers also require that unbound columns come after all bound columns.
+    ///
+    /// # Parameters
+    ///
+    /// * `row_index`: Zero based index of the row within the current row set. Fails with
+    ///   [`Error::RowIndexOutOfBounds`] unless it is smaller than the number of rows fetched last.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{
+    ///     buffers::{buffer_from_description_and_indices, BufferDescription, BufferKind, Item},
+    ///     Cursor, Error,
+    /// };
+    /// use std::iter;
+    ///
+    /// /// Fetches the first column in batches, and the second lazily, row by row.
+    /// fn fetch_lazily(cursor: impl Cursor) -> Result<Vec<(i32, Vec<u8>)>, Error> {
+    ///     let desc = BufferDescription {
+    ///         kind: BufferKind::I32,
+    ///         nullable: false,
+    ///     };
+    ///     let buffer = buffer_from_description_and_indices(100, iter::once((1, desc)));
+    ///     let mut row_set_cursor = cursor.bind_buffer(buffer)?;
+    ///     let mut rows = Vec::new();
+    ///     while let Some(batch) = row_set_cursor.fetch()? {
+    ///         let ids = i32::as_slice(batch.column(0)).unwrap().to_vec();
+    ///         for (row_index, id) in ids.into_iter().enumerate() {
+    ///             let mut blob = Vec::new();
+    ///             row_set_cursor.row_at(row_index)?.get_binary(2, &mut blob)?;
+    ///             rows.push((id, blob));
+    ///         }
+    ///     }
+    ///     Ok(rows)
+    /// }
+    /// ```
+    pub fn row_at(&mut self, row_index: usize) -> Result<CursorRow<'_, C::Statement>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        let num_rows = *self.buffer.mut_num_fetch_rows();
+        if row_index >= num_rows {
+            return Err(Error::RowIndexOutOfBounds {
+                row_index,
+                num_rows,
+            });
+        }
         unsafe {
-            if let Some(res) = self.cursor.stmt_mut().fetch() {
-                res.into_result(self.cursor.stmt_mut())?;
-                Ok(Some(&self.buffer))
-            } else {
-                Ok(None)
-            }
+            let stmt = self.cursor.stmt_mut();
+            stmt.set_pos(row_index + 1, Operation::Position, Lock::NoChange)
+                .into_result(stmt)?;
+            Ok(CursorRow::new(self.cursor.stmt_mut()))
+        }
+    }
+
+    /// Reads the value of a column, which is not bound to the buffer, for one row of the row set
+    /// fetched last. A buffer only binds the columns it has been created for, so if it covers just
+    /// the first columns of the result set, any trailing column can be read lazily using this
+    /// method. Useful for columns which are large, or rarely needed.
+    ///
+    /// This positions the cursor on the row first, like [`Self::row_at`], and then calls
+    /// [`CursorRow::get_data`]. The same driver requirements apply: Fetching more than one row at
+    /// once requires `SQL_GD_BLOCK` support, and the column must come after all bound columns for
+    /// most drivers.
+    ///
+    /// # Parameters
+    ///
+    /// * `col_or_param_num`: One based index of the unbound column.
+    /// * `row_index`: Zero based index of the row within the current row set. Fails with
+    ///   [`Error::RowIndexOutOfBounds`] unless it is smaller than the number of rows fetched last.
+    /// * `target`: Receives the value of the field.
+    pub fn extra_column(
+        &mut self,
+        col_or_param_num: u16,
+        row_index: usize,
+        target: &mut impl OutputParameter,
+    ) -> Result<(), Error>
+    where
+        B: RowSetBuffer,
+    {
+        self.row_at(row_index)?.get_data(col_or_param_num, target)
+    }
+
+    /// Fetches all remaining row sets and appends one element per row to `rows`. `map` is called
+    /// for every row with the buffer holding the current row set and the zero based index of the
+    /// row within it. The buffer is only borrowed for the duration of the call, so `map` must copy
+    /// out everything it wants to keep.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{buffers::TextRowSet, Cursor, Error};
+    ///
+    /// /// Collects the rows of a result set with two columns as pairs of owned strings.
+    /// fn collect_pairs(cursor: impl Cursor) -> Result<Vec<(String, String)>, Error> {
+    ///     let buffer = TextRowSet::for_cursor(100, &cursor, Some(4096))?;
+    ///     let mut row_set_cursor = cursor.bind_buffer(buffer)?;
+    ///     let mut pairs = Vec::new();
+    ///     row_set_cursor.fetch_into(&mut pairs, |batch, row_index| {
+    ///         let text = |col_index| {
+    ///             batch
+    ///                 .at_as_str_lossy(col_index, row_index)
+    ///                 .unwrap_or_default()
+    ///                 .into_owned()
+    ///         };
+    ///         (text(0), text(1))
+    ///     })?;
+    ///     Ok(pairs)
+    /// }
+    /// ```
+    pub fn fetch_into<T>(
+        &mut self,
+        rows: &mut impl Extend<T>,
+        mut map: impl FnMut(&B, usize) -> T,
+    ) -> Result<(), Error>
+    where
+        B: RowSetBuffer,
+    {
+        while self.fetch()?.is_some() {
+            let num_rows = *self.buffer.mut_num_fetch_rows();
+            let buffer = &self.buffer;
+            rows.extend((0..num_rows).map(|row_index| map(buffer, row_index)));
         }
+        Ok(())
+    }
+}
+
+impl<C, B> RowSetCursor<C, B>
+where
+    C: Cursor,
+{
+    /// Unbinds the buffer from the cursor and returns both of them. Allows to continue using the
+    /// cursor without the buffer, e.g. to move on to the next result set using
+    /// [`CursorImpl::more_results`].
+    pub fn unbind(self) -> Result<(C, B), Error> {
+        // We unbind the buffer ourselves, so we must not run the `Drop` implementation, which would
+        // try to do it a second time.
+        let mut row_set_cursor = ManuallyDrop::new(self);
+        let result = unsafe {
+            let stmt = row_set_cursor.cursor.stmt_mut();
+            stmt.unbind_cols()
+                .into_result(stmt)
+                .and_then(|()| stmt.set_num_rows_fetched(None).into_result(stmt))
+        };
+        // Safe: `row_set_cursor` is never dropped, so each member is moved out exactly once.
+        let (cursor, buffer) = unsafe {
+            (
+                ptr::read(&row_set_cursor.cursor),
+                ptr::read(&row_set_cursor.buffer),
+            )
+        };
+        result.map(|()| (cursor, buffer))
     }
 }
 
@@ -401,3 +1051,116 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, ptr::null_mut};
+
+    use odbc_sys::{HStmt, Handle, HandleType, SqlDataType};
+
+    use crate::{
+        borrow_mut_statement::BorrowMutStatement,
+        handles::{AsHandle, SqlResult, Statement},
+        DataType, ResultSetMetadata,
+    };
+
+    use super::CursorImpl;
+
+    /// Answers column attribute requests without a driver and counts how often it has been asked.
+    #[derive(Default)]
+    struct CountingStatement {
+        concise_type_calls: Cell<usize>,
+        octet_length_calls: Cell<usize>,
+        remaining_result_sets: usize,
+    }
+
+    unsafe impl AsHandle for CountingStatement {
+        fn as_handle(&self) -> Handle {
+            null_mut()
+        }
+
+        fn handle_type(&self) -> HandleType {
+            HandleType::Stmt
+        }
+    }
+
+    impl Statement for CountingStatement {
+        fn as_sys(&self) -> HStmt {
+            null_mut()
+        }
+
+        fn col_concise_type(&self, _column_number: u16) -> SqlResult<SqlDataType> {
+            self.concise_type_calls
+                .set(self.concise_type_calls.get() + 1);
+            SqlResult::Success(SqlDataType::EXT_VAR_BINARY)
+        }
+
+        fn col_octet_length(&self, _column_number: u16) -> SqlResult<isize> {
+            self.octet_length_calls
+                .set(self.octet_length_calls.get() + 1);
+            SqlResult::Success(42)
+        }
+
+        fn num_result_cols(&self) -> SqlResult<i16> {
+            SqlResult::Success(1)
+        }
+
+        fn more_results(&mut self) -> SqlResult<bool> {
+            let more = self.remaining_result_sets != 0;
+            self.remaining_result_sets = self.remaining_result_sets.saturating_sub(1);
+            SqlResult::Success(more)
+        }
+
+        fn close_cursor(&mut self) -> SqlResult<()> {
+            SqlResult::Success(())
+        }
+    }
+
+    impl BorrowMutStatement for CountingStatement {
+        type Statement = Self;
+
+        fn borrow(&self) -> &Self {
+            self
+        }
+
+        fn borrow_mut(&mut self) -> &mut Self {
+            self
+        }
+    }
+
+    #[test]
+    fn column_attributes_are_queried_only_once() {
+        let cursor = unsafe { CursorImpl::new(CountingStatement::default()) };
+
+        assert_eq!(
+            DataType::Varbinary { length: 42 },
+            cursor.col_data_type(1).unwrap()
+        );
+        assert_eq!(
+            DataType::Varbinary { length: 42 },
+            cursor.col_data_type(1).unwrap()
+        );
+        assert_eq!(42, cursor.col_octet_length(1).unwrap());
+
+        let stmt = cursor.stmt_ref();
+        assert_eq!(1, stmt.concise_type_calls.get());
+        assert_eq!(1, stmt.octet_length_calls.get());
+    }
+
+    #[test]
+    fn more_results_invalidates_cached_column_attributes() {
+        let statement = CountingStatement {
+            remaining_result_sets: 1,
+            ..CountingStatement::default()
+        };
+        let cursor = unsafe { CursorImpl::new(statement) };
+        cursor.col_data_type(1).unwrap();
+
+        let cursor = cursor.more_results().unwrap().unwrap();
+        cursor.col_data_type(1).unwrap();
+
+        let stmt = cursor.stmt_ref();
+        assert_eq!(2, stmt.concise_type_calls.get());
+        assert_eq!(2, stmt.octet_length_calls.get());
+    }
+}
@@ -3,7 +3,10 @@
 pub enum DriverCompleteOption {
     /// Do not show a prompt to the user. This implies that the connection string, must already
     /// provide all information needed to Connect to the data source, otherwise the operation fails.
-    /// This is the only supported variant on non windows platforms
+    ///
+    /// On non windows platforms no prompt is shown for any of the variants. `Complete` and
+    /// `CompleteRequired` still allow the driver to complete the connection string using the
+    /// information available to it without user interaction.
     NoPrompt,
     /// Always show a prompt to the user.
     Prompt,
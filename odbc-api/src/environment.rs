@@ -1,8 +1,13 @@
-use std::{cmp::max, collections::HashMap, ptr::null_mut, sync::Mutex};
+use std::{
+    cmp::max, collections::HashMap, ptr::null_mut, str::FromStr, sync::Mutex, thread::sleep,
+};
+#[cfg(feature = "dynamic-driver-manager")]
+use std::{ffi::OsStr, path::Path};
 
 use crate::{
     handles::{self, log_diagnostics, OutputStringBuffer, SqlResult, State},
-    Connection, DriverCompleteOption, Error,
+    BrowseConnect, BrowseConnection, Connection, ConnectionBuilder, ConnectionOptions,
+    DriverCompleteOption, Error, RetryPolicy,
 };
 use log::debug;
 use odbc_sys::{AttrCpMatch, AttrOdbcVersion, FetchOrientation, HWnd};
@@ -88,6 +93,8 @@ impl Environment {
     pub unsafe fn set_connection_pooling(
         scheme: odbc_sys::AttrConnectionPooling,
     ) -> Result<(), Error> {
+        #[cfg(feature = "dynamic-driver-manager")]
+        load_driver_manager(OsStr::new(handles::DEFAULT_DRIVER_MANAGER))?;
         match handles::Environment::set_connection_pooling(scheme) {
             SqlResult::Error { .. } => Err(Error::FailedSettingConnectionPooling),
             SqlResult::Success(()) | SqlResult::SuccessWithInfo(()) => Ok(()),
@@ -127,6 +134,31 @@ impl Environment {
     ///
     /// Creating one environment in your binary is safe however.
     pub fn new() -> Result<Self, Error> {
+        Self::new_with_version(AttrOdbcVersion::Odbc3_80)
+    }
+
+    /// Like [`Self::new`], but declares `version` as the ODBC version the application wants to use,
+    /// instead of ODBC 3.8. This affects the behavior of the driver manager and the driver, e.g.
+    /// which SQLSTATE codes are returned. It is intended for legacy drivers, which misbehave if an
+    /// application declares a newer version than they support.
+    ///
+    /// The version is declared right after allocating the environment, so it applies to every
+    /// connection made with it. ODBC 3.x is strongly recommended, as this crate is written against
+    /// the behavior of ODBC 3.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Environment, sys::AttrOdbcVersion};
+    ///
+    /// let env = Environment::new_with_version(AttrOdbcVersion::Odbc3)?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn new_with_version(version: AttrOdbcVersion) -> Result<Self, Error> {
+        // Use the default driver manager, unless the application has loaded one explicitly.
+        #[cfg(feature = "dynamic-driver-manager")]
+        load_driver_manager(OsStr::new(handles::DEFAULT_DRIVER_MANAGER))?;
+
         let result = handles::Environment::new();
 
         let environment = match result {
@@ -141,7 +173,7 @@ impl Environment {
         debug!("ODBC Environment created.");
 
         let result = environment
-            .declare_version(AttrOdbcVersion::Odbc3_80)
+            .declare_version(version)
             .into_result(&environment);
 
         // Translate invalid attribute into a more meaningful error, provided the additional
@@ -164,6 +196,35 @@ impl Environment {
         })
     }
 
+    /// Like [`Self::new`], but routes all calls to the ODBC driver manager loaded at runtime from
+    /// the shared library at `path`, rather than to the one linked at build time. This allows a
+    /// single binary to use either e.g. unixODBC (`libodbc.so.2`) or iODBC (`libiodbc.so.2`).
+    /// Requires the `dynamic-driver-manager` feature. With this feature enabled, [`Self::new`]
+    /// loads [`crate::DEFAULT_DRIVER_MANAGER`].
+    ///
+    /// The driver manager is loaded only once per process and stays loaded. Fails with
+    /// [`Error::DriverManagerAlreadyLoaded`] if a different one has been loaded before.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    ///
+    /// let env = Environment::with_driver_manager("libiodbc.so.2")?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    #[cfg(feature = "dynamic-driver-manager")]
+    pub fn with_driver_manager(path: impl AsRef<OsStr>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let loaded = load_driver_manager(path)?;
+        if loaded != Path::new(path) {
+            return Err(Error::DriverManagerAlreadyLoaded {
+                loaded: loaded.to_owned(),
+            });
+        }
+        Self::new()
+    }
+
     /// Allocates a connection handle and establishes connections to a driver and a data source.
     ///
     /// * See [Connecting with SQLConnect][1]
@@ -253,11 +314,29 @@ impl Environment {
     /// let mut conn = env.connect_with_connection_string(connection_string)?;
     /// # Ok::<(), odbc_api::Error>(())
     /// ```
+    ///
+    /// Use [`crate::ConnectionString`] to assemble the connection string from individual
+    /// attributes, escaping their values properly.
+    ///
+    /// ```no_run
+    /// use odbc_api::{ConnectionString, Environment};
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let connection_string = ConnectionString::new()
+    ///     .driver("ODBC Driver 17 for SQL Server")
+    ///     .server("localhost")
+    ///     .uid("SA")
+    ///     .pwd("<YourStrong@Passw0rd>");
+    ///
+    /// let mut conn = env.connect_with_connection_string(&connection_string)?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
     pub fn connect_with_connection_string(
         &self,
-        connection_string: &str,
+        connection_string: impl AsRef<str>,
     ) -> Result<Connection<'_>, Error> {
-        let connection_string = U16String::from_str(connection_string);
+        let connection_string = U16String::from_str(connection_string.as_ref());
         self.connect_with_connection_string_utf16(&connection_string)
     }
 
@@ -279,6 +358,119 @@ impl Environment {
         Ok(Connection::new(connection))
     }
 
+    /// Allocates a connection handle, applies `options` to it and establishes connections to a
+    /// driver and a data source using a connection string. See
+    /// [`Self::connect_with_connection_string`].
+    ///
+    /// # Example
+    ///
+    /// Give up after five seconds, if the data source can not be reached.
+    ///
+    /// ```no_run
+    /// use odbc_api::{ConnectionOptions, Environment};
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let connection_string = "
+    ///     Driver={ODBC Driver 17 for SQL Server};\
+    ///     Server=localhost;\
+    ///     UID=SA;\
+    ///     PWD=<YourStrong@Passw0rd>;\
+    /// ";
+    ///
+    /// let options = ConnectionOptions {
+    ///     login_timeout_sec: Some(5),
+    ///     ..ConnectionOptions::default()
+    /// };
+    /// let mut conn = env.connect_with_options(connection_string, options)?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn connect_with_options(
+        &self,
+        connection_string: &str,
+        options: ConnectionOptions,
+    ) -> Result<Connection<'_>, Error> {
+        self.connection_builder(options)
+            .connect_with_connection_string(connection_string)
+    }
+
+    /// A builder creating connections which are all configured with `options`. Use this rather
+    /// than [`Self::connect_with_options`] if you open many connections with the same options.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{AccessMode, ConnectionOptions, Environment};
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let read_only = env.connection_builder(ConnectionOptions {
+    ///     access_mode: Some(AccessMode::ReadOnly),
+    ///     ..ConnectionOptions::default()
+    /// });
+    /// let conn = read_only.connect("YourDatabase", "SA", "<YourStrong@Passw0rd>")?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn connection_builder(&self, options: ConnectionOptions) -> ConnectionBuilder<'_> {
+        ConnectionBuilder::new(self, options)
+    }
+
+    /// Connects to a data source using a connection string and calls `f` with the connection. If
+    /// either connecting or `f` fail with a transient connection error (see
+    /// [`Error::is_transient_connection_error`]), the connection is established anew and `f` is
+    /// called again, until it succeeds or the maximum number of attempts specified in `policy` is
+    /// reached.
+    ///
+    /// Since `f` may be called multiple times, it should be safe to repeat the work it does. E.g.
+    /// by performing it within a single transaction.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Environment, RetryPolicy};
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let connection_string = "
+    ///     Driver={ODBC Driver 17 for SQL Server};\
+    ///     Server=localhost;\
+    ///     UID=SA;\
+    ///     PWD=<YourStrong@Passw0rd>;\
+    /// ";
+    ///
+    /// env.connect_with_retry(connection_string, RetryPolicy::default(), |conn| {
+    ///     conn.execute("UPDATE Birthdays SET year = 1993 WHERE name = 'Jurassic Park';", ())?;
+    ///     Ok(())
+    /// })?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn connect_with_retry<T>(
+        &self,
+        connection_string: &str,
+        policy: RetryPolicy,
+        mut f: impl FnMut(&Connection<'_>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut attempt = 1;
+        loop {
+            let result = self
+                .connect_with_connection_string(connection_string)
+                .and_then(|conn| f(&conn));
+            match result {
+                Err(error)
+                    if attempt < policy.max_attempts && error.is_transient_connection_error() =>
+                {
+                    debug!(
+                        "Attempt {} failed with transient connection error. Reconnecting. {}",
+                        attempt, error
+                    );
+                    sleep(policy.backoff(attempt));
+                    attempt += 1;
+                }
+                other => break other,
+            }
+        }
+    }
+
     /// Allocates a connection handle and establishes connections to a driver and a data source.
     ///
     /// An alternative to `connect` and `connect_with_connection_string`. This method can be
@@ -298,11 +490,21 @@ impl Environment {
     ///   time of this writing:
     ///   * Maria DB crashes with STATUS_TACK_BUFFER_OVERRUN
     ///   * SQLite does not change the output buffer at all and does not indicate truncation.
+    ///
+    ///   The size of the completed connection string can not be probed in advance, since
+    ///   `SQLDriverConnect` already establishes the connection. Use
+    ///   [`OutputStringBuffer::is_truncated`] to check whether the buffer has been large enough.
+    ///   Be aware that the completed connection string usually contains the password, so take
+    ///   care not to log or persist it carelessly.
     /// * `driver_completion`: Specifies how and if the driver manager uses a prompt to complete
     ///   the provided connection string. For arguments other than
     ///   [`crate::DriverCompleteOption::NoPrompt`] this method is going to create a message only
-    ///   parent window for you on windows. On other platform this method is going to panic. In case
-    ///   you want to provide your own parent window please use [`Self::driver_connect_with_hwnd`].
+    ///   parent window for you on windows. In case you want to provide your own parent window
+    ///   please use [`Self::driver_connect_with_hwnd`]. On other platforms no prompt is shown. The
+    ///   driver completes the connection string with the information it finds, e.g. in the DSN,
+    ///   and fails if that is not sufficient. The completed connection string is written to
+    ///   `completed_connection_string` on all platforms, so applications may implement their own
+    ///   prompt. See also [`Self::browse_connect`].
     ///
     /// # Examples
     ///
@@ -383,37 +585,15 @@ impl Environment {
         completed_connection_string: Option<&mut OutputStringBuffer>,
         driver_completion: DriverCompleteOption,
     ) -> Result<Connection<'_>, Error> {
-        #[cfg(target_os = "windows")]
-        let parent_window = match driver_completion {
-            DriverCompleteOption::NoPrompt => None,
-            _ => {
-                if !cfg!(target_os = "windows") {
-                    panic!("Prompt is not supported on non windows platforms. Use `NoPrompt`.")
-                }
-                // We need a parent window, let's provide a message only window.
-                Some(
-                    WindowBuilder::new()
-                        .with_visible(false)
-                        .build(&EventLoop::new())
-                        .unwrap(),
-                )
-            }
-        };
-        #[cfg(target_os = "windows")]
-        let hwnd = parent_window
-            .as_ref()
-            .map(|window| window.hwnd())
-            .unwrap_or_else(null_mut);
-        #[cfg(not(target_os = "windows"))]
-        let hwnd = null_mut();
-        unsafe {
+        let may_prompt = !matches!(driver_completion, DriverCompleteOption::NoPrompt);
+        with_parent_window(may_prompt, |hwnd| unsafe {
             self.driver_connect_with_hwnd(
                 connection_string,
                 completed_connection_string,
                 driver_completion,
                 hwnd,
             )
-        }
+        })
     }
 
     /// Allows to call driver connect with a user supplied HWnd. Same as [`Self::driver_connect`],
@@ -432,7 +612,34 @@ impl Environment {
         driver_completion: DriverCompleteOption,
         parent_window: HWnd,
     ) -> Result<Connection<'_>, Error> {
+        let connection = self.driver_connect_handle(
+            connection_string,
+            completed_connection_string,
+            driver_completion,
+            parent_window,
+            &ConnectionOptions::default(),
+        )?;
+        Ok(Connection::new(connection))
+    }
+
+    /// Allocates a connection handle, applies `options` to it and connects using
+    /// `SQLDriverConnect`. Shared by [`Self::driver_connect_with_hwnd`] and
+    /// [`ConnectionBuilder::driver_connect`].
+    ///
+    /// # Safety
+    ///
+    /// `parent_window` must be null or a valid window handle. See
+    /// [`Self::driver_connect_with_hwnd`].
+    pub(crate) unsafe fn driver_connect_handle(
+        &self,
+        connection_string: &str,
+        completed_connection_string: Option<&mut OutputStringBuffer>,
+        driver_completion: DriverCompleteOption,
+        parent_window: HWnd,
+        options: &ConnectionOptions,
+    ) -> Result<handles::Connection<'_>, Error> {
         let mut connection = self.allocate_connection()?;
+        options.apply(&connection)?;
         let connection_string = U16String::from_str(connection_string);
 
         connection
@@ -444,7 +651,41 @@ impl Environment {
             )
             .map(|res| res.into_result(&connection))
             .unwrap_or(Err(Error::AbortedConnectionStringCompletion))?;
-        Ok(Connection::new(connection))
+        Ok(connection)
+    }
+
+    /// Discovers the attributes required to connect to a data source iteratively, by calling
+    /// `SQLBrowseConnect`. Unlike [`Self::driver_connect`] this does not rely on the driver showing
+    /// a prompt, but lets the application ask for the missing attributes. Therefore it is also
+    /// supported on non windows platforms.
+    ///
+    /// `connection_string` must at least specify `DRIVER` or `DSN`. The driver answers with the
+    /// attributes still required, which are passed on using [`BrowseConnection::browse`], until the
+    /// connection is established.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{BrowseConnect, Environment};
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let mut step = env.browse_connect("DRIVER={ODBC Driver 17 for SQL Server};")?;
+    /// let connection = loop {
+    ///     match step {
+    ///         BrowseConnect::NeedData(browse) => {
+    ///             // Usually we would ask the user for the required attributes.
+    ///             println!("Missing attributes: {}", browse.attributes());
+    ///             step = browse.browse("SERVER=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;")?;
+    ///         }
+    ///         BrowseConnect::Connected(connection) => break connection,
+    ///     }
+    /// };
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn browse_connect(&self, connection_string: &str) -> Result<BrowseConnect<'_>, Error> {
+        let connection = self.allocate_connection()?;
+        BrowseConnection::step(connection, connection_string)
     }
 
     /// Get information about available drivers. Only 32 or 64 Bit drivers will be listed, depending
@@ -518,6 +759,26 @@ impl Environment {
         Ok(driver_info)
     }
 
+    /// Like [`Self::drivers`], but only returns the drivers for which `predicate` returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    ///
+    /// let env = Environment::new()?;
+    /// let postgres_drivers = env.drivers_filtered(|driver| {
+    ///     driver.description.contains("PostgreSQL")
+    /// })?;
+    /// # Ok::<_, odbc_api::Error>(())
+    /// ```
+    pub fn drivers_filtered(
+        &self,
+        predicate: impl FnMut(&DriverInfo) -> bool,
+    ) -> Result<Vec<DriverInfo>, Error> {
+        Ok(self.drivers()?.into_iter().filter(predicate).collect())
+    }
+
     /// User and system data sources
     ///
     /// # Example
@@ -638,7 +899,7 @@ impl Environment {
         Ok(data_source_info)
     }
 
-    fn allocate_connection(&self) -> Result<handles::Connection, Error> {
+    pub(crate) fn allocate_connection(&self) -> Result<handles::Connection, Error> {
         // Hold lock diagnostics errors are consumed in this thread.
         let _lock = self.internal_state.lock().unwrap();
         self.environment
@@ -647,6 +908,34 @@ impl Environment {
     }
 }
 
+/// Calls `f` with a parent window handle. On windows a message only window is created for the
+/// duration of the call, if the driver may prompt the user (i.e. the completion option is not
+/// [`DriverCompleteOption::NoPrompt`]). Without a parent window (i.e. a null handle) the driver
+/// manager completes the connection string without prompting the user.
+#[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+pub(crate) fn with_parent_window<T>(may_prompt: bool, f: impl FnOnce(HWnd) -> T) -> T {
+    #[cfg(target_os = "windows")]
+    let parent_window = if may_prompt {
+        // We need a parent window, let's provide a message only window.
+        Some(
+            WindowBuilder::new()
+                .with_visible(false)
+                .build(&EventLoop::new())
+                .unwrap(),
+        )
+    } else {
+        None
+    };
+    #[cfg(target_os = "windows")]
+    let hwnd = parent_window
+        .as_ref()
+        .map(|window| window.hwnd())
+        .unwrap_or_else(null_mut);
+    #[cfg(not(target_os = "windows"))]
+    let hwnd = null_mut();
+    f(hwnd)
+}
+
 /// Struct holding information available on a driver. Can be obtained via [`Environment::drivers`].
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DriverInfo {
@@ -656,6 +945,57 @@ pub struct DriverInfo {
     pub attributes: HashMap<String, String>,
 }
 
+impl DriverInfo {
+    /// Value of the attribute with the given key. In contrast to indexing [`Self::attributes`]
+    /// directly, keys are compared case insensitive, since driver managers differ in the case they
+    /// report them in.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// `APILevel`. ODBC interface conformance level supported by the driver. `0` for none, `1` for
+    /// level 1 and `2` for level 2 conformance.
+    pub fn api_level(&self) -> Option<u8> {
+        self.parse_attribute("APILevel")
+    }
+
+    /// `FileUsage`. `0` if the driver is not single-tier, `1` if it treats files in a data source
+    /// as tables and `2` if it treats files as catalogs.
+    pub fn file_usage(&self) -> Option<u8> {
+        self.parse_attribute("FileUsage")
+    }
+
+    /// `SQLLevel`. SQL-92 grammar supported by the driver. `0` for entry level, `1` for FIPS127-2
+    /// transitional, `2` for intermediate and `3` for full conformance.
+    pub fn sql_level(&self) -> Option<u8> {
+        self.parse_attribute("SQLLevel")
+    }
+
+    /// `CPTimeout`. Time in seconds a connection remains in the connection pool unused.
+    pub fn connection_pool_timeout(&self) -> Option<u32> {
+        self.parse_attribute("CPTimeout")
+    }
+
+    /// `UsageCount`. Number of installations of the driver, as tracked by the driver manager.
+    pub fn usage_count(&self) -> Option<u32> {
+        self.parse_attribute("UsageCount")
+    }
+
+    /// `Driver`. Path to the shared library implementing the driver.
+    pub fn driver_path(&self) -> Option<&str> {
+        self.attribute("Driver")
+    }
+
+    /// `None` if the attribute is missing, or could not be parsed.
+    fn parse_attribute<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.attribute(key)
+            .and_then(|value| value.trim().parse().ok())
+    }
+}
+
 /// Holds name and description of a datasource
 ///
 /// Can be obtained via [`Environment::data_sources`]
@@ -682,6 +1022,20 @@ fn attributes_iter(attributes: &str) -> impl Iterator<Item = (String, String)> +
         })
 }
 
+/// Loads the driver manager at `path`, unless one has been loaded already. Returns the path of the
+/// driver manager in use.
+#[cfg(feature = "dynamic-driver-manager")]
+fn load_driver_manager(path: &OsStr) -> Result<&'static Path, Error> {
+    // Loading a library runs its initialization routines. We trust the application to point us to
+    // an ODBC driver manager.
+    unsafe { handles::load_driver_manager(path) }.map_err(|source| {
+        Error::FailedLoadingDriverManager {
+            path: path.into(),
+            source,
+        }
+    })
+}
+
 #[cfg(test)]
 mod test {
 
@@ -700,4 +1054,22 @@ mod test {
         assert_eq!(attributes["SQLLevel"], "1");
         assert_eq!(attributes["UsageCount"], "1");
     }
+
+    #[test]
+    fn typed_driver_attributes() {
+        let buffer = "apilevel=2\0FILEUSAGE=1\0SQLLevel=x\0CPTimeout=60\0\0";
+        let driver_info = DriverInfo {
+            description: "Some Driver".to_string(),
+            attributes: attributes_iter(buffer).collect(),
+        };
+        assert_eq!(Some("2"), driver_info.attribute("APILevel"));
+        assert_eq!(Some(2), driver_info.api_level());
+        assert_eq!(Some(1), driver_info.file_usage());
+        assert_eq!(Some(60), driver_info.connection_pool_timeout());
+        // Invalid values
+        assert_eq!(None, driver_info.sql_level());
+        // Missing attributes
+        assert_eq!(None, driver_info.usage_count());
+        assert_eq!(None, driver_info.driver_path());
+    }
 }
@@ -1,8 +1,12 @@
-use std::io;
+use std::{fmt::Display, io};
 
 use thiserror::Error as ThisError;
 
-use crate::handles::{log_diagnostics, AsHandle, Record as DiagnosticRecord, SqlResult};
+use crate::handles::{log_diagnostics, AsHandle, Record as DiagnosticRecord, SqlResult, State};
+
+/// Maximum number of characters of the query text attached to [`Error::FailedExecutingQuery`].
+/// Longer queries are truncated.
+const MAX_QUERY_LEN_IN_ERROR: usize = 1000;
 
 #[derive(Debug, ThisError)]
 /// Error type used to indicate a low level ODBC call returned with SQL_ERROR.
@@ -36,13 +40,27 @@ pub enum Error {
         /// ODBC API call which produced the diagnostic record
         function: &'static str,
     },
+    /// The driver does not support an optional feature or function required by the call, i.e. it
+    /// returned SQLSTATE `HYC00` (optional feature not implemented) or `IM001` (driver does not
+    /// support this function). Callers may fall back to an alternative which does not require the
+    /// feature. E.g. not all drivers support scrollable cursors, asynchronous execution or
+    /// `SQLSetPos`.
+    #[error(
+        "The ODBC driver does not support an optional feature required by '{function}':\n{record}"
+    )]
+    UnsupportedOptionalFeature {
+        /// Diagnostic record returned by the ODBC driver manager
+        record: DiagnosticRecord,
+        /// ODBC API call which produced the diagnostic record
+        function: &'static str,
+    },
     /// A user dialog to complete the connection string has been aborted.
     #[error("The dialog shown to provide or complete the connection string has been aborted.")]
     AbortedConnectionStringCompletion,
     /// An error returned if we fail to set the ODBC version
     #[error(
-        "ODBC diver manager does not seem to support the required ODBC version 3.80. (Most \
-        likely you need to update unixODBC if you run on a Linux. Diagnostic record returned by \
+        "ODBC diver manager does not seem to support the required ODBC version. (Most likely you \
+        need to update unixODBC if you run on a Linux. Diagnostic record returned by \
         SQLSetEnvAttr:\n{0}"
     )]
     UnsupportedOdbcApiVersion(DiagnosticRecord),
@@ -63,6 +81,196 @@ pub enum Error {
         record: DiagnosticRecord,
         size: usize,
     },
+    /// A column expected by name (e.g. by a struct implementing [`crate::FromRow`]) is not part of
+    /// the result set.
+    #[error("The result set does not contain a column named '{name}'.")]
+    ColumnNotFound { name: String },
+    /// A column containing `NULL` has been read into a field of a struct implementing
+    /// [`crate::FromRow`], which is not an `Option`.
+    #[error(
+        "Column '{column}' contains NULL, but the field it is read into is not an Option. Use an \
+        Option to allow NULL values."
+    )]
+    NullInRequiredField { column: String },
+    /// A value did not fit into the buffer bound to its column and would have been truncated.
+    /// Emitted by [`crate::RowSetCursor::fetch_with_truncation_check`], for text as well as binary
+    /// columns.
+    #[error(
+        "A value in column {column} has been truncated, because it did not fit into the bound \
+        buffer. Length of the value in bytes: {}.",
+        .needed_len.map(|len| len.to_string()).unwrap_or_else(|| "unknown".to_string())
+    )]
+    TextTruncation {
+        /// Number of the column containing the truncated value, starting at 1.
+        column: u16,
+        /// Length in bytes required to hold the entire value, excluding a terminating zero. `None`
+        /// if the driver did not report it.
+        needed_len: Option<usize>,
+    },
+    /// A query expected to return at most one row (e.g. using [`crate::Cursor::fetch_one_scalar`])
+    /// returned more.
+    #[error("The result set contains more than one row, but at most one row has been expected.")]
+    TooManyRows,
+    /// Executing one of the statements of a script failed. Emitted by
+    /// [`crate::Connection::execute_batch`]. Statements preceding the failing one have been
+    /// executed, the ones following it have not.
+    #[error("Executing statement {position} of the script failed:\n{statement}\n{source}")]
+    FailedExecutingScriptStatement {
+        /// One based position of the failing statement within the script.
+        position: usize,
+        /// Text of the failing statement.
+        statement: String,
+        /// Error returned executing the statement.
+        source: Box<Error>,
+    },
+    /// The driver reported a column to hold values longer than the maximum string length, and
+    /// [`crate::buffers::MaxStrLen::ClampOrError`] has been specified.
+    #[error(
+        "Column {column} may hold values of up to {reported_len} elements, which exceeds the \
+        maximum string length of {max_str_len}."
+    )]
+    ColumnExceedsMaxStrLen {
+        /// Number of the column, starting at 1.
+        column: u16,
+        /// Length of the column as reported by the driver.
+        reported_len: usize,
+        /// Upper bound for the length of the column.
+        max_str_len: usize,
+    },
+    /// A buffer passed to [`crate::Cursor::bind_buffer_checked`] binds fewer columns than there
+    /// are in the result set.
+    #[error(
+        "The result set has {expected} columns, but the buffer bound to it only holds {actual}. \
+        Use `bind_buffer` if leaving columns unbound is intended."
+    )]
+    TooFewColumnsInBuffer {
+        /// Number of columns in the result set.
+        expected: usize,
+        /// Number of columns bound by the buffer.
+        actual: usize,
+    },
+    /// A row of a row set has been requested (e.g. [`crate::RowSetCursor::row_at`]), which is not
+    /// part of the rows fetched last.
+    #[error(
+        "Row index {row_index} is out of bounds. The row set fetched last holds {num_rows} rows."
+    )]
+    RowIndexOutOfBounds {
+        /// Zero based index of the requested row.
+        row_index: usize,
+        /// Number of rows fetched last.
+        num_rows: usize,
+    },
+    /// Loading the ODBC driver manager from a shared library failed. Emitted by
+    /// [`crate::Environment::with_driver_manager`], or by [`crate::Environment::new`] if the default
+    /// driver manager can not be loaded.
+    #[cfg(feature = "dynamic-driver-manager")]
+    #[error("Failed to load ODBC driver manager from '{}':\n{source}", .path.display())]
+    FailedLoadingDriverManager {
+        /// Path of the shared library.
+        path: std::path::PathBuf,
+        /// Error reported by the dynamic loader.
+        source: libloading::Error,
+    },
+    /// [`crate::Environment::with_driver_manager`] has been called, yet a different driver manager
+    /// has already been loaded by this process. There can only be one driver manager per process.
+    #[cfg(feature = "dynamic-driver-manager")]
+    #[error(
+        "The ODBC driver manager '{}' has already been loaded. Only one driver manager can be \
+        used per process.",
+        .loaded.display()
+    )]
+    DriverManagerAlreadyLoaded {
+        /// Path of the driver manager loaded before.
+        loaded: std::path::PathBuf,
+    },
+    /// A [`crate::parameter::Blob`] streamed to the data source at statement execution time, did
+    /// not provide the number of bytes it declared via its size hint. The execution has been
+    /// canceled.
+    #[error(
+        "A blob parameter declared a length of {declared} bytes, yet provided {actual} bytes."
+    )]
+    BlobLengthMismatch {
+        /// Length declared by the size hint of the blob, then binding it as a parameter.
+        declared: usize,
+        /// Number of bytes provided by the blob. If the blob provides more bytes than declared,
+        /// streaming stops as soon as the declared length is exceeded, so this is a lower bound.
+        actual: usize,
+    },
+    /// `SQLCancel` returned an error then canceling a statement using a [`crate::CancelHandle`].
+    /// Diagnostics are not available, since they can not be read from the statement safely, while
+    /// another thread may be executing on it.
+    #[error("Canceling the execution of the statement failed.")]
+    FailedCanceling,
+    /// Executing a query failed. Carries the query text alongside the error returned by the
+    /// driver, so it is easy to tell which query failed, e.g. then executing many of them in a
+    /// loop. Only the statement text is attached, never the values of any bound parameters.
+    ///
+    /// Only emitted, if enabled with [`crate::Connection::set_query_text_in_errors`]. Otherwise the
+    /// error returned by the driver is reported as is.
+    #[error("Executing query failed:\n{query}\n{source}")]
+    FailedExecutingQuery {
+        /// Text of the query. Truncated to its first 1000 characters.
+        query: String,
+        /// Error returned executing the query.
+        source: Box<Error>,
+    },
+    /// The driver requested the values of delayed parameters (e.g.
+    /// [`crate::parameter::BlobParam`]) during an asynchronous execution started with
+    /// [`crate::Connection::execute_polling`]. Streaming parameters at execution time is not
+    /// supported in asynchronous execution. The statement has been discarded.
+    #[error(
+        "Delayed parameters, which are sent at statement execution time, are not supported in \
+        asynchronous execution."
+    )]
+    DelayedParametersInAsyncExecution,
+    /// The attributes reported by `SQLBrowseConnect` did not fit into the output buffer. Emitted by
+    /// [`crate::Environment::browse_connect`] and [`crate::BrowseConnection::browse`]. Browsing
+    /// can not be resumed, since calling `SQLBrowseConnect` again would advance the process,
+    /// rather than repeating the step. The connection handle has been disconnected.
+    #[error(
+        "The attributes required to connect, reported by the driver, exceed {max_len} characters \
+        and have been truncated."
+    )]
+    BrowseConnectAttributesTruncated {
+        /// Maximum length of the attribute string in characters.
+        max_len: usize,
+    },
+}
+
+impl Error {
+    /// `true` if the error indicates that the connection to the data source has been lost, or
+    /// could not be established, in a way which might be resolved by connecting again. E.g. a
+    /// communication link failure (`08S01`) or a connection timeout (`HYT01`). See
+    /// [`crate::Environment::connect_with_retry`].
+    pub fn is_transient_connection_error(&self) -> bool {
+        match self {
+            Error::FailedExecutingQuery { source, .. } => source.is_transient_connection_error(),
+            Error::Diagnostics { record, .. } => matches!(
+                record.state,
+                State::CLIENT_UNABLE_TO_ESTABLISH_CONNECTION
+                    | State::CONNECTION_FAILURE_DURING_TRANSACTION
+                    | State::COMMUNICATION_LINK_FAILURE
+                    | State::CONNECTION_TIMEOUT_EXPIRED
+            ),
+            _ => false,
+        }
+    }
+
+    /// `true` if the error indicates that a timeout expired. Either the query timeout (`HYT00`,
+    /// see [`crate::Preallocated::set_query_timeout_sec`]), or the connection timeout (`HYT01`).
+    /// Allows to tell a timeout, which might be worth retrying, apart from e.g. a syntax error. The
+    /// diagnostic record is still available in [`Error::Diagnostics`], which is the source of
+    /// [`Error::FailedExecutingQuery`], in case the query text is attached to the error.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Error::FailedExecutingQuery { source, .. } => source.is_timeout(),
+            Error::Diagnostics { record, .. } => matches!(
+                record.state,
+                State::TIMEOUT_EXPIRED | State::CONNECTION_TIMEOUT_EXPIRED
+            ),
+            _ => false,
+        }
+    }
 }
 
 // Define that here rather than in `sql_result` mod to keep the `handles` modlue entirely agnostic
@@ -81,11 +289,79 @@ impl<T> SqlResult<T> {
                 let mut record = DiagnosticRecord::default();
                 if record.fill_from(handle, 1) {
                     log_diagnostics(handle);
-                    Err(Error::Diagnostics { record, function })
+                    if matches!(
+                        record.state,
+                        State::OPTIONAL_FEATURE_NOT_IMPLEMENTED
+                            | State::DRIVER_DOES_NOT_SUPPORT_FUNCTION
+                    ) {
+                        Err(Error::UnsupportedOptionalFeature { record, function })
+                    } else {
+                        Err(Error::Diagnostics { record, function })
+                    }
                 } else {
                     Err(Error::NoDiagnostics { function })
                 }
             }
         }
     }
+
+    /// Like [`Self::into_result`], but errors are wrapped into [`Error::FailedExecutingQuery`]
+    /// carrying `query`. The query is only formatted in case of an error. Pass only the statement
+    /// text, never parameter values, as these may contain sensitive data which should not end up
+    /// in logs.
+    pub fn into_result_with_context(
+        self,
+        handle: &dyn AsHandle,
+        query: &impl Display,
+    ) -> Result<T, Error> {
+        self.into_result(handle)
+            .map_err(|error| error.with_query_text(&query.to_string()))
+    }
+}
+
+impl Error {
+    /// Wraps the error into [`Error::FailedExecutingQuery`], carrying the (truncated) `query`.
+    pub(crate) fn with_query_text(self, query: &str) -> Self {
+        Error::FailedExecutingQuery {
+            query: truncate_query(query),
+            source: Box::new(self),
+        }
+    }
+}
+
+/// Attaches `query` to the error, if any, in case `query` is `Some`. See
+/// [`crate::Connection::set_query_text_in_errors`].
+pub(crate) fn with_query_text<T>(
+    result: Result<T, Error>,
+    query: Option<&str>,
+) -> Result<T, Error> {
+    match query {
+        Some(query) => result.map_err(|error| error.with_query_text(query)),
+        None => result,
+    }
+}
+
+/// Truncates `query` to at most [`MAX_QUERY_LEN_IN_ERROR`] characters. An ellipsis indicates that
+/// the text has been truncated.
+fn truncate_query(query: &str) -> String {
+    match query.char_indices().nth(MAX_QUERY_LEN_IN_ERROR) {
+        Some((index, _)) => format!("{}...", &query[..index]),
+        None => query.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{truncate_query, MAX_QUERY_LEN_IN_ERROR};
+
+    #[test]
+    fn truncate_long_query() {
+        let query = "ä".repeat(MAX_QUERY_LEN_IN_ERROR + 1);
+        let truncated = truncate_query(&query);
+        assert_eq!(
+            format!("{}...", "ä".repeat(MAX_QUERY_LEN_IN_ERROR)),
+            truncated
+        );
+        assert_eq!("SELECT 42", truncate_query("SELECT 42"));
+    }
 }
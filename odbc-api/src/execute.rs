@@ -1,12 +1,56 @@
-use std::intrinsics::transmute;
+use std::{borrow::Cow, intrinsics::transmute};
 
-use widestring::U16Str;
+use odbc_sys::SqlDataType;
+use widestring::{U16Str, U16String};
 
 use crate::{
-    borrow_mut_statement::BorrowMutStatement, handles::Statement, parameter::Blob, CursorImpl,
-    Error, ParameterRefCollection,
+    borrow_mut_statement::BorrowMutStatement,
+    handles::{SqlResult, Statement},
+    parameter::Blob,
+    CursorImpl, Error, ParameterRefCollection,
 };
 
+/// Text of an SQL statement, encoded either as UTF-16 for the wide function variants (e.g.
+/// `SQLExecDirectW`), or as UTF-8 for the narrow ones (e.g. `SQLExecDirect`). See
+/// [`crate::Connection::set_narrow_query_text`].
+#[derive(Debug, Clone)]
+pub enum SqlText<'a> {
+    Wide(Cow<'a, U16Str>),
+    Narrow(&'a str),
+}
+
+impl<'a> SqlText<'a> {
+    /// Encodes `text` as UTF-16, unless `narrow` is `true`, in which case the UTF-8 text is passed
+    /// to the driver as is.
+    pub fn new(text: &'a str, narrow: bool) -> Self {
+        if narrow {
+            SqlText::Narrow(text)
+        } else {
+            SqlText::Wide(Cow::Owned(U16String::from_str(text)))
+        }
+    }
+
+    /// Executes the statement text using either `SQLExecDirectW` or `SQLExecDirect`.
+    ///
+    /// # Safety
+    ///
+    /// See [`Statement::exec_direct`].
+    pub unsafe fn exec_direct(&self, stmt: &mut impl Statement) -> SqlResult<bool> {
+        match self {
+            SqlText::Wide(text) => stmt.exec_direct(text),
+            SqlText::Narrow(text) => stmt.exec_direct_narrow(text),
+        }
+    }
+
+    /// Prepares the statement text using either `SQLPrepareW` or `SQLPrepare`.
+    pub fn prepare(&self, stmt: &mut impl Statement) -> SqlResult<()> {
+        match self {
+            SqlText::Wide(text) => stmt.prepare(text),
+            SqlText::Narrow(text) => stmt.prepare_narrow(text),
+        }
+    }
+}
+
 /// Shared implementation for executing a query with parameters between [`crate::Connection`],
 /// [`crate::Preallocated`] and [`crate::Prepared`].
 ///
@@ -20,7 +64,7 @@ use crate::{
 /// * `params`: The parameters bound to the statement before query execution.
 pub fn execute_with_parameters<S>(
     lazy_statement: impl FnOnce() -> Result<S, Error>,
-    query: Option<&U16Str>,
+    query: Option<SqlText<'_>>,
     mut params: impl ParameterRefCollection,
 ) -> Result<Option<CursorImpl<S>>, Error>
 where
@@ -53,14 +97,14 @@ where
 /// * Furthermore all bound delayed parameters must be of type `*mut &mut dyn Blob`.
 pub unsafe fn execute<S>(
     mut statement: S,
-    query: Option<&U16Str>,
+    query: Option<SqlText<'_>>,
 ) -> Result<Option<CursorImpl<S>>, Error>
 where
     S: BorrowMutStatement,
 {
     let stmt = statement.borrow_mut();
     let need_data = if let Some(sql) = query {
-        stmt.exec_direct(sql).into_result(stmt)?
+        sql.exec_direct(stmt).into_result(stmt)?
     } else {
         stmt.execute().into_result(stmt)?
     };
@@ -72,10 +116,25 @@ where
             // The safe interfaces currently exclusively bind pointers to `Blob` trait objects
             let blob_ptr: *mut &mut dyn Blob = transmute(blob_ptr);
             let blob_ref = &mut *blob_ptr;
+            // The size hint must be accurate before the first batch is fetched. It is the length
+            // we declared to the driver then binding the parameter.
+            let declared = blob_ref.size_hint();
+            let mut actual = 0;
             // Loop over all batches within each blob
             while let Some(batch) = blob_ref.next_batch().map_err(Error::FailedReadingInput)? {
+                actual += batch.len();
+                if let Some(declared) = declared {
+                    if actual > declared {
+                        return Err(blob_length_mismatch(stmt, declared, actual));
+                    }
+                }
                 stmt.put_binary_batch(batch).into_result(stmt)?;
             }
+            if let Some(declared) = declared {
+                if actual != declared {
+                    return Err(blob_length_mismatch(stmt, declared, actual));
+                }
+            }
         }
     }
 
@@ -89,6 +148,14 @@ where
     }
 }
 
+/// Cancels the execution waiting for the remaining data of delayed parameters, so the statement can
+/// be reused, and creates the error describing the mismatch.
+fn blob_length_mismatch(stmt: &impl Statement, declared: usize, actual: usize) -> Error {
+    // The length mismatch is the more relevant error, so we ignore errors during cancellation.
+    let _ = stmt.cancel();
+    Error::BlobLengthMismatch { declared, actual }
+}
+
 /// Shared implementation for executing a columns query between [`crate::Connection`] and
 /// [`crate::Preallocated`].
 pub fn execute_columns<S>(
@@ -139,3 +206,52 @@ where
 
     Ok(cursor)
 }
+
+/// Shared implementation for executing a statistics query between [`crate::Connection`] and
+/// [`crate::Preallocated`].
+pub fn execute_statistics<S>(
+    mut statement: S,
+    catalog_name: Option<&U16Str>,
+    schema_name: Option<&U16Str>,
+    table_name: &U16Str,
+    unique_only: bool,
+    quick: bool,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: BorrowMutStatement,
+{
+    let stmt = statement.borrow_mut();
+
+    stmt.statistics(catalog_name, schema_name, table_name, unique_only, quick)
+        .into_result(stmt)?;
+
+    // We assume statistics always creates a result set, since it works like a SELECT statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
+
+/// Shared implementation for executing a type info query between [`crate::Connection`] and
+/// [`crate::Preallocated`].
+pub fn execute_type_info<S>(
+    mut statement: S,
+    data_type: SqlDataType,
+) -> Result<CursorImpl<S>, Error>
+where
+    S: BorrowMutStatement,
+{
+    let stmt = statement.borrow_mut();
+
+    stmt.get_type_info(data_type).into_result(stmt)?;
+
+    // We assume type info always creates a result set, since it works like a SELECT statement.
+    debug_assert_ne!(stmt.num_result_cols().unwrap(), 0);
+
+    // Safe: `statement` is in Cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
+
+    Ok(cursor)
+}
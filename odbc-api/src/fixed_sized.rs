@@ -1,8 +1,9 @@
 use crate::{
     handles::{CData, DataType, HasDataType},
     parameter::{InputParameter, StableCData},
+    Guid,
 };
-use odbc_sys::{CDataType, Date, Numeric, Time, Timestamp};
+use odbc_sys::{CDataType, Date, Numeric, SqlDataType, Time, Timestamp};
 use std::{ffi::c_void, ptr::null};
 
 /// New type wrapping u8 and binding as SQL_BIT.
@@ -14,14 +15,27 @@ use std::{ffi::c_void, ptr::null};
 pub struct Bit(pub u8);
 
 impl Bit {
-    /// Maps `1` to `true`, `0` to `false`. Panics if `Bit` should be invalid (not `0` or `1`).
-    pub fn as_bool(self) -> bool {
-        match self.0 {
-            0 => false,
-            1 => true,
-            _ => panic!("Invalid boolean representation in Bit."),
+    /// Maps `true` to `1` and `false` to `0`.
+    ///
+    /// ```
+    /// use odbc_api::Bit;
+    ///
+    /// assert_eq!(Bit(1), Bit::from_bool(true));
+    /// assert_eq!(Bit(0), Bit::from_bool(false));
+    /// ```
+    pub fn from_bool(boolean: bool) -> Self {
+        if boolean {
+            Bit(1)
+        } else {
+            Bit(0)
         }
     }
+
+    /// Maps `0` to `false` and any other value to `true`. Drivers should only ever return `0` or
+    /// `1`, yet like in C any nonzero value is considered true.
+    pub fn as_bool(self) -> bool {
+        self.0 != 0
+    }
 }
 
 /// A plain old data type. With an associated C Type. Must be completely stack allocated without any
@@ -78,6 +92,7 @@ impl_fixed_sized!(u8, CDataType::UTinyInt);
 impl_fixed_sized!(Bit, CDataType::Bit);
 impl_fixed_sized!(i64, CDataType::SBigInt);
 impl_fixed_sized!(u64, CDataType::UBigInt);
+impl_fixed_sized!(Guid, CDataType::Guid);
 
 // While the C-Type is independent of the Data (SQL) Type in the source, there are often DataTypes
 // which are a natural match for the C-Type in question. These can be used to spare the user to
@@ -100,25 +115,36 @@ macro_rules! impl_input_fixed_sized {
 impl_input_fixed_sized!(f64, DataType::Double);
 impl_input_fixed_sized!(f32, DataType::Real);
 impl_input_fixed_sized!(Date, DataType::Date);
+// Seven digits is the highest precision supported by Microsoft SQL Server. Use
+// `WithDataType::timestamp` to bind timestamps with a different precision.
+impl_input_fixed_sized!(Timestamp, DataType::Timestamp { precision: 7 });
 impl_input_fixed_sized!(i16, DataType::SmallInt);
 impl_input_fixed_sized!(i32, DataType::Integer);
 impl_input_fixed_sized!(i8, DataType::TinyInt);
 impl_input_fixed_sized!(Bit, DataType::Bit);
 impl_input_fixed_sized!(i64, DataType::BigInt);
+impl_input_fixed_sized!(
+    Guid,
+    DataType::Other {
+        data_type: SqlDataType::EXT_GUID,
+        column_size: 36,
+        decimal_digits: 0
+    }
+);
 
-// Support for fixed size types, which are not unsigned. Time, Date and timestamp types could be
-// supported, implementation DataType would need to take an instance into account.
+// Support for fixed size types, which are not unsigned. Time could be supported, implementation
+// DataType would need to take an instance into account.
 
 #[cfg(test)]
 mod test {
 
     use super::Bit;
 
-    /// `as_bool` should panic if bit is neither 0 or 1.
+    /// Any nonzero value is `true`.
     #[test]
-    #[should_panic(expected = "Invalid boolean representation in Bit.")]
-    fn invalid_bit() {
-        let bit = Bit(2);
-        bit.as_bool();
+    fn nonzero_bit_is_true() {
+        assert!(!Bit(0).as_bool());
+        assert!(Bit(1).as_bool());
+        assert!(Bit(2).as_bool());
     }
 }
@@ -0,0 +1,305 @@
+use odbc_sys::{Date, Time, Timestamp};
+
+use crate::{
+    buffers::MaxStrLen,
+    buffers::{
+        buffer_from_description_and_indices, AnyColumnBuffer, AnyColumnView, BufferDescription,
+        BufferKind, ColumnarBuffer,
+    },
+    Bit, Cursor, Error, Guid, ResultSetMetadata,
+};
+
+/// Types which can be constructed from the rows of a result set. Instead of implementing this trait
+/// manually, you likely want to derive it using `#[derive(FromRow)]`, which is available if the
+/// `derive` feature of this crate is activated.
+///
+/// Each field of the struct is read from the column with the same name. Use
+/// `#[odbc(name = "...")]` to read a field from a column with a different name. Fields of type
+/// `Option<T>` map `NULL` to `None`. Reading `NULL` into any other field type results in
+/// [`Error::NullInRequiredField`].
+///
+/// Text and binary fields are read into buffers of at most 4096 bytes per value, also for columns
+/// of unknown length (e.g. `VARCHAR(MAX)`). Longer values are truncated.
+///
+/// # Example
+///
+/// ```ignore
+/// use odbc_api::{Connection, Error, FromRow};
+///
+/// #[derive(FromRow)]
+/// struct Movie {
+///     title: String,
+///     #[odbc(name = "year")]
+///     release_year: Option<i16>,
+/// }
+///
+/// fn movies(conn: &Connection<'_>) -> Result<Vec<Movie>, Error> {
+///     let cursor = conn.execute("SELECT title, year FROM Movies", ())?.unwrap();
+///     Movie::from_cursor(cursor, 100)
+/// }
+/// ```
+pub trait FromRow: Sized {
+    /// Names of the columns the fields are read from, in order of the fields.
+    const COLUMN_NAMES: &'static [&'static str];
+
+    /// Kind of buffer to bind for each field.
+    ///
+    /// # Parameters
+    ///
+    /// * `cursor`: Used to query metadata, e.g. the maximum string length of a text column.
+    /// * `column_numbers`: One based index of the column in the result set, for each field.
+    fn buffer_kinds(
+        cursor: &impl ResultSetMetadata,
+        column_numbers: &[u16],
+    ) -> Result<Vec<BufferKind>, Error>;
+
+    /// Creates one instance for each row in `buffer`. The columns of the buffer are expected in
+    /// order of the fields.
+    fn from_rows(buffer: &ColumnarBuffer<AnyColumnBuffer>) -> Result<Vec<Self>, Error>;
+
+    /// Fetches all rows of the cursor, in batches of `batch_size` rows.
+    fn from_cursor(cursor: impl Cursor, batch_size: usize) -> Result<Vec<Self>, Error> {
+        let names = cursor.column_names()?.collect::<Result<Vec<_>, _>>()?;
+        let column_numbers = Self::COLUMN_NAMES
+            .iter()
+            .map(|&field_column| {
+                names
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(field_column))
+                    .map(|index| (index + 1).try_into().unwrap())
+                    .ok_or_else(|| Error::ColumnNotFound {
+                        name: field_column.to_string(),
+                    })
+            })
+            .collect::<Result<Vec<u16>, _>>()?;
+        let kinds = Self::buffer_kinds(&cursor, &column_numbers)?;
+        let descriptions = column_numbers
+            .iter()
+            .copied()
+            .zip(kinds)
+            .map(|(index, kind)| {
+                // Always bind an indicator, so `NULL` is reported rather than causing an error.
+                (
+                    index,
+                    BufferDescription {
+                        kind,
+                        nullable: true,
+                    },
+                )
+            });
+        let buffer = buffer_from_description_and_indices(batch_size, descriptions);
+
+        let mut row_set_cursor = cursor.bind_buffer(buffer)?;
+        let mut rows = Vec::new();
+        while let Some(batch) = row_set_cursor.fetch()? {
+            rows.extend(Self::from_rows(batch)?);
+        }
+        Ok(rows)
+    }
+}
+
+/// Types of fields in structs implementing [`FromRow`]. Implemented for the fixed sized types
+/// supported by [`BufferKind`], `bool`, `String` and `Vec<u8>`, as well as `Option`s of them.
+pub trait FromColumn: Sized {
+    /// Kind of buffer bound to the column the field is read from.
+    fn buffer_kind(
+        cursor: &impl ResultSetMetadata,
+        column_number: u16,
+    ) -> Result<BufferKind, Error>;
+
+    /// Reads all values of the column. Fails with [`Error::NullInRequiredField`] if it contains
+    /// `NULL` and `Self` is not an `Option`. Panics if the column has been bound with an unexpected
+    /// buffer kind.
+    ///
+    /// * `column_name`: Name of the column. Only used to report errors.
+    fn from_column(column: AnyColumnView<'_>, column_name: &str) -> Result<Vec<Self>, Error>;
+}
+
+/// Maximum length in bytes of text and binary values read by [`FromRow`]. Columns of unknown
+/// length, or exceeding this length, are bound with a buffer of this size.
+const MAX_FIELD_LEN: usize = 4096;
+
+fn null_in_required_field(column_name: &str) -> Error {
+    Error::NullInRequiredField {
+        column: column_name.to_string(),
+    }
+}
+
+macro_rules! impl_from_column {
+    ($type:ty, $kind:ident, $variant:ident, $nullable_variant:ident) => {
+        impl FromColumn for $type {
+            fn buffer_kind(_: &impl ResultSetMetadata, _: u16) -> Result<BufferKind, Error> {
+                Ok(BufferKind::$kind)
+            }
+
+            fn from_column(
+                column: AnyColumnView<'_>,
+                column_name: &str,
+            ) -> Result<Vec<Self>, Error> {
+                match column {
+                    AnyColumnView::$variant(values) => Ok(values.to_vec()),
+                    AnyColumnView::$nullable_variant(values) => values
+                        .map(|value| {
+                            value
+                                .copied()
+                                .ok_or_else(|| null_in_required_field(column_name))
+                        })
+                        .collect(),
+                    _ => panic!(concat!("Unexpected buffer kind for ", stringify!($type))),
+                }
+            }
+        }
+
+        impl FromColumn for Option<$type> {
+            fn buffer_kind(_: &impl ResultSetMetadata, _: u16) -> Result<BufferKind, Error> {
+                Ok(BufferKind::$kind)
+            }
+
+            fn from_column(column: AnyColumnView<'_>, _: &str) -> Result<Vec<Self>, Error> {
+                match column {
+                    AnyColumnView::$variant(values) => {
+                        Ok(values.iter().copied().map(Some).collect())
+                    }
+                    AnyColumnView::$nullable_variant(values) => {
+                        Ok(values.map(|value| value.copied()).collect())
+                    }
+                    _ => panic!(concat!("Unexpected buffer kind for ", stringify!($type))),
+                }
+            }
+        }
+    };
+}
+
+impl_from_column!(Date, Date, Date, NullableDate);
+impl_from_column!(Time, Time, Time, NullableTime);
+impl_from_column!(Timestamp, Timestamp, Timestamp, NullableTimestamp);
+impl_from_column!(f64, F64, F64, NullableF64);
+impl_from_column!(f32, F32, F32, NullableF32);
+impl_from_column!(i8, I8, I8, NullableI8);
+impl_from_column!(i16, I16, I16, NullableI16);
+impl_from_column!(i32, I32, I32, NullableI32);
+impl_from_column!(i64, I64, I64, NullableI64);
+impl_from_column!(u8, U8, U8, NullableU8);
+impl_from_column!(u16, U16, U16, NullableU16);
+impl_from_column!(u32, U32, U32, NullableU32);
+impl_from_column!(u64, U64, U64, NullableU64);
+impl_from_column!(Bit, Bit, Bit, NullableBit);
+impl_from_column!(Guid, Guid, Guid, NullableGuid);
+
+impl FromColumn for Option<bool> {
+    fn buffer_kind(_: &impl ResultSetMetadata, _: u16) -> Result<BufferKind, Error> {
+        Ok(BufferKind::Bit)
+    }
+
+    fn from_column(column: AnyColumnView<'_>, column_name: &str) -> Result<Vec<Self>, Error> {
+        let bits = Option::<Bit>::from_column(column, column_name)?;
+        Ok(bits
+            .into_iter()
+            .map(|bit| bit.map(|bit| bit.as_bool()))
+            .collect())
+    }
+}
+
+impl FromColumn for bool {
+    fn buffer_kind(_: &impl ResultSetMetadata, _: u16) -> Result<BufferKind, Error> {
+        Ok(BufferKind::Bit)
+    }
+
+    fn from_column(column: AnyColumnView<'_>, column_name: &str) -> Result<Vec<Self>, Error> {
+        let bits = Bit::from_column(column, column_name)?;
+        Ok(bits.into_iter().map(|bit| bit.as_bool()).collect())
+    }
+}
+
+/// Same estimate for the maximum string length as used by [`crate::buffers::TextRowSet`], capped
+/// at [`MAX_FIELD_LEN`].
+fn text_kind(cursor: &impl ResultSetMetadata, column_number: u16) -> Result<BufferKind, Error> {
+    let reported_len = if let Some(encoded_len) = cursor.col_data_type(column_number)?.utf8_len() {
+        encoded_len
+    } else {
+        cursor
+            .col_display_size(column_number)?
+            .try_into()
+            .unwrap_or(0)
+    };
+    let max_str_len = MaxStrLen::Clamp(MAX_FIELD_LEN).buffer_len(column_number, reported_len)?;
+    Ok(BufferKind::Text { max_str_len })
+}
+
+impl FromColumn for Option<String> {
+    fn buffer_kind(
+        cursor: &impl ResultSetMetadata,
+        column_number: u16,
+    ) -> Result<BufferKind, Error> {
+        text_kind(cursor, column_number)
+    }
+
+    fn from_column(column: AnyColumnView<'_>, _: &str) -> Result<Vec<Self>, Error> {
+        match column {
+            AnyColumnView::Text(values) => Ok(values
+                .map(|text| text.map(|text| String::from_utf8_lossy(text).into_owned()))
+                .collect()),
+            _ => panic!("Unexpected buffer kind for String"),
+        }
+    }
+}
+
+impl FromColumn for String {
+    fn buffer_kind(
+        cursor: &impl ResultSetMetadata,
+        column_number: u16,
+    ) -> Result<BufferKind, Error> {
+        text_kind(cursor, column_number)
+    }
+
+    fn from_column(column: AnyColumnView<'_>, column_name: &str) -> Result<Vec<Self>, Error> {
+        Option::<String>::from_column(column, column_name)?
+            .into_iter()
+            .map(|text| text.ok_or_else(|| null_in_required_field(column_name)))
+            .collect()
+    }
+}
+
+/// Uses the octet length reported by the driver, capped at [`MAX_FIELD_LEN`].
+fn binary_kind(cursor: &impl ResultSetMetadata, column_number: u16) -> Result<BufferKind, Error> {
+    let reported_len = cursor
+        .col_octet_length(column_number)?
+        .try_into()
+        .unwrap_or(0);
+    let length = MaxStrLen::Clamp(MAX_FIELD_LEN).buffer_len(column_number, reported_len)?;
+    Ok(BufferKind::Binary { length })
+}
+
+impl FromColumn for Option<Vec<u8>> {
+    fn buffer_kind(
+        cursor: &impl ResultSetMetadata,
+        column_number: u16,
+    ) -> Result<BufferKind, Error> {
+        binary_kind(cursor, column_number)
+    }
+
+    fn from_column(column: AnyColumnView<'_>, _: &str) -> Result<Vec<Self>, Error> {
+        match column {
+            AnyColumnView::Binary(values) => {
+                Ok(values.map(|bytes| bytes.map(<[u8]>::to_vec)).collect())
+            }
+            _ => panic!("Unexpected buffer kind for Vec<u8>"),
+        }
+    }
+}
+
+impl FromColumn for Vec<u8> {
+    fn buffer_kind(
+        cursor: &impl ResultSetMetadata,
+        column_number: u16,
+    ) -> Result<BufferKind, Error> {
+        binary_kind(cursor, column_number)
+    }
+
+    fn from_column(column: AnyColumnView<'_>, column_name: &str) -> Result<Vec<Self>, Error> {
+        Option::<Vec<u8>>::from_column(column, column_name)?
+            .into_iter()
+            .map(|bytes| bytes.ok_or_else(|| null_in_required_field(column_name)))
+            .collect()
+    }
+}
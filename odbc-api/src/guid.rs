@@ -0,0 +1,160 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A globally unique identifier, e.g. the value of a `UNIQUEIDENTIFIER` column in Microsoft SQL
+/// Server. Memory layout is identical to the `SQLGUID` structure of the ODBC C API, so it can be
+/// bound directly as `SQL_C_GUID`.
+///
+/// Note that `SQLGUID` is mixed-endian: The first three groups are stored as integers in native
+/// byte order, while the last eight bytes are stored as is. Use [`Guid::from_bytes`] and
+/// [`Guid::to_bytes`] to convert from and to the byte order of the canonical string
+/// representation, rather than reinterpreting the memory of this struct.
+///
+/// ```
+/// use odbc_api::Guid;
+///
+/// let guid: Guid = "6F9619FF-8B86-D011-B42D-00C04FC964FF".parse().unwrap();
+/// assert_eq!(0x6F9619FF, guid.data1);
+/// assert_eq!("6F9619FF-8B86-D011-B42D-00C04FC964FF", guid.to_string());
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Guid {
+    /// First group of eight hexadecimal digits.
+    pub data1: u32,
+    /// Second group of four hexadecimal digits.
+    pub data2: u16,
+    /// Third group of four hexadecimal digits.
+    pub data3: u16,
+    /// Last two groups of four and twelve hexadecimal digits.
+    pub data4: [u8; 8],
+}
+
+impl Guid {
+    /// Creates a GUID from its bytes in the order they appear in the canonical string
+    /// representation (i.e. big-endian, as specified in RFC 4122).
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        let mut data4 = [0; 8];
+        data4.copy_from_slice(&bytes[8..]);
+        Guid {
+            data1: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            data2: u16::from_be_bytes([bytes[4], bytes[5]]),
+            data3: u16::from_be_bytes([bytes[6], bytes[7]]),
+            data4,
+        }
+    }
+
+    /// Bytes of the GUID in the order they appear in the canonical string representation (i.e.
+    /// big-endian, as specified in RFC 4122).
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[0..4].copy_from_slice(&self.data1.to_be_bytes());
+        bytes[4..6].copy_from_slice(&self.data2.to_be_bytes());
+        bytes[6..8].copy_from_slice(&self.data3.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.data4);
+        bytes
+    }
+}
+
+/// Formats the GUID in its canonical `8-4-4-4-12` form using upper case hexadecimal digits, like
+/// Microsoft SQL Server does.
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.to_bytes();
+        for (index, byte) in bytes.iter().enumerate() {
+            if matches!(index, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the canonical `8-4-4-4-12` form of a GUID. Hexadecimal digits may be upper or lower
+/// case.
+impl FromStr for Guid {
+    type Err = ParseGuidError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.as_bytes();
+        if text.len() != 36 {
+            return Err(ParseGuidError);
+        }
+        let mut bytes = [0u8; 16];
+        let mut digits = text
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| !matches!(index, 8 | 13 | 18 | 23));
+        for byte in &mut bytes {
+            let (_, &high) = digits.next().unwrap();
+            let (_, &low) = digits.next().unwrap();
+            *byte = hex_value(high)? << 4 | hex_value(low)?;
+        }
+        if [8, 13, 18, 23].iter().any(|&index| text[index] != b'-') {
+            return Err(ParseGuidError);
+        }
+        Ok(Guid::from_bytes(bytes))
+    }
+}
+
+fn hex_value(digit: u8) -> Result<u8, ParseGuidError> {
+    (digit as char)
+        .to_digit(16)
+        .map(|value| value as u8)
+        .ok_or(ParseGuidError)
+}
+
+/// Error returned if a string could not be parsed into a [`Guid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseGuidError;
+
+impl fmt::Display for ParseGuidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Invalid GUID. Expected format: XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX")
+    }
+}
+
+impl Error for ParseGuidError {}
+
+#[cfg(test)]
+mod test {
+    use super::Guid;
+
+    #[test]
+    fn bytes_round_trip() {
+        let bytes = [
+            0x6F, 0x96, 0x19, 0xFF, 0x8B, 0x86, 0xD0, 0x11, 0xB4, 0x2D, 0x00, 0xC0, 0x4F, 0xC9,
+            0x64, 0xFF,
+        ];
+        let guid = Guid::from_bytes(bytes);
+        assert_eq!(0x6F9619FF, guid.data1);
+        assert_eq!(0x8B86, guid.data2);
+        assert_eq!(0xD011, guid.data3);
+        assert_eq!(bytes, guid.to_bytes());
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let text = "6F9619FF-8B86-D011-B42D-00C04FC964FF";
+        let guid: Guid = text.parse().unwrap();
+        assert_eq!(text, guid.to_string());
+        let lower: Guid = text.to_lowercase().parse().unwrap();
+        assert_eq!(guid, lower);
+    }
+
+    #[test]
+    fn reject_invalid_strings() {
+        let invalid = [
+            "",
+            // Too short
+            "6F9619FF-8B86-D011-B42D-00C04FC964F",
+            // Wrong separator
+            "6F9619FF_8B86-D011-B42D-00C04FC964FF",
+            // Not a hexadecimal digit
+            "6F9619FG-8B86-D011-B42D-00C04FC964FF",
+        ];
+        for text in invalid {
+            assert!(text.parse::<Guid>().is_err(), "{}", text);
+        }
+    }
+}
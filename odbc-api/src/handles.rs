@@ -12,6 +12,7 @@ mod column_description;
 mod connection;
 mod data_type;
 mod diagnostics;
+mod driver_manager;
 mod environment;
 mod logging;
 mod sql_result;
@@ -22,16 +23,22 @@ pub use {
     bind::{CData, CDataMut, DelayedInput, HasDataType},
     buffer::OutputStringBuffer,
     column_description::{ColumnDescription, Nullability},
-    connection::Connection,
+    connection::{AccessMode, Connection},
     data_type::DataType,
     diagnostics::{Record, State},
     environment::Environment,
     logging::log_diagnostics,
     sql_result::SqlResult,
-    statement::{ParameterDescription, Statement, StatementImpl},
+    statement::{
+        Concurrency, CursorType, Lock, Operation, ParameterDescription, Statement, StatementImpl,
+    },
 };
 
-use odbc_sys::{Handle, HandleType, SQLFreeHandle, SqlReturn};
+#[cfg(feature = "dynamic-driver-manager")]
+pub use driver_manager::{load_driver_manager, DEFAULT_DRIVER_MANAGER};
+
+use driver_manager::SQLFreeHandle;
+use odbc_sys::{Handle, HandleType, SqlReturn};
 use std::thread::panicking;
 
 /// Helper function freeing a handle and panicking on errors. Yet if the drop is triggered during
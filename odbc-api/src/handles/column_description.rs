@@ -48,6 +48,12 @@ impl ColumnDescription {
         decode_utf16(self.name.iter().copied()).collect()
     }
 
+    /// The data type of the column as it would be written in a `CREATE TABLE` statement, e.g.
+    /// `VARCHAR(255)`. See [`DataType::to_sql_ddl`].
+    pub fn data_type_display(&self) -> Option<String> {
+        self.data_type.to_sql_ddl()
+    }
+
     /// `true` if the column is `Nullable` or it is not know whether the column is nullable. `false`
     /// if and only if the column is `NoNulls`.
     pub fn could_be_nullable(&self) -> bool {
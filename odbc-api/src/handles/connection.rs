@@ -1,6 +1,10 @@
 use super::{
     as_handle::AsHandle,
     buffer::{buf_ptr, clamp_int, clamp_small_int, mut_buf_ptr, OutputStringBuffer},
+    driver_manager::{
+        SQLAllocHandle, SQLBrowseConnectW, SQLConnectW, SQLDisconnect, SQLDriverConnectW,
+        SQLEndTran, SQLGetConnectAttrW, SQLGetInfoW, SQLNativeSqlW, SQLSetConnectAttrW,
+    },
     drop_handle,
     sql_result::ExtSqlReturn,
     statement::StatementImpl,
@@ -8,12 +12,21 @@ use super::{
 };
 use odbc_sys::{
     CompletionType, ConnectionAttribute, DriverConnectOption, HDbc, HEnv, HStmt, HWnd, Handle,
-    HandleType, InfoType, Pointer, SQLAllocHandle, SQLConnectW, SQLDisconnect, SQLDriverConnectW,
-    SQLEndTran, SQLGetConnectAttrW, SQLGetInfoW, SQLSetConnectAttrW,
+    HandleType, InfoType, Pointer, SqlReturn,
 };
 use std::{ffi::c_void, marker::PhantomData, mem::size_of, ptr::null_mut};
 use widestring::U16Str;
 
+/// Access mode of a connection. See [`Connection::set_access_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessMode {
+    /// The connection is not required to support SQL statements that cause updates to occur.
+    /// Drivers may use this to optimize. It is not a way to prevent updates.
+    ReadOnly = 1,
+    /// Statements may read and write data. Default.
+    ReadWrite = 0,
+}
+
 /// The connection handle references storage of all information about the connection to the data
 /// source, including status, transaction state, and error information.
 pub struct Connection<'c> {
@@ -143,6 +156,37 @@ impl<'c> Connection<'c> {
         .into_opt_sql_result("SQLDriverConnectW")
     }
 
+    /// Iteratively discovers the attributes required to connect to a data source. Each call
+    /// returns the next level of attributes in `out_connection_string`.
+    ///
+    /// # Return
+    ///
+    /// `true` if the driver requires more attributes to be specified before the connection is
+    /// established. In this case `out_connection_string` holds the attributes still missing. `false`
+    /// if the connection has been established, in which case `out_connection_string` holds the
+    /// completed connection string.
+    pub fn browse_connect(
+        &mut self,
+        connection_string: &U16Str,
+        out_connection_string: &mut OutputStringBuffer,
+    ) -> SqlResult<bool> {
+        unsafe {
+            match SQLBrowseConnectW(
+                self.handle,
+                buf_ptr(connection_string.as_slice()),
+                connection_string.len().try_into().unwrap(),
+                out_connection_string.mut_buf_ptr(),
+                out_connection_string.buf_len(),
+                out_connection_string.mut_actual_len_ptr(),
+            ) {
+                SqlReturn::NEED_DATA => SqlResult::Success(true),
+                other => other
+                    .into_sql_result("SQLBrowseConnectW")
+                    .on_success(|| false),
+            }
+        }
+    }
+
     /// Disconnect from an ODBC data source.
     pub fn disconnect(&mut self) -> SqlResult<()> {
         unsafe { SQLDisconnect(self.handle).into_sql_result("SQLDisconnect") }
@@ -175,6 +219,56 @@ impl<'c> Connection<'c> {
         }
     }
 
+    /// Number of seconds to wait for a login request to complete before returning to the
+    /// application. The default is driver-dependent. If `0` the timeout is disabled and a
+    /// connection attempt will wait indefinitely.
+    ///
+    /// This attribute must be set before connecting to the data source.
+    pub fn set_login_timeout_sec(&self, timeout: u32) -> SqlResult<()> {
+        unsafe {
+            SQLSetConnectAttrW(
+                self.handle,
+                ConnectionAttribute::LoginTimeout,
+                timeout as usize as Pointer,
+                0, // will be ignored according to ODBC spec
+            )
+            .into_sql_result("SQLSetConnectAttrW")
+        }
+    }
+
+    /// Network packet size in bytes. Many data sources either do not support this option or only
+    /// can return but not set the network packet size. Drivers may clamp the value to the range
+    /// supported by the data source.
+    ///
+    /// This attribute must be set before connecting to the data source.
+    pub fn set_packet_size(&self, packet_size: u32) -> SqlResult<()> {
+        unsafe {
+            SQLSetConnectAttrW(
+                self.handle,
+                ConnectionAttribute::PacketSize,
+                packet_size as usize as Pointer,
+                0, // will be ignored according to ODBC spec
+            )
+            .into_sql_result("SQLSetConnectAttrW")
+        }
+    }
+
+    /// Hint to the driver, whether the application is going to write data using this connection.
+    /// Drivers may use this to optimize, but are not required to prevent writes in read only mode.
+    ///
+    /// Some drivers only honor this attribute if it is set before connecting to the data source.
+    pub fn set_access_mode(&self, mode: AccessMode) -> SqlResult<()> {
+        unsafe {
+            SQLSetConnectAttrW(
+                self.handle,
+                ConnectionAttribute::AccessMode,
+                mode as usize as Pointer,
+                0, // will be ignored according to ODBC spec
+            )
+            .into_sql_result("SQLSetConnectAttrW")
+        }
+    }
+
     /// To commit a transaction in manual-commit mode.
     pub fn commit(&self) -> SqlResult<()> {
         unsafe {
@@ -237,6 +331,54 @@ impl<'c> Connection<'c> {
         }
     }
 
+    /// Translates `query` into the native SQL dialect of the data source, i.e. expands ODBC escape
+    /// sequences, and stores the result into the provided `buf`. The query is not executed.
+    pub fn native_sql(&self, query: &U16Str, buf: &mut Vec<u16>) -> SqlResult<()> {
+        // String length in characters. Terminating zero is excluded.
+        let mut string_length: i32 = 0;
+        // Let's utilize all of `buf`s capacity.
+        buf.resize(buf.capacity(), 0);
+
+        unsafe {
+            let mut res = SQLNativeSqlW(
+                self.handle,
+                buf_ptr(query.as_slice()),
+                query.len().try_into().unwrap(),
+                mut_buf_ptr(buf),
+                clamp_int(buf.len()),
+                &mut string_length,
+            )
+            .into_sql_result("SQLNativeSqlW");
+
+            if res.is_err() {
+                return res;
+            }
+
+            // Call has been a success but let's check if the buffer had been large enough.
+            if clamp_int(buf.len()) < string_length + 1 {
+                // It seems we must try again with a large enough buffer.
+                buf.resize((string_length + 1).try_into().unwrap(), 0);
+                res = SQLNativeSqlW(
+                    self.handle,
+                    buf_ptr(query.as_slice()),
+                    query.len().try_into().unwrap(),
+                    mut_buf_ptr(buf),
+                    clamp_int(buf.len()),
+                    &mut string_length,
+                )
+                .into_sql_result("SQLNativeSqlW");
+
+                if res.is_err() {
+                    return res;
+                }
+            }
+
+            // Resize buffer to exact string length without terminal zero
+            buf.resize(string_length.try_into().unwrap(), 0);
+            res
+        }
+    }
+
     fn info_u16(&self, info_type: InfoType) -> SqlResult<u16> {
         unsafe {
             let mut value = 0u16;
@@ -320,6 +462,20 @@ impl<'c> Connection<'c> {
         }
     }
 
+    /// Sets the catalog (i.e. the database) used by the connection. Some drivers only allow changing
+    /// the catalog before any statement has been executed on the connection.
+    pub fn set_current_catalog(&self, catalog_name: &U16Str) -> SqlResult<()> {
+        unsafe {
+            SQLSetConnectAttrW(
+                self.handle,
+                ConnectionAttribute::CurrentCatalog,
+                buf_ptr(catalog_name.as_slice()) as Pointer,
+                (catalog_name.len() * 2).try_into().unwrap(),
+            )
+            .into_sql_result("SQLSetConnectAttrW")
+        }
+    }
+
     /// Indicates the state of the connection. If `true` the connection has been lost. If `false`,
     /// the connection is still active.
     pub fn is_dead(&self) -> SqlResult<bool> {
@@ -333,6 +489,35 @@ impl<'c> Connection<'c> {
         }
     }
 
+    /// Number of seconds to wait for a login request to complete. See
+    /// [`Self::set_login_timeout_sec`].
+    pub fn login_timeout_sec(&self) -> SqlResult<u32> {
+        unsafe {
+            self.numeric_attribute(ConnectionAttribute::LoginTimeout)
+                .map(|v| v.try_into().unwrap())
+        }
+    }
+
+    /// Network packet size in bytes used by the established connection.
+    pub fn packet_size(&self) -> SqlResult<u32> {
+        unsafe {
+            self.numeric_attribute(ConnectionAttribute::PacketSize)
+                .map(|v| v.try_into().unwrap())
+        }
+    }
+
+    /// Access mode of the connection. See [`Self::set_access_mode`].
+    pub fn access_mode(&self) -> SqlResult<AccessMode> {
+        unsafe {
+            self.numeric_attribute(ConnectionAttribute::AccessMode)
+                .map(|v| match v {
+                    0 => AccessMode::ReadWrite,
+                    1 => AccessMode::ReadOnly,
+                    other => panic!("Unexpected result value from SQLGetConnectAttrW: {}", other),
+                })
+        }
+    }
+
     /// # Safety
     ///
     /// Caller must ensure connection attribute is numeric.
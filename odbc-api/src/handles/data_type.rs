@@ -389,6 +389,62 @@ impl DataType {
             other => other.display_size(),
         }
     }
+
+    /// Renders the data type as it would be written in a `CREATE TABLE` statement, e.g.
+    /// `VARCHAR(255)` or `DECIMAL(19,4)`. Type names follow the ODBC SQL grammar, so the result is
+    /// portable to many, but not all data sources.
+    ///
+    /// `None` for [`DataType::Unknown`], [`DataType::Other`] and types with a length of `0`. Some
+    /// drivers report variable length types without an upper bound (e.g. `VARCHAR(MAX)` on
+    /// Microsoft SQL Server) with a length of zero, and there is no portable way to render these.
+    ///
+    /// ```
+    /// use odbc_api::DataType;
+    ///
+    /// assert_eq!(
+    ///     Some("VARCHAR(255)".to_string()),
+    ///     DataType::Varchar { length: 255 }.to_sql_ddl()
+    /// );
+    /// assert_eq!(
+    ///     Some("DECIMAL(19,4)".to_string()),
+    ///     DataType::Decimal { precision: 19, scale: 4 }.to_sql_ddl()
+    /// );
+    /// ```
+    pub fn to_sql_ddl(&self) -> Option<String> {
+        let ddl = match self {
+            DataType::Unknown | DataType::Other { .. } => return None,
+            // Variable length types without a maximum length are reported with a length of zero.
+            DataType::Char { length: 0 }
+            | DataType::WChar { length: 0 }
+            | DataType::Varchar { length: 0 }
+            | DataType::WVarchar { length: 0 }
+            | DataType::Binary { length: 0 }
+            | DataType::Varbinary { length: 0 } => return None,
+            DataType::Char { length } => format!("CHAR({})", length),
+            DataType::WChar { length } => format!("NCHAR({})", length),
+            DataType::Varchar { length } => format!("VARCHAR({})", length),
+            DataType::WVarchar { length } => format!("NVARCHAR({})", length),
+            DataType::LongVarchar { .. } => "LONG VARCHAR".to_string(),
+            DataType::Binary { length } => format!("BINARY({})", length),
+            DataType::Varbinary { length } => format!("VARBINARY({})", length),
+            DataType::LongVarbinary { .. } => "LONG VARBINARY".to_string(),
+            DataType::Numeric { precision, scale } => format!("NUMERIC({},{})", precision, scale),
+            DataType::Decimal { precision, scale } => format!("DECIMAL({},{})", precision, scale),
+            DataType::TinyInt => "TINYINT".to_string(),
+            DataType::SmallInt => "SMALLINT".to_string(),
+            DataType::Integer => "INTEGER".to_string(),
+            DataType::BigInt => "BIGINT".to_string(),
+            DataType::Float { precision } => format!("FLOAT({})", precision),
+            DataType::Real => "REAL".to_string(),
+            DataType::Double => "DOUBLE PRECISION".to_string(),
+            DataType::Bit => "BIT".to_string(),
+            DataType::Date => "DATE".to_string(),
+            // Always render the precision, since the default differs between data sources.
+            DataType::Time { precision } => format!("TIME({})", precision),
+            DataType::Timestamp { precision } => format!("TIMESTAMP({})", precision),
+        };
+        Some(ddl)
+    }
 }
 
 impl Default for DataType {
@@ -396,3 +452,74 @@ impl Default for DataType {
         DataType::Unknown
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::DataType;
+
+    #[test]
+    fn render_char_as_sql_ddl() {
+        assert_eq!(
+            Some("CHAR(10)".to_string()),
+            DataType::Char { length: 10 }.to_sql_ddl()
+        );
+        assert_eq!(
+            Some("NVARCHAR(50)".to_string()),
+            DataType::WVarchar { length: 50 }.to_sql_ddl()
+        );
+    }
+
+    #[test]
+    fn render_unbounded_varchar_as_sql_ddl() {
+        assert_eq!(None, DataType::Varchar { length: 0 }.to_sql_ddl());
+        assert_eq!(None, DataType::WVarchar { length: 0 }.to_sql_ddl());
+        assert_eq!(None, DataType::Varbinary { length: 0 }.to_sql_ddl());
+        assert_eq!(
+            Some("LONG VARCHAR".to_string()),
+            DataType::LongVarchar { length: 2147483647 }.to_sql_ddl()
+        );
+    }
+
+    #[test]
+    fn render_decimal_as_sql_ddl() {
+        assert_eq!(
+            Some("DECIMAL(19,4)".to_string()),
+            DataType::Decimal {
+                precision: 19,
+                scale: 4
+            }
+            .to_sql_ddl()
+        );
+    }
+
+    #[test]
+    fn render_integers_as_sql_ddl() {
+        assert_eq!(Some("INTEGER".to_string()), DataType::Integer.to_sql_ddl());
+        assert_eq!(
+            Some("SMALLINT".to_string()),
+            DataType::SmallInt.to_sql_ddl()
+        );
+        assert_eq!(Some("BIGINT".to_string()), DataType::BigInt.to_sql_ddl());
+    }
+
+    #[test]
+    fn render_timestamp_as_sql_ddl() {
+        assert_eq!(
+            Some("TIMESTAMP(0)".to_string()),
+            DataType::Timestamp { precision: 0 }.to_sql_ddl()
+        );
+        assert_eq!(
+            Some("TIMESTAMP(7)".to_string()),
+            DataType::Timestamp { precision: 7 }.to_sql_ddl()
+        );
+        assert_eq!(
+            Some("TIME(0)".to_string()),
+            DataType::Time { precision: 0 }.to_sql_ddl()
+        );
+    }
+
+    #[test]
+    fn unknown_has_no_sql_ddl() {
+        assert_eq!(None, DataType::Unknown.to_sql_ddl());
+    }
+}
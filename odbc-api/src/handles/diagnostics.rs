@@ -1,8 +1,9 @@
 use super::{
     as_handle::AsHandle,
     buffer::{clamp_small_int, mut_buf_ptr},
+    driver_manager::SQLGetDiagRecW,
 };
-use odbc_sys::{SQLGetDiagRecW, SqlReturn, SQLSTATE_SIZE};
+use odbc_sys::{SqlReturn, SQLSTATE_SIZE};
 use std::fmt;
 use widestring::U16Str;
 
@@ -15,6 +16,27 @@ impl State {
     pub const INVALID_STATE_TRANSACTION: State = State(*b"25000");
     /// Given the specified Attribute value, an invalid value was specified in ValuePtr.
     pub const INVALID_ATTRIBUTE_VALUE: State = State(*b"HY024");
+    /// The driver was unable to establish a connection with the data source.
+    pub const CLIENT_UNABLE_TO_ESTABLISH_CONNECTION: State = State(*b"08001");
+    /// The connection between the driver and the data source failed during the execution of a
+    /// transaction.
+    pub const CONNECTION_FAILURE_DURING_TRANSACTION: State = State(*b"08007");
+    /// The communication link between the driver and the data source failed before the function
+    /// completed processing.
+    pub const COMMUNICATION_LINK_FAILURE: State = State(*b"08S01");
+    /// The query timeout period expired before the data source returned the result set. See
+    /// [`crate::Preallocated::set_query_timeout_sec`].
+    pub const TIMEOUT_EXPIRED: State = State(*b"HYT00");
+    /// The connection timeout period expired before the data source responded to the request.
+    pub const CONNECTION_TIMEOUT_EXPIRED: State = State(*b"HYT01");
+    /// Processing on the statement has been canceled, e.g. by a call to `SQLCancel` from another
+    /// thread.
+    pub const OPERATION_CANCELED: State = State(*b"HY008");
+    /// The driver or data source does not support an optional feature requested by the
+    /// application, e.g. a statement attribute or a cursor type.
+    pub const OPTIONAL_FEATURE_NOT_IMPLEMENTED: State = State(*b"HYC00");
+    /// The driver associated with the connection does not support the function called.
+    pub const DRIVER_DOES_NOT_SUPPORT_FUNCTION: State = State(*b"IM001");
 
     /// `SQLGetDiagRecW` returns ODBC state as wide characters. This constructor converts the wide
     /// characters to narrow and drops the terminating zero.
@@ -0,0 +1,375 @@
+//! Entry points into the ODBC driver manager used by the handles.
+//!
+//! By default these are the functions declared by `odbc_sys`, which are linked at build time. With
+//! the `dynamic-driver-manager` feature they are resolved at runtime from a shared library instead.
+//! See [`crate::Environment::with_driver_manager`].
+
+#[cfg(not(feature = "dynamic-driver-manager"))]
+pub use odbc_sys::{
+    SQLAllocHandle, SQLBindCol, SQLBindParameter, SQLBrowseConnectW, SQLCancel, SQLCloseCursor,
+    SQLColAttributeW, SQLColumnsW, SQLConnectW, SQLDataSourcesW, SQLDescribeColW, SQLDescribeParam,
+    SQLDisconnect, SQLDriverConnectW, SQLDriversW, SQLEndTran, SQLExecDirect, SQLExecDirectW,
+    SQLExecute, SQLFetch, SQLFetchScroll, SQLFreeHandle, SQLFreeStmt, SQLGetConnectAttrW,
+    SQLGetData, SQLGetDiagRecW, SQLGetInfoW, SQLGetTypeInfo, SQLMoreResults, SQLNumResultCols,
+    SQLParamData, SQLPrepare, SQLPrepareW, SQLPutData, SQLRowCount, SQLSetConnectAttrW,
+    SQLSetEnvAttr, SQLSetStmtAttrW, SQLTablesW,
+};
+
+#[cfg(not(feature = "dynamic-driver-manager"))]
+pub use self::linked::*;
+
+/// Functions of the driver manager, which are not declared by the version of `odbc_sys` this crate
+/// depends on. The driver manager library itself is still linked by `odbc_sys`.
+#[cfg(not(feature = "dynamic-driver-manager"))]
+mod linked {
+    use crate::handles::{Lock, Operation};
+    use odbc_sys::{HDbc, HStmt, Integer, SmallInt, SqlReturn, ULen, USmallInt, WChar};
+
+    extern "system" {
+        /// Returns the SQL string as modified by the driver, without executing it.
+        pub fn SQLNativeSqlW(
+            connection_handle: HDbc,
+            in_statement_text: *const WChar,
+            in_statement_length: Integer,
+            out_statement_text: *mut WChar,
+            buffer_length: Integer,
+            out_statement_length: *mut Integer,
+        ) -> SqlReturn;
+
+        /// Sets the cursor position in a row set and allows an application to refresh data in the
+        /// row set or to update or delete data in the result set.
+        pub fn SQLSetPos(
+            statement_handle: HStmt,
+            row_number: ULen,
+            operation: Operation,
+            lock_type: Lock,
+        ) -> SqlReturn;
+
+        /// Retrieves a list of statistics about a single table and the indexes associated with
+        /// the table.
+        pub fn SQLStatisticsW(
+            statement_handle: HStmt,
+            catalog_name: *const WChar,
+            catalog_name_length: SmallInt,
+            schema_name: *const WChar,
+            schema_name_length: SmallInt,
+            table_name: *const WChar,
+            table_name_length: SmallInt,
+            unique: USmallInt,
+            reserved: USmallInt,
+        ) -> SqlReturn;
+    }
+}
+
+#[cfg(feature = "dynamic-driver-manager")]
+pub use self::dynamic::*;
+
+#[cfg(feature = "dynamic-driver-manager")]
+mod dynamic {
+    use std::{
+        ffi::OsStr,
+        path::{Path, PathBuf},
+    };
+
+    use crate::handles::{Lock, Operation};
+    use libloading::Library;
+    use odbc_sys::{
+        CDataType, Char, CompletionType, ConnectionAttribute, Desc, DriverConnectOption,
+        EnvironmentAttribute, FetchOrientation, FreeStmtOption, HDbc, HEnv, HStmt, HWnd, Handle,
+        HandleType, InfoType, Integer, Len, Nullability, ParamType, Pointer, SmallInt, SqlDataType,
+        SqlReturn, StatementAttribute, ULen, USmallInt, WChar,
+    };
+    use once_cell::sync::OnceCell;
+
+    /// File name of the driver manager loaded, if the application does not specify one
+    /// explicitly.
+    #[cfg(windows)]
+    pub const DEFAULT_DRIVER_MANAGER: &str = "odbc32.dll";
+    /// File name of the driver manager loaded, if the application does not specify one
+    /// explicitly.
+    #[cfg(target_os = "macos")]
+    pub const DEFAULT_DRIVER_MANAGER: &str = "libodbc.2.dylib";
+    /// File name of the driver manager loaded, if the application does not specify one
+    /// explicitly.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub const DEFAULT_DRIVER_MANAGER: &str = "libodbc.so.2";
+
+    /// The driver manager all calls are routed to. Once loaded it stays loaded for the lifetime
+    /// of the process, since handles allocated by one driver manager can not be passed to another.
+    static DRIVER_MANAGER: OnceCell<DriverManager> = OnceCell::new();
+
+    /// Loads the driver manager from the shared library at `path`, unless a driver manager has
+    /// already been loaded.
+    ///
+    /// # Return
+    ///
+    /// Path of the driver manager used by this process. May differ from `path`, if another driver
+    /// manager had been loaded before.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library executes its initialization routines. `path` must point to an ODBC driver
+    /// manager.
+    pub unsafe fn load_driver_manager(path: &OsStr) -> Result<&'static Path, libloading::Error> {
+        DRIVER_MANAGER
+            .get_or_try_init(|| DriverManager::load(path))
+            .map(|driver_manager| driver_manager.path.as_path())
+    }
+
+    fn driver_manager() -> &'static DriverManager {
+        DRIVER_MANAGER
+            .get()
+            .expect("ODBC driver manager must be loaded before calling into it.")
+    }
+
+    /// Declares a field in [`DriverManager`] for each function, and a free function with the same
+    /// name and signature as in `odbc_sys`, which forwards to it.
+    macro_rules! driver_manager_functions {
+        ($($name:ident($($arg:ident: $arg_type:ty),* $(,)?);)*) => {
+            #[allow(non_snake_case)]
+            struct DriverManager {
+                /// Path the library has been loaded from.
+                path: PathBuf,
+                /// Keeps the function pointers below valid.
+                _library: Library,
+                $($name: unsafe extern "system" fn($($arg_type),*) -> SqlReturn,)*
+            }
+
+            impl DriverManager {
+                unsafe fn load(path: &OsStr) -> Result<Self, libloading::Error> {
+                    let library = Library::new(path)?;
+                    $(
+                        let $name: unsafe extern "system" fn($($arg_type),*) -> SqlReturn =
+                            *library.get(concat!(stringify!($name), "\0").as_bytes())?;
+                    )*
+                    Ok(DriverManager {
+                        path: PathBuf::from(path),
+                        _library: library,
+                        $($name,)*
+                    })
+                }
+            }
+
+            $(
+                #[allow(non_snake_case, clippy::too_many_arguments)]
+                pub unsafe fn $name($($arg: $arg_type),*) -> SqlReturn {
+                    (driver_manager().$name)($($arg),*)
+                }
+            )*
+        };
+    }
+
+    driver_manager_functions! {
+        SQLAllocHandle(handle_type: HandleType, input_handle: Handle, output_handle: *mut Handle);
+        SQLFreeHandle(handle_type: HandleType, handle: Handle);
+        SQLSetEnvAttr(
+            environment_handle: HEnv,
+            attribute: EnvironmentAttribute,
+            value: Pointer,
+            string_length: Integer,
+        );
+        SQLDriversW(
+            environment_handle: HEnv,
+            direction: FetchOrientation,
+            driver_desc: *mut WChar,
+            driver_desc_max: SmallInt,
+            out_driver_desc: *mut SmallInt,
+            driver_attributes: *mut WChar,
+            driver_attr_max: SmallInt,
+            out_driver_attr: *mut SmallInt,
+        );
+        SQLDataSourcesW(
+            environment_handle: HEnv,
+            direction: FetchOrientation,
+            server_name: *mut WChar,
+            buffer_length_1: SmallInt,
+            name_length_1: *mut SmallInt,
+            description: *mut WChar,
+            buffer_length_2: SmallInt,
+            name_length_2: *mut SmallInt,
+        );
+        SQLGetDiagRecW(
+            handle_type: HandleType,
+            handle: Handle,
+            record_number: SmallInt,
+            state: *mut WChar,
+            native_error_ptr: *mut Integer,
+            message_text: *mut WChar,
+            buffer_length: SmallInt,
+            text_length_ptr: *mut SmallInt,
+        );
+        SQLConnectW(
+            connection_handle: HDbc,
+            server_name: *const WChar,
+            name_length_1: SmallInt,
+            user_name: *const WChar,
+            name_length_2: SmallInt,
+            authentication: *const WChar,
+            name_length_3: SmallInt,
+        );
+        SQLDriverConnectW(
+            connection_handle: HDbc,
+            window_handle: HWnd,
+            in_connection_string: *const WChar,
+            string_length_1: SmallInt,
+            out_connection_string: *mut WChar,
+            buffer_length: SmallInt,
+            string_length_2: *mut SmallInt,
+            driver_completion: DriverConnectOption,
+        );
+        SQLBrowseConnectW(
+            connection_handle: HDbc,
+            in_connection_string: *const WChar,
+            string_length: SmallInt,
+            out_connection_string: *mut WChar,
+            buffer_length: SmallInt,
+            out_buffer_length: *mut SmallInt,
+        );
+        SQLDisconnect(connection_handle: HDbc);
+        SQLEndTran(handle_type: HandleType, handle: Handle, completion_type: CompletionType);
+        SQLSetConnectAttrW(
+            connection_handle: HDbc,
+            attribute: ConnectionAttribute,
+            value: Pointer,
+            string_length: Integer,
+        );
+        SQLGetConnectAttrW(
+            connection_handle: HDbc,
+            attribute: ConnectionAttribute,
+            value_ptr: Pointer,
+            buffer_length: Integer,
+            string_length_ptr: *mut Integer,
+        );
+        SQLGetInfoW(
+            connection_handle: HDbc,
+            info_type: InfoType,
+            info_value_ptr: Pointer,
+            buffer_length: SmallInt,
+            string_length_ptr: *mut SmallInt,
+        );
+        SQLNativeSqlW(
+            connection_handle: HDbc,
+            in_statement_text: *const WChar,
+            in_statement_length: Integer,
+            out_statement_text: *mut WChar,
+            buffer_length: Integer,
+            out_statement_length: *mut Integer,
+        );
+        SQLBindCol(
+            statement_handle: HStmt,
+            column_number: USmallInt,
+            target_type: CDataType,
+            target_value: Pointer,
+            buffer_length: Len,
+            length_or_indicator: *mut Len,
+        );
+        SQLBindParameter(
+            statement_handle: HStmt,
+            parameter_number: USmallInt,
+            input_output_type: ParamType,
+            value_type: CDataType,
+            parameter_type: SqlDataType,
+            column_size: ULen,
+            decimal_digits: SmallInt,
+            parameter_value_ptr: Pointer,
+            buffer_length: Len,
+            str_len_or_ind_ptr: *mut Len,
+        );
+        SQLCancel(statement_handle: HStmt);
+        SQLCloseCursor(statement_handle: HStmt);
+        SQLColAttributeW(
+            statement_handle: HStmt,
+            column_number: USmallInt,
+            field_identifier: Desc,
+            character_attribute_ptr: Pointer,
+            buffer_length: SmallInt,
+            string_length_ptr: *mut SmallInt,
+            numeric_attribute_ptr: *mut Len,
+        );
+        SQLColumnsW(
+            statement_handle: HStmt,
+            catalog_name: *const WChar,
+            catalog_name_length: SmallInt,
+            schema_name: *const WChar,
+            schema_name_length: SmallInt,
+            table_name: *const WChar,
+            table_name_length: SmallInt,
+            column_name: *const WChar,
+            column_name_length: SmallInt,
+        );
+        SQLDescribeColW(
+            statement_handle: HStmt,
+            column_number: USmallInt,
+            column_name: *mut WChar,
+            buffer_length: SmallInt,
+            name_length: *mut SmallInt,
+            data_type: *mut SqlDataType,
+            column_size: *mut ULen,
+            decimal_digits: *mut SmallInt,
+            nullable: *mut Nullability,
+        );
+        SQLDescribeParam(
+            statement_handle: HStmt,
+            parameter_number: USmallInt,
+            data_type_ptr: *mut SqlDataType,
+            parameter_size_ptr: *mut ULen,
+            decimal_digits_ptr: *mut SmallInt,
+            nullable_ptr: *mut Nullability,
+        );
+        SQLExecDirect(statement_handle: HStmt, statement_text: *const Char, text_length: Integer);
+        SQLExecDirectW(statement_handle: HStmt, statement_text: *const WChar, text_length: Integer);
+        SQLExecute(statement_handle: HStmt);
+        SQLFetch(statement_handle: HStmt);
+        SQLFetchScroll(
+            statement_handle: HStmt,
+            fetch_orientation: FetchOrientation,
+            fetch_offset: Len,
+        );
+        SQLFreeStmt(statement_handle: HStmt, option: FreeStmtOption);
+        SQLGetData(
+            statement_handle: HStmt,
+            col_or_param_num: USmallInt,
+            target_type: CDataType,
+            target_value_ptr: Pointer,
+            buffer_length: Len,
+            str_len_or_ind_ptr: *mut Len,
+        );
+        SQLGetTypeInfo(statement_handle: HStmt, data_type: SqlDataType);
+        SQLMoreResults(statement_handle: HStmt);
+        SQLNumResultCols(statement_handle: HStmt, column_count_ptr: *mut SmallInt);
+        SQLParamData(statement_handle: HStmt, value_ptr_ptr: *mut Pointer);
+        SQLPrepare(statement_handle: HStmt, statement_text: *const Char, text_length: Integer);
+        SQLPrepareW(statement_handle: HStmt, statement_text: *const WChar, text_length: Integer);
+        SQLPutData(statement_handle: HStmt, data_ptr: Pointer, str_len_or_ind_ptr: Len);
+        SQLRowCount(statement_handle: HStmt, row_count_ptr: *mut Len);
+        SQLSetPos(statement_handle: HStmt, row_number: ULen, operation: Operation, lock_type: Lock);
+        SQLSetStmtAttrW(
+            statement_handle: HStmt,
+            attribute: StatementAttribute,
+            value: Pointer,
+            string_length: Integer,
+        );
+        SQLStatisticsW(
+            statement_handle: HStmt,
+            catalog_name: *const WChar,
+            catalog_name_length: SmallInt,
+            schema_name: *const WChar,
+            schema_name_length: SmallInt,
+            table_name: *const WChar,
+            table_name_length: SmallInt,
+            unique: USmallInt,
+            reserved: USmallInt,
+        );
+        SQLTablesW(
+            statement_handle: HStmt,
+            catalog_name: *const WChar,
+            catalog_name_length: SmallInt,
+            schema_name: *const WChar,
+            schema_name_length: SmallInt,
+            table_name: *const WChar,
+            table_name_length: SmallInt,
+            table_type: *const WChar,
+            table_type_length: SmallInt,
+        );
+    }
+}
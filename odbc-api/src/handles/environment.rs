@@ -1,12 +1,13 @@
 use super::{
     as_handle::AsHandle,
+    driver_manager::{SQLAllocHandle, SQLDataSourcesW, SQLDriversW, SQLSetEnvAttr},
     drop_handle,
     sql_result::{ExtSqlReturn, SqlResult},
     Connection,
 };
 use odbc_sys::{
     AttrCpMatch, AttrOdbcVersion, EnvironmentAttribute, FetchOrientation, HDbc, HEnv, Handle,
-    HandleType, SQLAllocHandle, SQLDataSourcesW, SQLDriversW, SQLSetEnvAttr,
+    HandleType,
 };
 use std::ptr::null_mut;
 
@@ -253,7 +254,7 @@ impl Environment {
         let mut length_name: i16 = 0;
         let mut length_description: i16 = 0;
         // Determine required buffer size
-        odbc_sys::SQLDataSourcesW(
+        SQLDataSourcesW(
             self.handle,
             direction,
             null_mut(),
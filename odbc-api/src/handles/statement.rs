@@ -4,16 +4,20 @@ use super::{
     buffer::{buf_ptr, clamp_small_int, mut_buf_ptr},
     column_description::{ColumnDescription, Nullability},
     data_type::DataType,
+    driver_manager::{
+        SQLBindCol, SQLBindParameter, SQLCancel, SQLCloseCursor, SQLColAttributeW, SQLColumnsW,
+        SQLDescribeColW, SQLDescribeParam, SQLExecDirect, SQLExecDirectW, SQLExecute, SQLFetch,
+        SQLFetchScroll, SQLFreeStmt, SQLGetData, SQLGetTypeInfo, SQLMoreResults, SQLNumResultCols,
+        SQLParamData, SQLPrepare, SQLPrepareW, SQLPutData, SQLRowCount, SQLSetPos, SQLSetStmtAttrW,
+        SQLStatisticsW, SQLTablesW,
+    },
     drop_handle,
     sql_result::ExtSqlReturn,
     CData, SqlResult,
 };
 use odbc_sys::{
-    Desc, FreeStmtOption, HDbc, HStmt, Handle, HandleType, Len, ParamType, Pointer, SQLBindCol,
-    SQLBindParameter, SQLCloseCursor, SQLColAttributeW, SQLColumnsW, SQLDescribeColW,
-    SQLDescribeParam, SQLExecDirectW, SQLExecute, SQLFetch, SQLFreeStmt, SQLGetData,
-    SQLNumResultCols, SQLParamData, SQLPrepareW, SQLPutData, SQLSetStmtAttrW, SQLTablesW,
-    SqlDataType, SqlReturn, StatementAttribute, ULen,
+    Desc, FetchOrientation, FreeStmtOption, HDbc, HStmt, Handle, HandleType, Len, ParamType,
+    Pointer, SqlDataType, SqlReturn, StatementAttribute, ULen,
 };
 use std::{
     ffi::c_void,
@@ -23,6 +27,59 @@ use std::{
 };
 use widestring::U16Str;
 
+/// Type of the cursor used to iterate over a result set. See [`Statement::set_cursor_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorType {
+    /// Only allows scrolling forward. Default.
+    ForwardOnly = 0,
+    /// Membership and order of the rows are fixed when the cursor is opened, but changes to the
+    /// values of the rows are visible.
+    KeysetDriven = 1,
+    /// Changes to membership, order and values of rows are visible.
+    Dynamic = 2,
+    /// The result set is fixed when the cursor is opened.
+    Static = 3,
+}
+
+/// Concurrency control for cursors. See [`Statement::set_concurrency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Concurrency {
+    /// Cursor is read only. No updates are allowed. Default.
+    ReadOnly = 1,
+    /// Uses the lowest level of locking sufficient to ensure that the row can be updated.
+    Lock = 2,
+    /// Optimistic concurrency control comparing row versions.
+    RowVersion = 3,
+    /// Optimistic concurrency control comparing values.
+    Values = 4,
+}
+
+/// Operation performed by [`Statement::set_pos`] on a row of the row set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Operation {
+    /// Positions the cursor on the row, without changing any data.
+    Position = 0,
+    /// Fetches the values of the row from the data source again.
+    Refresh = 1,
+    /// Writes the values of the bound columns to the row in the data source.
+    Update = 2,
+    /// Deletes the row from the data source.
+    Delete = 3,
+}
+
+/// How [`Statement::set_pos`] locks a row after performing its operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Lock {
+    /// Leaves the row in the lock state it has been in before.
+    NoChange = 0,
+    /// Locks the row exclusively.
+    Exclusive = 1,
+    /// Unlocks the row.
+    Unlock = 2,
+}
+
 /// Wraps a valid (i.e. successfully allocated) ODBC statement handle.
 pub struct StatementImpl<'s> {
     parent: PhantomData<&'s HDbc>,
@@ -123,6 +180,55 @@ pub trait Statement: AsHandle {
         SQLFetch(self.as_sys()).into_opt_sql_result("SQLFetch")
     }
 
+    /// Fetches the specified row set of data from the result set and returns data for all bound
+    /// columns. Row sets can be specified at an absolute or relative position. Positions other than
+    /// [`FetchOrientation::Next`] require a scrollable cursor, which must be requested using
+    /// [`Self::set_cursor_scrollable`] before the statement is executed.
+    ///
+    /// * `orientation`: Type of fetch.
+    /// * `offset`: Number of the row to fetch. Only used for [`FetchOrientation::Absolute`] and
+    ///   [`FetchOrientation::Relative`], ignored otherwise.
+    ///
+    /// # Safety
+    ///
+    /// Fetch dereferences bound column pointers.
+    unsafe fn fetch_scroll(
+        &mut self,
+        orientation: FetchOrientation,
+        offset: isize,
+    ) -> Option<SqlResult<()>> {
+        SQLFetchScroll(self.as_sys(), orientation, offset).into_opt_sql_result("SQLFetchScroll")
+    }
+
+    /// Sets the cursor position in the current row set and may refresh, update or delete data in
+    /// the row set. Use [`Operation::Position`] to select the row subsequent calls to
+    /// [`Self::get_data`] refer to, if the cursor fetches more than one row at once.
+    ///
+    /// [`Operation::Update`] writes the values of the bound columns to the data source,
+    /// [`Operation::Delete`] deletes the row and [`Operation::Refresh`] fetches it again. These
+    /// require a cursor which is not read only, see [`Self::set_concurrency`], and usually a keyset
+    /// driven or dynamic cursor, see [`Self::set_cursor_type`]. Drivers generally only support
+    /// updatable cursors for result sets generated from a single table, which contain the key
+    /// columns of that table.
+    ///
+    /// * `row_number`: One based position of the row in the row set. `0` applies the operation to
+    ///   every row in the row set.
+    /// * `operation`: Operation to perform.
+    /// * `lock`: How to lock the row after performing the operation. Use [`Lock::NoChange`] to
+    ///   leave the lock state as it is.
+    ///
+    /// # Safety
+    ///
+    /// Refresh and update operations dereference bound column pointers.
+    unsafe fn set_pos(
+        &mut self,
+        row_number: usize,
+        operation: Operation,
+        lock: Lock,
+    ) -> SqlResult<()> {
+        SQLSetPos(self.as_sys(), row_number as _, operation, lock).into_sql_result("SQLSetPos")
+    }
+
     /// Retrieves data for a single column in the result set or for a single parameter.
     fn get_data(&mut self, col_or_param_num: u16, target: &mut impl CDataMut) -> SqlResult<()> {
         unsafe {
@@ -240,6 +346,63 @@ pub trait Statement: AsHandle {
         }
     }
 
+    /// Like [`Self::exec_direct`], but passes the statement text as UTF-8 to the narrow
+    /// `SQLExecDirect`, rather than as UTF-16 to `SQLExecDirectW`. This saves transcoding the
+    /// statement text, but requires the driver to interpret narrow strings as UTF-8, as is usually
+    /// the case for drivers managed by unixODBC.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::exec_direct`].
+    unsafe fn exec_direct_narrow(&mut self, statement_text: &str) -> SqlResult<bool> {
+        match SQLExecDirect(
+            self.as_sys(),
+            buf_ptr(statement_text.as_bytes()),
+            statement_text.len().try_into().unwrap(),
+        ) {
+            SqlReturn::NEED_DATA => SqlResult::Success(true),
+            // A searched update or delete statement that does not affect any rows at the data
+            // source.
+            SqlReturn::NO_DATA => SqlResult::Success(false),
+            other => other.into_sql_result("SQLExecDirect").on_success(|| false),
+        }
+    }
+
+    /// Like [`Self::exec_direct`], but for statements with asynchronous execution enabled (see
+    /// [`Self::set_async_enable`]). Must be called repeatedly with the same statement text until
+    /// the statement no longer executes.
+    ///
+    /// # Safety
+    ///
+    /// While `self` as always guaranteed to be a valid allocated handle, this function may
+    /// dereference bound parameters. It is the callers responsibility to ensure these are still
+    /// valid.
+    ///
+    /// # Return
+    ///
+    /// `None` if the statement is still executing. Otherwise `true` if execution requires
+    /// additional data from delayed parameters.
+    unsafe fn exec_direct_async(&mut self, statement_text: &U16Str) -> Option<SqlResult<bool>> {
+        match SQLExecDirectW(
+            self.as_sys(),
+            buf_ptr(statement_text.as_slice()),
+            statement_text.len().try_into().unwrap(),
+        ) {
+            SqlReturn::STILL_EXECUTING => None,
+            SqlReturn::NEED_DATA => Some(SqlResult::Success(true)),
+            // A searched update or delete statement that does not affect any rows at the data
+            // source.
+            SqlReturn::NO_DATA => Some(SqlResult::Success(false)),
+            other => Some(other.into_sql_result("SQLExecDirectW").on_success(|| false)),
+        }
+    }
+
+    /// Cancels the processing on the statement. ODBC allows calling this function from another
+    /// thread, than the one executing a function on the statement.
+    fn cancel(&self) -> SqlResult<()> {
+        unsafe { SQLCancel(self.as_sys()) }.into_sql_result("SQLCancel")
+    }
+
     /// Close an open cursor.
     fn close_cursor(&mut self) -> SqlResult<()> {
         unsafe { SQLCloseCursor(self.as_sys()) }.into_sql_result("SQLCloseCursor")
@@ -259,6 +422,19 @@ pub trait Statement: AsHandle {
         .into_sql_result("SQLPrepareW")
     }
 
+    /// Like [`Self::prepare`], but passes the statement text as UTF-8 to the narrow `SQLPrepare`,
+    /// rather than as UTF-16 to `SQLPrepareW`. See [`Self::exec_direct_narrow`].
+    fn prepare_narrow(&mut self, statement_text: &str) -> SqlResult<()> {
+        unsafe {
+            SQLPrepare(
+                self.as_sys(),
+                buf_ptr(statement_text.as_bytes()),
+                statement_text.len().try_into().unwrap(),
+            )
+        }
+        .into_sql_result("SQLPrepare")
+    }
+
     /// Executes a statement prepared by `prepare`. After the application processes or discards the
     /// results from a call to `execute`, the application can call SQLExecute again with new
     /// parameter values.
@@ -293,6 +469,29 @@ pub trait Statement: AsHandle {
             .on_success(|| out)
     }
 
+    /// Closes the current result set and moves on to the next result of a statement which returns
+    /// several of them, e.g. a stored procedure or a batch of statements. The next result may
+    /// either be a result set or a row count.
+    ///
+    /// # Return
+    ///
+    /// `false` if there are no more results.
+    fn more_results(&mut self) -> SqlResult<bool> {
+        match unsafe { SQLMoreResults(self.as_sys()) } {
+            SqlReturn::NO_DATA => SqlResult::Success(false),
+            other => other.into_sql_result("SQLMoreResults").on_success(|| true),
+        }
+    }
+
+    /// Number of rows affected by an `UPDATE`, `INSERT`, or `DELETE` statement. Drivers return
+    /// `-1` if the number of affected rows is not available.
+    fn row_count(&mut self) -> SqlResult<isize> {
+        let mut out: Len = 0;
+        unsafe { SQLRowCount(self.as_sys(), &mut out) }
+            .into_sql_result("SQLRowCount")
+            .on_success(|| out)
+    }
+
     /// Sets the batch size for bulk cursors, if retrieving many rows at once.
     ///
     /// # Safety
@@ -310,6 +509,51 @@ pub trait Statement: AsHandle {
         .into_sql_result("SQLSetStmtAttrW")
     }
 
+    /// Sets the maximum number of rows returned for result sets of statements executed from now
+    /// on (`SQL_ATTR_MAX_ROWS`). `0` means unlimited. This is a hint, drivers which do not support
+    /// it ignore it, which is usually reported as a warning.
+    fn set_max_rows(&mut self, max_rows: usize) -> SqlResult<()> {
+        unsafe {
+            SQLSetStmtAttrW(
+                self.as_sys(),
+                StatementAttribute::MaxRows,
+                max_rows as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttrW")
+        }
+    }
+
+    /// Sets the maximum number of bytes returned for a single value of a character or binary
+    /// column (`SQL_ATTR_MAX_LENGTH`). Longer values are truncated silently. `0` means unlimited.
+    /// Like `SQL_ATTR_MAX_ROWS` this is a hint, which drivers may ignore.
+    fn set_max_length(&mut self, max_length: usize) -> SqlResult<()> {
+        unsafe {
+            SQLSetStmtAttrW(
+                self.as_sys(),
+                StatementAttribute::MaxLength,
+                max_length as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttrW")
+        }
+    }
+
+    /// Sets the number of seconds to wait for statements executed from now on to finish, before
+    /// returning to the application (`SQL_ATTR_QUERY_TIMEOUT`). `0` means no timeout, which is the
+    /// default.
+    fn set_query_timeout_sec(&mut self, timeout_sec: usize) -> SqlResult<()> {
+        unsafe {
+            SQLSetStmtAttrW(
+                self.as_sys(),
+                StatementAttribute::QueryTimeout,
+                timeout_sec as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttrW")
+        }
+    }
+
     /// Specifies the number of values for each parameter. If it is greater than 1, the data and
     /// indicator buffers of the statement point to arrays. The cardinality of each array is equal
     /// to the value of this field.
@@ -348,6 +592,65 @@ pub trait Statement: AsHandle {
         .into_sql_result("SQLSetStmtAttrW")
     }
 
+    /// Requests a scrollable cursor, allowing [`Self::fetch_scroll`] to fetch row sets in any
+    /// order. Must be set before the statement is executed (or prepared). Leaves the choice of the
+    /// concrete cursor type to the driver.
+    fn set_cursor_scrollable(&mut self, scrollable: bool) -> SqlResult<()> {
+        unsafe {
+            SQLSetStmtAttrW(
+                self.as_sys(),
+                StatementAttribute::CursorScrollable,
+                scrollable as u8 as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttrW")
+        }
+    }
+
+    /// Sets the type of cursor used for result sets of statements executed from now on. Must be set
+    /// before the statement is executed (or prepared). Drivers may substitute a different cursor
+    /// type if the requested one is not supported, which is reported as a warning.
+    fn set_cursor_type(&mut self, cursor_type: CursorType) -> SqlResult<()> {
+        unsafe {
+            SQLSetStmtAttrW(
+                self.as_sys(),
+                StatementAttribute::CursorType,
+                cursor_type as usize as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttrW")
+        }
+    }
+
+    /// Sets the concurrency control of cursors of statements executed from now on. Must be set
+    /// before the statement is executed (or prepared). Any value other than
+    /// [`Concurrency::ReadOnly`] allows modifying the result set using [`Self::set_pos`].
+    fn set_concurrency(&mut self, concurrency: Concurrency) -> SqlResult<()> {
+        unsafe {
+            SQLSetStmtAttrW(
+                self.as_sys(),
+                StatementAttribute::Concurrency,
+                concurrency as usize as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttrW")
+        }
+    }
+
+    /// Enables or disables asynchronous execution of functions called on this statement. Not all
+    /// drivers support asynchronous execution.
+    fn set_async_enable(&mut self, on: bool) -> SqlResult<()> {
+        unsafe {
+            SQLSetStmtAttrW(
+                self.as_sys(),
+                StatementAttribute::AsyncEnable,
+                on as u8 as Pointer,
+                0,
+            )
+            .into_sql_result("SQLSetStmtAttrW")
+        }
+    }
+
     fn set_metadata_id(&mut self, metadata_id: bool) -> SqlResult<()> {
         unsafe {
             SQLSetStmtAttrW(
@@ -519,49 +822,62 @@ pub trait Statement: AsHandle {
     /// The column alias, if it applies. If the column alias does not apply, the column name is
     /// returned. If there is no column name or a column alias, an empty string is returned.
     fn col_name(&self, column_number: u16, buf: &mut Vec<u16>) -> SqlResult<()> {
+        unsafe { self.string_col_attribute(Desc::Name, column_number, buf) }
+    }
+
+    /// Fetches a string attribute of a column in the result set into `buf`. `buf` is resized to
+    /// hold the entire value, excluding the terminating zero.
+    ///
+    /// # Safety
+    ///
+    /// It is the callers responsibility to ensure that `attribute` refers to a string attribute.
+    unsafe fn string_col_attribute(
+        &self,
+        attribute: Desc,
+        column_number: u16,
+        buf: &mut Vec<u16>,
+    ) -> SqlResult<()> {
         // String length in bytes, not characters. Terminating zero is excluded.
         let mut string_length_in_bytes: i16 = 0;
         // Let's utilize all of `buf`s capacity.
         buf.resize(buf.capacity(), 0);
-        unsafe {
-            let mut res = SQLColAttributeW(
+        let mut res = SQLColAttributeW(
+            self.as_sys(),
+            column_number,
+            attribute,
+            mut_buf_ptr(buf) as Pointer,
+            (buf.len() * 2).try_into().unwrap(),
+            &mut string_length_in_bytes as *mut i16,
+            null_mut(),
+        )
+        .into_sql_result("SQLColAttributeW");
+
+        if res.is_err() {
+            return res;
+        }
+
+        if clamp_small_int(buf.len() * 2) < string_length_in_bytes + 2 {
+            // If we could rely on every ODBC driver sticking to the specifcation it would
+            // probably best to resize by `string_length_in_bytes / 2 + 1`. Yet i.e. SQLite
+            // seems to report the length in characters, so to work with a wide range of DB
+            // systems, and since buffers for names are not expected to become super large we
+            // ommit the division by two here.
+            buf.resize((string_length_in_bytes + 1).try_into().unwrap(), 0);
+            res = SQLColAttributeW(
                 self.as_sys(),
                 column_number,
-                Desc::Name,
+                attribute,
                 mut_buf_ptr(buf) as Pointer,
                 (buf.len() * 2).try_into().unwrap(),
                 &mut string_length_in_bytes as *mut i16,
                 null_mut(),
             )
             .into_sql_result("SQLColAttributeW");
-
-            if res.is_err() {
-                return res;
-            }
-
-            if clamp_small_int(buf.len() * 2) < string_length_in_bytes + 2 {
-                // If we could rely on every ODBC driver sticking to the specifcation it would
-                // probably best to resize by `string_length_in_bytes / 2 + 1`. Yet i.e. SQLite
-                // seems to report the length in characters, so to work with a wide range of DB
-                // systems, and since buffers for names are not expected to become super large we
-                // ommit the division by two here.
-                buf.resize((string_length_in_bytes + 1).try_into().unwrap(), 0);
-                res = SQLColAttributeW(
-                    self.as_sys(),
-                    column_number,
-                    Desc::Name,
-                    mut_buf_ptr(buf) as Pointer,
-                    (buf.len() * 2).try_into().unwrap(),
-                    &mut string_length_in_bytes as *mut i16,
-                    null_mut(),
-                )
-                .into_sql_result("SQLColAttributeW");
-            }
-            // Resize buffer to exact string length without terminal zero
-            buf.resize(((string_length_in_bytes + 1) / 2).try_into().unwrap(), 0);
-
-            res
         }
+        // Resize buffer to exact string length without terminal zero
+        buf.resize(((string_length_in_bytes + 1) / 2).try_into().unwrap(), 0);
+
+        res
     }
 
     /// # Safety
@@ -701,6 +1017,63 @@ pub trait Statement: AsHandle {
         }
     }
 
+    /// Retrieves a list of statistics about a single table and the indexes associated with the
+    /// table. The driver returns the information as a result set.
+    ///
+    /// * `unique_only`: If `true` only unique indexes are returned, otherwise all indexes.
+    /// * `quick`: If `true` `CARDINALITY` and `PAGES` are only returned if they are readily
+    ///   available from the driver or data source. If `false` the driver is requested to retrieve
+    ///   them unconditionally.
+    fn statistics(
+        &mut self,
+        catalog_name: Option<&U16Str>,
+        schema_name: Option<&U16Str>,
+        table_name: &U16Str,
+        unique_only: bool,
+        quick: bool,
+    ) -> SqlResult<()> {
+        // Convert each filter into a pair of buffer pointer and buffer length.
+        let to_buf = |filter: Option<&U16Str>| {
+            if let Some(text) = filter {
+                (buf_ptr(text.as_slice()), text.len().try_into().unwrap())
+            } else {
+                (null(), 0i16)
+            }
+        };
+
+        let catalog = to_buf(catalog_name);
+        let schema = to_buf(schema_name);
+        // SQL_INDEX_UNIQUE = 0, SQL_INDEX_ALL = 1
+        let unique = if unique_only { 0 } else { 1 };
+        // SQL_QUICK = 0, SQL_ENSURE = 1
+        let reserved = if quick { 0 } else { 1 };
+
+        unsafe {
+            SQLStatisticsW(
+                self.as_sys(),
+                catalog.0,
+                catalog.1,
+                schema.0,
+                schema.1,
+                buf_ptr(table_name.as_slice()),
+                table_name.len().try_into().unwrap(),
+                unique,
+                reserved,
+            )
+            .into_sql_result("SQLStatisticsW")
+        }
+    }
+
+    /// Returns information about the data types supported by the data source. The driver returns
+    /// the information as a result set, ordered by `DATA_TYPE` and then by how closely the type
+    /// maps to the corresponding ODBC SQL data type.
+    ///
+    /// * `data_type`: The SQL data type to describe. [`SqlDataType::UNKNOWN_TYPE`] has the same
+    ///   value as `SQL_ALL_TYPES` and lists every type supported by the data source.
+    fn get_type_info(&mut self, data_type: SqlDataType) -> SqlResult<()> {
+        unsafe { SQLGetTypeInfo(self.as_sys(), data_type).into_sql_result("SQLGetTypeInfo") }
+    }
+
     /// To put a batch of binary data into the data source at statement execution time. Returns true
     /// if the `NEED_DATA` is returned by the driver.
     ///
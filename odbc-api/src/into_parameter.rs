@@ -1,6 +1,8 @@
 use crate::{
-    fixed_sized::Pod,
-    parameter::{InputParameter, VarBinaryBox, VarBinarySlice, VarCharBox, VarCharSlice},
+    fixed_sized::{Bit, Pod},
+    parameter::{
+        AsSqlParameter, InputParameter, VarBinaryBox, VarBinarySlice, VarCharBox, VarCharSlice,
+    },
     Nullable,
 };
 
@@ -105,15 +107,35 @@ impl<'a> IntoParameter for Option<Vec<u8>> {
     }
 }
 
+impl IntoParameter for bool {
+    type Parameter = Bit;
+
+    fn into_parameter(self) -> Self::Parameter {
+        Bit::from_bool(self)
+    }
+}
+
+impl IntoParameter for Option<bool> {
+    type Parameter = Nullable<Bit>;
+
+    fn into_parameter(self) -> Self::Parameter {
+        match self {
+            Some(boolean) => Nullable::new(Bit::from_bool(boolean)),
+            None => Nullable::null(),
+        }
+    }
+}
+
 impl<T> IntoParameter for Option<T>
 where
-    T: Pod + InputParameter,
+    T: AsSqlParameter,
+    T::Parameter: Pod + InputParameter,
 {
-    type Parameter = Nullable<T>;
+    type Parameter = Nullable<T::Parameter>;
 
     fn into_parameter(self) -> Self::Parameter {
         match self {
-            Some(value) => Nullable::new(value),
+            Some(value) => Nullable::new(*value.as_sql_parameter()),
             None => Nullable::null(),
         }
     }
@@ -5,20 +5,31 @@
 //! examples.
 
 mod borrow_mut_statement;
+mod browse_connect;
+mod cancel_handle;
+mod catalog;
 mod connection;
+mod connection_builder;
+mod connection_string;
 mod cursor;
 mod driver_complete_option;
 mod environment;
 mod error;
 mod execute;
 mod fixed_sized;
+mod from_row;
+mod guid;
 mod into_parameter;
+mod named_placeholders;
 mod nullable;
 mod parameter_collection;
+mod polling;
 mod preallocated;
 mod prebound;
 mod prepared;
 mod result_set_metadata;
+mod retry_policy;
+mod sql_script;
 mod statement_connection;
 
 pub mod buffers;
@@ -27,25 +38,43 @@ pub mod handles;
 pub mod parameter;
 
 pub use self::{
-    connection::{escape_attribute_value, Connection},
-    cursor::{Cursor, CursorImpl, CursorRow, RowSetBuffer, RowSetCursor},
+    browse_connect::{BrowseConnect, BrowseConnection},
+    cancel_handle::{CancelHandle, CancellableStatement},
+    catalog::{ColumnInfo, TableInfo},
+    connection::{escape_attribute_value, escape_search_pattern, Connection, ConnectionOptions},
+    connection_builder::ConnectionBuilder,
+    connection_string::ConnectionString,
+    cursor::{
+        BinaryColumnReader, Cursor, CursorImpl, CursorRow, RowSetBuffer, RowSetCursor,
+        ScrollPosition, RECOMMENDED_CHUNK_SIZE,
+    },
     driver_complete_option::DriverCompleteOption,
     environment::{DataSourceInfo, DriverInfo, Environment},
     error::Error,
     fixed_sized::Bit,
-    handles::{ColumnDescription, DataType, Nullability},
+    from_row::{FromColumn, FromRow},
+    guid::{Guid, ParseGuidError},
+    handles::{AccessMode, ColumnDescription, DataType, Nullability},
     into_parameter::IntoParameter,
+    named_placeholders::{expand_parameter_marker, rewrite_named_placeholders},
     nullable::Nullable,
     parameter::{InOut, Out, OutputParameter, ParameterRef},
     parameter_collection::ParameterRefCollection,
+    polling::PollingExecution,
     preallocated::Preallocated,
     prebound::Prebound,
     prepared::Prepared,
     result_set_metadata::ResultSetMetadata,
+    retry_policy::RetryPolicy,
+    sql_script::split_sql_script,
     statement_connection::StatementConnection,
 };
+#[cfg(feature = "dynamic-driver-manager")]
+pub use handles::DEFAULT_DRIVER_MANAGER;
 // Reexports
 pub use force_send_sync;
+#[cfg(feature = "derive")]
+pub use odbc_api_derive::FromRow;
 /// Reexports `odbc-sys` as sys to enable applications to always use the same version as this
 /// crate.
 pub use odbc_sys as sys;
@@ -0,0 +1,184 @@
+use std::{iter::Peekable, str::CharIndices};
+
+/// ODBC only knows positional parameter markers (`?`). This function rewrites named placeholders
+/// (`:name`) in the SQL text into positional ones, so the same value can be referenced multiple
+/// times by name.
+///
+/// Placeholder names start with an ASCII letter or underscore followed by any number of ASCII
+/// alphanumeric characters or underscores. Text within string literals (`'...'`), quoted
+/// identifiers (`"..."`), line comments (`-- ...`) and block comments (`/* ... */`) is left
+/// untouched. So are double colons (`::`), which some data sources use for type casts.
+///
+/// # Return
+///
+/// The rewritten SQL text, together with one element for each parameter marker in it, in order.
+/// The element holds the name of the placeholder the marker replaced, or `None` if the marker has
+/// already been a positional `?` in the original text.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api::rewrite_named_placeholders;
+///
+/// let (sql, names) = rewrite_named_placeholders(
+///     "SELECT * FROM t WHERE a = :x OR b = :x AND c <> ':x' AND d = ?"
+/// );
+///
+/// assert_eq!("SELECT * FROM t WHERE a = ? OR b = ? AND c <> ':x' AND d = ?", sql);
+/// assert_eq!(
+///     vec![Some("x".to_string()), Some("x".to_string()), None],
+///     names
+/// );
+/// ```
+pub fn rewrite_named_placeholders(sql: &str) -> (String, Vec<Option<String>>) {
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut names = Vec::new();
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            _ if copy_quoted_or_comment(sql, index, c, &mut chars, &mut rewritten) => (),
+            '?' => {
+                rewritten.push(c);
+                names.push(None);
+            }
+            // Double colon is not a placeholder, but a cast.
+            ':' if sql[index..].starts_with("::") => {
+                rewritten.push_str("::");
+                chars.next();
+            }
+            ':' if sql[index + 1..]
+                .starts_with(|first: char| first.is_ascii_alphabetic() || first == '_') =>
+            {
+                let start = index + 1;
+                let mut end = start;
+                while let Some((next, _)) =
+                    chars.next_if(|&(_, inner)| inner.is_ascii_alphanumeric() || inner == '_')
+                {
+                    end = next + 1;
+                }
+                rewritten.push('?');
+                names.push(Some(sql[start..end].to_string()));
+            }
+            other => rewritten.push(other),
+        }
+    }
+
+    (rewritten, names)
+}
+
+/// Expands the parameter marker (`?`) at `marker_index` into `count` comma separated markers.
+/// Useful for `IN` clauses, since ODBC does not support binding a variable number of values to a
+/// single marker. The values can then be bound individually, e.g. by passing a slice of them as
+/// parameters.
+///
+/// Like [`rewrite_named_placeholders`] this leaves string literals, quoted identifiers and comments
+/// untouched, so question marks within them are neither expanded nor counted.
+///
+/// # Parameters
+///
+/// * `sql`: SQL text containing positional parameter markers.
+/// * `marker_index`: Zero based index of the marker to expand, among all markers in `sql`.
+/// * `count`: Number of markers to replace it with.
+///
+/// # Return
+///
+/// The rewritten SQL text, or `None` if `sql` contains less than `marker_index + 1` markers.
+///
+/// # Panics
+///
+/// If `count` is `0`. An empty `IN` list is not valid SQL, so callers have to handle empty lists
+/// themselves.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api::{expand_parameter_marker, Connection, Cursor, Error};
+///
+/// let sql = expand_parameter_marker("SELECT a FROM t WHERE b <> '?' AND a IN (?)", 0, 3);
+/// assert_eq!(
+///     Some("SELECT a FROM t WHERE b <> '?' AND a IN (?, ?, ?)".to_string()),
+///     sql
+/// );
+///
+/// /// `years` must not be empty.
+/// fn count_movies(conn: &Connection<'_>, years: &[i32]) -> Result<Option<i64>, Error> {
+///     let sql = "SELECT COUNT(*) FROM Movies WHERE year IN (?)";
+///     let sql = expand_parameter_marker(sql, 0, years.len()).unwrap();
+///     let mut cursor = conn.execute(&sql, years)?.unwrap();
+///     cursor.fetch_first_scalar()
+/// }
+/// ```
+pub fn expand_parameter_marker(sql: &str, marker_index: usize, count: usize) -> Option<String> {
+    assert!(
+        count != 0,
+        "Parameter markers can not be expanded to an empty list."
+    );
+    let mut rewritten = String::with_capacity(sql.len() + 3 * count);
+    let mut current_marker = 0;
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            _ if copy_quoted_or_comment(sql, index, c, &mut chars, &mut rewritten) => (),
+            '?' => {
+                if current_marker == marker_index {
+                    rewritten.push_str(&vec!["?"; count].join(", "));
+                    rewritten.push_str(&sql[index + 1..]);
+                    return Some(rewritten);
+                }
+                rewritten.push(c);
+                current_marker += 1;
+            }
+            other => rewritten.push(other),
+        }
+    }
+
+    None
+}
+
+/// Copies the string literal, quoted identifier or comment starting with `c` at `index` verbatim
+/// to `out`, advancing `chars` past it. `false` if nothing of the sort starts at `index`, in which
+/// case neither `chars` nor `out` are changed.
+fn copy_quoted_or_comment(
+    sql: &str,
+    index: usize,
+    c: char,
+    chars: &mut Peekable<CharIndices<'_>>,
+    out: &mut String,
+) -> bool {
+    match c {
+        // Copy literals and quoted identifiers verbatim until the closing quote. An escaped
+        // quote (e.g. `''`) is handled by simply entering the literal once more.
+        '\'' | '"' => {
+            out.push(c);
+            for (_, inner) in chars.by_ref() {
+                out.push(inner);
+                if inner == c {
+                    break;
+                }
+            }
+        }
+        // Line comment. Copy verbatim until end of line.
+        '-' if sql[index..].starts_with("--") => {
+            out.push(c);
+            for (_, inner) in chars.by_ref() {
+                out.push(inner);
+                if inner == '\n' {
+                    break;
+                }
+            }
+        }
+        // Block comment. Copy verbatim until it is closed.
+        '/' if sql[index..].starts_with("/*") => {
+            let end = sql[index + 2..]
+                .find("*/")
+                .map(|offset| index + 2 + offset + 2)
+                .unwrap_or(sql.len());
+            out.push_str(&sql[index..end]);
+            while chars.next_if(|&(next, _)| next < end).is_some() {}
+        }
+        _ => return false,
+    }
+    true
+}
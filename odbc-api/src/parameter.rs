@@ -11,7 +11,9 @@
 //! * `&mut BlobParam` -> Stream long input parameters.
 //! * `Box<dyn InputParameter>` -> Aribtrary input parameter
 //! * `&[Box<dyn InputParameter>]` -> Aribtrary number of arbitrary input parameters
+//! * `Vec<Box<dyn InputParameter>>` -> Same as above, but passes ownership of the parameters
 //! * `a.into_parameter()` -> Convert idiomatic Rust type into something bindable by ODBC.
+//! * `&a` with `a: AsSqlParameter` -> Bind a domain type by delegating to an inner parameter.
 //!
 //! ## Passing a single parameter
 //!
@@ -132,13 +134,32 @@
 //! ```
 //!
 //! Should you have more type information the type available, but only at runtime can also bind an
-//! array of `[Box<dyn InputParameter]`.
+//! array of `[Box<dyn InputParameter]`. Pass a `Vec` of them, if you want to hand over ownership of
+//! the parameters assembled at runtime.
+//!
+//! ```
+//! use odbc_api::{Connection, IntoParameter, Error, parameter::InputParameter};
+//!
+//! fn insert_person(
+//!     connection: &Connection,
+//!     name: &str,
+//!     age: Option<i32>
+//! ) -> Result<(), Error> {
+//!     let params: Vec<Box<dyn InputParameter>> = vec![
+//!         Box::new(name.to_string().into_parameter()),
+//!         Box::new(age.into_parameter()),
+//!     ];
+//!     connection.execute("INSERT INTO Persons (name, age) VALUES (?, ?)", params)?;
+//!     Ok(())
+//! }
+//! ```
 //!
 //! ## Output and Input/Output parameters
 //!
-//! Mutable references are treated as input/output parameters. To use a parameter purely as an
-//! output parameter you may wrapt it into out. Consider a Mircosoft SQL Server with the following
-//! stored procedure:
+//! Wrap a mutable reference into [`InOut`] to bind it as an input/output parameter, or into
+//! [`Out`] to bind it purely as an output parameter. Plain references, mutable or not, are bound
+//! as input parameters. Since all of these implement [`ParameterRef`], they can be freely mixed
+//! within one tuple. Consider a Mircosoft SQL Server with the following stored procedure:
 //!
 //! ```mssql
 //! CREATE PROCEDURE TestParam
@@ -150,7 +171,7 @@
 //! ```
 //!
 //! We bind the return value as the first output parameter. The second parameter is an input/output
-//! bound as a mutable reference.
+//! parameter bound via `InOut`.
 //!
 //! ```no_run
 //! use odbc_api::{Environment, Out, InOut, Nullable};
@@ -313,20 +334,51 @@
 //! work? Well, in that case please open an issue or a pull request. [`crate::IntoParameter`] can usually be
 //! implemented entirely in safe code, and is a suitable spot to enable support for your custom
 //! types.
+//!
+//! ## Binding domain types
+//!
+//! Newtypes wrapping a value, which can already be bound as a parameter, can implement
+//! [`AsSqlParameter`] in safe code. References to them can then be passed like any other input
+//! parameter, also as part of a tuple. `Option`s of them are converted into nullable parameters
+//! using [`crate::IntoParameter`], if the inner parameter is a fixed size type.
+//!
+//! ```no_run
+//! use odbc_api::{parameter::AsSqlParameter, Environment, IntoParameter};
+//!
+//! struct Cents(i64);
+//!
+//! impl AsSqlParameter for Cents {
+//!     type Parameter = i64;
+//!
+//!     fn as_sql_parameter(&self) -> &i64 {
+//!         &self.0
+//!     }
+//! }
+//!
+//! let env = Environment::new()?;
+//!
+//! let mut conn = env.connect("YourDatabase", "SA", "<YourStrong@Passw0rd>")?;
+//! let price = Cents(1999);
+//! let discount = None::<Cents>.into_parameter();
+//! conn.execute("INSERT INTO Prices (price, discount) VALUES (?, ?);", (&price, &discount))?;
+//! # Ok::<(), odbc_api::Error>(())
+//! ```
 mod blob;
 mod c_string;
+mod typed_null;
 mod varbin;
 mod varchar;
 
 pub use self::{
     blob::{Blob, BlobParam, BlobRead, BlobSlice},
+    typed_null::TypedNull,
     varbin::{VarBinary, VarBinaryArray, VarBinaryBox, VarBinarySlice, VarBinarySliceMut},
     varchar::{VarChar, VarCharArray, VarCharBox, VarCharSlice, VarCharSliceMut},
 };
 
 use std::ffi::c_void;
 
-use odbc_sys::CDataType;
+use odbc_sys::{CDataType, Timestamp};
 
 use crate::{
     handles::{CData, CDataMut, HasDataType, Statement},
@@ -348,6 +400,31 @@ use crate::{
 /// the buffer and the value within).
 pub unsafe trait InputParameter: HasDataType + CData {}
 
+/// Types which are bound as input parameters by delegating to another input parameter, e.g. a
+/// newtype wrapping a primitive value. In contrast to [`InputParameter`] this trait can be
+/// implemented in safe code. A reference to any type implementing this trait is a [`ParameterRef`]
+/// and can therefore be passed to statements on its own, or as part of a tuple.
+///
+/// Every [`InputParameter`] implements this trait by referring to itself.
+pub trait AsSqlParameter {
+    /// Parameter actually bound to the statement.
+    type Parameter: InputParameter + ?Sized;
+
+    /// Reference to the parameter bound to the statement in place of `self`.
+    fn as_sql_parameter(&self) -> &Self::Parameter;
+}
+
+impl<T> AsSqlParameter for T
+where
+    T: InputParameter + ?Sized,
+{
+    type Parameter = T;
+
+    fn as_sql_parameter(&self) -> &T {
+        self
+    }
+}
+
 /// # Safety
 ///
 /// Guarantees that there is space in the output buffer for at least one element.
@@ -378,14 +455,14 @@ pub unsafe trait ParameterRef {
 /// Bind immutable references as input parameters.
 unsafe impl<T: ?Sized> ParameterRef for &T
 where
-    T: InputParameter,
+    T: AsSqlParameter,
 {
     unsafe fn bind_to(
         &mut self,
         parameter_number: u16,
         stmt: &mut impl Statement,
     ) -> Result<(), Error> {
-        stmt.bind_input_parameter(parameter_number, *self)
+        stmt.bind_input_parameter(parameter_number, self.as_sql_parameter())
             .into_result(stmt)
     }
 }
@@ -525,6 +602,34 @@ pub struct WithDataType<T> {
     pub data_type: DataType,
 }
 
+impl WithDataType<Timestamp> {
+    /// Binds a timestamp with `precision` digits of fractional seconds. By default timestamps are
+    /// bound with a precision of `7`. Some drivers reject or round values, if the precision of the
+    /// parameter does not match the one declared for the target column, e.g. `DATETIME2(3)`.
+    ///
+    /// ```
+    /// use odbc_api::{parameter::WithDataType, sys::Timestamp, DataType};
+    ///
+    /// let timestamp = Timestamp {
+    ///     year: 2021,
+    ///     month: 3,
+    ///     day: 20,
+    ///     hour: 15,
+    ///     minute: 24,
+    ///     second: 12,
+    ///     fraction: 123_000_000,
+    /// };
+    /// let param = WithDataType::timestamp(timestamp, 3);
+    /// assert_eq!(DataType::Timestamp { precision: 3 }, param.data_type);
+    /// ```
+    pub fn timestamp(value: Timestamp, precision: i16) -> Self {
+        WithDataType {
+            value,
+            data_type: DataType::Timestamp { precision },
+        }
+    }
+}
+
 unsafe impl<T> CData for WithDataType<T>
 where
     T: CData,
@@ -7,7 +7,7 @@ use crate::{
 use std::{
     ffi::c_void,
     fs::File,
-    io::{self, BufRead, BufReader},
+    io::{self, BufRead, BufReader, Read},
     path::Path,
 };
 
@@ -295,6 +295,36 @@ impl<R> BlobRead<R> {
     }
 }
 
+impl<R> BlobRead<BufReader<R>>
+where
+    R: Read,
+{
+    /// Construct a blob from any [`std::io::Read`], e.g. a socket. Data is read into an internal
+    /// buffer with a capacity of `batch_size` bytes and sent to the database one batch at a time,
+    /// until the reader reports the end of the stream. The `upper_bound` is used in the type
+    /// description then binding the blob as a parameter. Errors reading from `read` are reported
+    /// as [`crate::Error::FailedReadingInput`] during execution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::net::TcpStream;
+    /// use odbc_api::{Connection, parameter::{Blob, BlobRead}, Error};
+    ///
+    /// fn insert_upload(conn: &Connection<'_>, upload: TcpStream) -> Result<(), Error> {
+    ///     const MAX_UPLOAD_SIZE: usize = 64 * 1024 * 1024;
+    ///     let mut blob = BlobRead::from_read(upload, 64 * 1024, MAX_UPLOAD_SIZE);
+    ///
+    ///     let sql = "INSERT INTO Uploads (data) VALUES (?)";
+    ///     conn.execute(sql, &mut blob.as_blob_param())?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_read(read: R, batch_size: usize, upper_bound: usize) -> Self {
+        Self::with_upper_bound(BufReader::with_capacity(batch_size, read), upper_bound)
+    }
+}
+
 impl BlobRead<BufReader<File>> {
     /// Construct a blob from a Path. The metadata of the file is used to give the ODBC driver a
     /// size hint.
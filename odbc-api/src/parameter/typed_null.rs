@@ -0,0 +1,68 @@
+use std::{ffi::c_void, ptr::null};
+
+use odbc_sys::{CDataType, NULL_DATA};
+
+use crate::{
+    handles::{CData, HasDataType},
+    parameter::InputParameter,
+    DataType,
+};
+
+/// Binds `NULL` as an input parameter with an explicit SQL data type. Useful if the driver or a
+/// stored procedure needs to know the type of the parameter, even though its value is `NULL`, e.g.
+/// to resolve overloads. In contrast to `None::<&str>.into_parameter()`, which is always bound as
+/// `VARCHAR`, the chosen data type is reported to the driver together with its column size and
+/// decimal digits.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{Environment, parameter::TypedNull, DataType};
+///
+/// let env = Environment::new()?;
+///
+/// let mut conn = env.connect("YourDatabase", "SA", "<YourStrong@Passw0rd>")?;
+/// let born = TypedNull::new(DataType::Timestamp { precision: 3 });
+/// conn.execute("INSERT INTO Birthdays (name, born) VALUES ('Anonymous', ?);", &born)?;
+/// # Ok::<(), odbc_api::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypedNull {
+    data_type: DataType,
+}
+
+impl TypedNull {
+    /// A `NULL` which is bound as a parameter of type `data_type`.
+    pub fn new(data_type: DataType) -> Self {
+        Self { data_type }
+    }
+}
+
+unsafe impl CData for TypedNull {
+    fn cdata_type(&self) -> CDataType {
+        // No value is ever read from the value buffer, so let the driver choose the C type
+        // matching the SQL data type.
+        CDataType::Default
+    }
+
+    fn indicator_ptr(&self) -> *const isize {
+        &NULL_DATA as *const isize
+    }
+
+    fn value_ptr(&self) -> *const c_void {
+        // Allowed by the ODBC standard, since the indicator is `SQL_NULL_DATA`.
+        null()
+    }
+
+    fn buffer_length(&self) -> isize {
+        0
+    }
+}
+
+impl HasDataType for TypedNull {
+    fn data_type(&self) -> DataType {
+        self.data_type
+    }
+}
+
+unsafe impl InputParameter for TypedNull {}
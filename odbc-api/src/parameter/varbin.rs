@@ -1,5 +1,6 @@
 use std::{
     borrow::{Borrow, BorrowMut},
+    cmp::max,
     ffi::c_void,
 };
 
@@ -171,7 +172,9 @@ where
 {
     fn data_type(&self) -> DataType {
         DataType::Varbinary {
-            length: self.buffer.borrow().len(),
+            // An empty buffer must not be bound as `VARBINARY(0)`, which is rejected by some
+            // drivers. The indicator still tells the driver the value is empty rather than `NULL`.
+            length: max(1, self.buffer.borrow().len()),
         }
     }
 }
@@ -110,3 +110,19 @@ where
         Ok(())
     }
 }
+
+/// Owned version of the implementation for slices. Allows to assemble parameters at runtime and
+/// hand them to the statement in one go, e.g. a `Vec<Box<dyn InputParameter>>` holding parameters
+/// of different types.
+unsafe impl<T> ParameterRefCollection for Vec<T>
+where
+    T: InputParameter,
+{
+    fn parameter_set_size(&self) -> usize {
+        1
+    }
+
+    unsafe fn bind_parameters_to(&mut self, stmt: &mut impl Statement) -> Result<(), Error> {
+        self.as_slice().bind_parameters_to(stmt)
+    }
+}
@@ -1,5 +1,6 @@
-//! Implement `Parameters` trait for tuples consisting of elements implementing `SingleParameter`
-//! trait.
+//! Implement `ParameterRefCollection` trait for tuples consisting of elements implementing
+//! `ParameterRef`. Each element decides on its own whether it is bound as an input, output or
+//! input/output parameter, so e.g. `(&a, Out(&mut b))` binds `a` as input and `b` as output.
 
 use super::ParameterRefCollection;
 use crate::{handles::Statement, Error, ParameterRef};
@@ -0,0 +1,119 @@
+use std::task::Poll;
+
+use widestring::{U16Str, U16String};
+
+use crate::{
+    handles::{Statement, StatementImpl},
+    CursorImpl, Error, ParameterRefCollection,
+};
+
+/// A statement executed asynchronously. Created by [`crate::Connection::execute_polling`]. Call
+/// [`Self::poll`] repeatedly until it returns [`Poll::Ready`], and [`Self::cancel`] to abort the
+/// execution.
+///
+/// Support for asynchronous execution varies between drivers. Drivers which do not support it
+/// execute the statement synchronously the first time it is polled, so [`Self::poll`] returns
+/// [`Poll::Ready`] right away.
+pub struct PollingExecution<'o, P> {
+    /// `None` if there is nothing (left) to execute. Either because the parameter set is empty, or
+    /// because execution already finished and ownership has been transferred to the cursor.
+    statement: Option<StatementImpl<'o>>,
+    /// Each call to `SQLExecDirectW` must pass the same statement text, until execution finishes.
+    query: U16String,
+    /// Parameters bound to the statement. Must outlive the execution. Boxed, so the locations bound
+    /// to the statement stay valid, then `PollingExecution` is moved.
+    _params: Box<P>,
+}
+
+impl<'o, P> PollingExecution<'o, P>
+where
+    P: ParameterRefCollection,
+{
+    /// Enables asynchronous execution on `statement` and binds the parameters to it. Execution
+    /// starts with the first call to [`Self::poll`].
+    pub(crate) fn new(
+        mut statement: StatementImpl<'o>,
+        query: &U16Str,
+        params: P,
+    ) -> Result<Self, Error> {
+        let mut params = Box::new(params);
+        let parameter_set_size = params.parameter_set_size();
+        let statement = if parameter_set_size == 0 {
+            None
+        } else {
+            statement.set_async_enable(true).into_result(&statement)?;
+            statement.reset_parameters().into_result(&statement)?;
+            unsafe {
+                statement
+                    .set_paramset_size(parameter_set_size)
+                    .into_result(&statement)?;
+                // Bound parameters stay valid, since we keep `params` alive together with the
+                // statement, and its heap allocation is not moved if `self` is.
+                params.bind_parameters_to(&mut statement)?;
+            }
+            Some(statement)
+        };
+        Ok(Self {
+            statement,
+            query: query.to_owned(),
+            _params: params,
+        })
+    }
+
+    /// Drives the execution of the statement.
+    ///
+    /// # Return
+    ///
+    /// [`Poll::Pending`] if the statement is still executing. [`Poll::Ready`] once the execution
+    /// finished, holding a cursor if the statement created a result set. Once execution has
+    /// finished, further calls return `Ready(None)`.
+    ///
+    /// Delayed parameters streamed to the data source at execution time (e.g.
+    /// [`crate::parameter::BlobParam`]) are not supported in asynchronous execution. If the driver
+    /// requests their values, [`Error::DelayedParametersInAsyncExecution`] is returned.
+    pub fn poll(&mut self) -> Result<Poll<Option<CursorImpl<StatementImpl<'o>>>>, Error> {
+        let stmt = match self.statement.as_mut() {
+            Some(stmt) => stmt,
+            None => return Ok(Poll::Ready(None)),
+        };
+        // Safe: parameters are bound to members of `self` which are still valid.
+        let need_data = match unsafe { stmt.exec_direct_async(&self.query) } {
+            None => return Ok(Poll::Pending),
+            Some(result) => result.into_result(stmt),
+        };
+        // Execution finished. Make sure we do not call into the statement again, should we be
+        // polled once more.
+        let mut statement = self.statement.take().unwrap();
+        if need_data? {
+            return Err(Error::DelayedParametersInAsyncExecution);
+        }
+
+        // The cursor is processed synchronously. Otherwise fetching would report the statement as
+        // still executing as well.
+        statement.set_async_enable(false).into_result(&statement)?;
+
+        // Check if a result set has been created.
+        if statement.num_result_cols().into_result(&statement)? == 0 {
+            Ok(Poll::Ready(None))
+        } else {
+            // Safe: `statement` is in cursor state.
+            let cursor = unsafe { CursorImpl::new(statement) };
+            Ok(Poll::Ready(Some(cursor)))
+        }
+    }
+
+    /// Cancels the execution of the statement. The next call to [`Self::poll`] is going to return
+    /// an error indicating that the operation has been canceled. Does nothing if execution already
+    /// finished.
+    ///
+    /// Since the statement is executed asynchronously, the thread polling it is free to cancel it in
+    /// between two calls to [`Self::poll`]. To cancel an execution blocking another thread, use
+    /// [`crate::Preallocated::split_cancel_handle`] instead.
+    pub fn cancel(&self) -> Result<(), Error> {
+        if let Some(stmt) = &self.statement {
+            stmt.cancel().into_result(stmt)
+        } else {
+            Ok(())
+        }
+    }
+}
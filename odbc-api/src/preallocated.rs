@@ -1,9 +1,18 @@
+use std::borrow::Cow;
+
+use odbc_sys::SqlDataType;
 use widestring::{U16Str, U16String};
 
 use crate::{
-    execute::{execute_columns, execute_tables, execute_with_parameters},
-    handles::StatementImpl,
-    CursorImpl, Error, ParameterRefCollection,
+    catalog::{column_infos, table_infos},
+    error::with_query_text,
+    execute::{
+        execute_columns, execute_statistics, execute_tables, execute_type_info,
+        execute_with_parameters, SqlText,
+    },
+    handles::{Concurrency, CursorType, Statement, StatementImpl},
+    CancelHandle, CancellableStatement, ColumnInfo, CursorImpl, Error, ParameterRefCollection,
+    TableInfo,
 };
 
 /// A preallocated SQL statement handle intended for sequential execution of different queries. See
@@ -34,11 +43,26 @@ use crate::{
 /// ```
 pub struct Preallocated<'open_connection> {
     statement: StatementImpl<'open_connection>,
+    /// Inherited from the connection. See [`crate::Connection::set_narrow_query_text`].
+    narrow_query_text: bool,
+    /// Inherited from the connection. See [`crate::Connection::set_query_text_in_errors`].
+    query_text_in_errors: bool,
+    /// Client side row limit for cursors. See [`Self::set_max_rows`].
+    max_rows: usize,
 }
 
 impl<'o> Preallocated<'o> {
-    pub(crate) fn new(statement: StatementImpl<'o>) -> Self {
-        Self { statement }
+    pub(crate) fn new(
+        statement: StatementImpl<'o>,
+        narrow_query_text: bool,
+        query_text_in_errors: bool,
+    ) -> Self {
+        Self {
+            statement,
+            narrow_query_text,
+            query_text_in_errors,
+            max_rows: 0,
+        }
     }
 
     /// Executes an sql statement using a wide string. See [`Self::execute`].
@@ -47,7 +71,15 @@ impl<'o> Preallocated<'o> {
         query: &U16Str,
         params: impl ParameterRefCollection,
     ) -> Result<Option<CursorImpl<&mut StatementImpl<'o>>>, Error> {
-        execute_with_parameters(move || Ok(&mut self.statement), Some(query), params)
+        let query_text = self.query_text_in_errors.then(|| query.to_string_lossy());
+        let max_rows = self.max_rows;
+        let result = execute_with_parameters(
+            move || Ok(&mut self.statement),
+            Some(SqlText::Wide(Cow::Borrowed(query))),
+            params,
+        );
+        let maybe_cursor = with_query_text(result, query_text.as_deref())?;
+        Ok(maybe_cursor.map(|cursor| cursor.with_max_rows(max_rows)))
     }
 
     /// Executes a statement. This is the fastest way to sequentially execute different SQL
@@ -95,8 +127,176 @@ impl<'o> Preallocated<'o> {
         query: &str,
         params: impl ParameterRefCollection,
     ) -> Result<Option<CursorImpl<&mut StatementImpl<'o>>>, Error> {
-        let query = U16String::from_str(query);
-        self.execute_utf16(&query, params)
+        let query_text = self.query_text_in_errors.then_some(query);
+        let max_rows = self.max_rows;
+        let sql = SqlText::new(query, self.narrow_query_text);
+        let result = execute_with_parameters(move || Ok(&mut self.statement), Some(sql), params);
+        let maybe_cursor = with_query_text(result, query_text)?;
+        Ok(maybe_cursor.map(|cursor| cursor.with_max_rows(max_rows)))
+    }
+
+    /// Executes a statement and hands the cursor (if any) to `f`. Afterwards the statement handle
+    /// is reset (see [`Self::reset`]), so it is immediately ready to execute the next, unrelated
+    /// query. This allows high frequency workloads to execute many ad-hoc queries, without
+    /// allocating a new statement handle for each one of them.
+    ///
+    /// The cursor is closed once `f` returns, even if it has not been consumed completely.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, Error};
+    ///
+    /// fn titles(conn: &Connection<'_>, ids: &[i32]) -> Result<Vec<String>, Error> {
+    ///     let mut statement = conn.preallocate()?;
+    ///     let mut titles = Vec::new();
+    ///     for &id in ids {
+    ///         let query = "SELECT title FROM Movies WHERE id = ?";
+    ///         statement.execute_and_reset(query, &id, |cursor| {
+    ///             if let Some(mut row) = cursor.unwrap().next_row()? {
+    ///                 let mut title = Vec::new();
+    ///                 row.get_text(1, &mut title)?;
+    ///                 titles.push(String::from_utf8(title).unwrap());
+    ///             }
+    ///             Ok(())
+    ///         })?;
+    ///     }
+    ///     Ok(titles)
+    /// }
+    /// ```
+    pub fn execute_and_reset<T>(
+        &mut self,
+        query: &str,
+        params: impl ParameterRefCollection,
+        f: impl FnOnce(Option<CursorImpl<&mut StatementImpl<'o>>>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let result = f(self.execute(query, params)?);
+        // Reset even if `f` failed, so the handle is in a usable state regardless.
+        self.reset()?;
+        result
+    }
+
+    /// Splits the statement into a [`CancelHandle`], which can be sent to another thread, and a
+    /// [`CancellableStatement`] to execute queries with. This allows e.g. a UI thread to cancel a
+    /// long running query, while another thread is blocked executing it.
+    ///
+    /// Both borrow the statement, so neither of them can outlive it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    /// use std::{thread, time::Duration};
+    ///
+    /// fn execute_with_timeout(conn: &Connection<'_>, query: &str) -> Result<(), Error> {
+    ///     let mut statement = conn.preallocate()?;
+    ///     let (cancel_handle, mut statement) = statement.split_cancel_handle();
+    ///     thread::scope(|s| {
+    ///         s.spawn(|| {
+    ///             thread::sleep(Duration::from_secs(5));
+    ///             // Has no effect, if the query already finished.
+    ///             cancel_handle.cancel()
+    ///         });
+    ///         statement.execute(query, ()).map(|_| ())
+    ///     })
+    /// }
+    /// ```
+    pub fn split_cancel_handle(&mut self) -> (CancelHandle<'_>, CancellableStatement<'_, 'o>) {
+        // Safe: The cancel handle borrows `self` for as long as the statement handle is valid.
+        let cancel_handle = unsafe { CancelHandle::new(&self.statement) };
+        (cancel_handle, CancellableStatement::new(self))
+    }
+
+    /// Resets the statement handle, so no state of a previous execution carries over to the next
+    /// one. Unbinds any parameters and column buffers still bound to the statement handle. Open
+    /// cursors do not need to be taken care of, since they are closed as soon as they are dropped,
+    /// which the borrow checker ensures happened before this method can be called.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        self.statement
+            .reset_parameters()
+            .into_result(&self.statement)?;
+        self.statement.unbind_cols().into_result(&self.statement)
+    }
+
+    /// Requests scrollable cursors for statements executed from now on. Scrollable cursors allow
+    /// for fetching rows in any order using [`crate::Cursor::scroll_to`], e.g. to implement
+    /// pagination without executing the query again. Must be called before the statement is
+    /// executed. Not all drivers support scrollable cursors.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, Error, ScrollPosition};
+    ///
+    /// fn last_title(conn: &Connection<'_>) -> Result<String, Error> {
+    ///     let mut statement = conn.preallocate()?;
+    ///     statement.set_scrollable(true)?;
+    ///     let mut title = Vec::new();
+    ///     if let Some(mut cursor) = statement.execute("SELECT title FROM Movies", ())? {
+    ///         if let Some(mut row) = cursor.scroll_to(ScrollPosition::Last)? {
+    ///             row.get_text(1, &mut title)?;
+    ///         }
+    ///     }
+    ///     Ok(String::from_utf8(title).unwrap())
+    /// }
+    /// ```
+    pub fn set_scrollable(&mut self, scrollable: bool) -> Result<(), Error> {
+        self.statement
+            .set_cursor_scrollable(scrollable)
+            .into_result(&self.statement)
+    }
+
+    /// Limits the number of rows in result sets of statements executed from now on to `max_rows`.
+    /// `0` means unlimited, which is also the default. This allows to fetch a preview of the result
+    /// of arbitrary queries, without adding a non portable `TOP` or `LIMIT` clause to them.
+    ///
+    /// Drivers which do not support `SQL_ATTR_MAX_ROWS` may ignore it and return all rows
+    /// nonetheless. Cursors returned by [`Self::execute`] stop fetching after `max_rows` anyway, be
+    /// it row by row or in row sets. The last row set is truncated if it would exceed the limit.
+    pub fn set_max_rows(&mut self, max_rows: usize) -> Result<(), Error> {
+        self.statement
+            .set_max_rows(max_rows)
+            .into_result(&self.statement)?;
+        self.max_rows = max_rows;
+        Ok(())
+    }
+
+    /// Caps the number of bytes the driver returns for a single value of a character or binary
+    /// column (`SQL_ATTR_MAX_LENGTH`) to `max_length`, for statements executed from now on. `0`
+    /// means unlimited, which is also the default. Protects against rows holding unexpectedly
+    /// large values, e.g. a blob several gigabytes in size.
+    ///
+    /// This is only a hint to the driver. Drivers which do not support it may ignore it and return
+    /// the entire value nonetheless. Truncated values are not reported as truncated. Yet their
+    /// indicator reports a length equal to `max_length`, so compare against it, if you need to know
+    /// whether a value might have been cut short.
+    pub fn set_max_length(&mut self, max_length: usize) -> Result<(), Error> {
+        self.statement
+            .set_max_length(max_length)
+            .into_result(&self.statement)
+    }
+
+    /// Number of seconds to wait for statements executed from now on to finish, before returning an
+    /// error with SQLSTATE `HYT00` (see [`Error::is_timeout`]). `0` means no timeout, which is also
+    /// the default. Drivers which do not support timeouts may ignore it.
+    pub fn set_query_timeout_sec(&mut self, timeout_sec: usize) -> Result<(), Error> {
+        self.statement
+            .set_query_timeout_sec(timeout_sec)
+            .into_result(&self.statement)
+    }
+
+    /// Sets the cursor type for statements executed from now on. Must be called before the
+    /// statement is executed. See [`crate::handles::Statement::set_cursor_type`].
+    pub fn set_cursor_type(&mut self, cursor_type: CursorType) -> Result<(), Error> {
+        self.statement
+            .set_cursor_type(cursor_type)
+            .into_result(&self.statement)
+    }
+
+    /// Sets the concurrency control for statements executed from now on. Must be called before the
+    /// statement is executed. See [`crate::handles::Statement::set_concurrency`].
+    pub fn set_concurrency(&mut self, concurrency: Concurrency) -> Result<(), Error> {
+        self.statement
+            .set_concurrency(concurrency)
+            .into_result(&self.statement)
     }
 
     /// Transfer ownership to the underlying statement handle.
@@ -124,6 +324,10 @@ impl<'o> Preallocated<'o> {
     /// * `table_type`: Filters results by table type. E.g: 'TABLE', 'VIEW'. This argument accepts a
     ///   comma separeted list of table types. Omit it to not filter the result by table type at
     ///   all.
+    ///
+    /// In contrast to [`crate::Connection::tables`] no statement handle is allocated. The
+    /// preallocated handle is reused for each call, which pays off for tools issuing many catalog
+    /// queries, e.g. to crawl the schema of a data source.
     pub fn tables(
         &mut self,
         catalog_name: Option<&str>,
@@ -149,6 +353,26 @@ impl<'o> Preallocated<'o> {
     /// `ORDINAL_POSITION`, `IS_NULLABLE`.
     ///
     /// In addition to that there may be a number of columns specific to the data source.
+    ///
+    /// In contrast to [`crate::Connection::columns`] no statement handle is allocated. The
+    /// preallocated handle is reused for each call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// /// Prints the column names of each table, reusing one statement handle.
+    /// fn print_columns(conn: &Connection<'_>, tables: &[&str]) -> Result<(), Error> {
+    ///     let mut statement = conn.preallocate()?;
+    ///     for &table in tables {
+    ///         for column in statement.list_columns("", "", table, "%")? {
+    ///             println!("{}.{}", table, column.column_name);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn columns(
         &mut self,
         catalog_name: &str,
@@ -164,4 +388,83 @@ impl<'o> Preallocated<'o> {
             &U16String::from_str(column_name),
         )
     }
+
+    /// Like [`Self::tables`], but fetches the entire result set and returns one [`TableInfo`] for
+    /// each row. See [`crate::Connection::list_tables`].
+    pub fn list_tables(
+        &mut self,
+        catalog_name: Option<&str>,
+        schema_name: Option<&str>,
+        table_name: Option<&str>,
+        table_type: Option<&str>,
+    ) -> Result<Vec<TableInfo>, Error> {
+        let cursor = self.tables(catalog_name, schema_name, table_name, table_type)?;
+        table_infos(cursor)
+    }
+
+    /// Like [`Self::columns`], but fetches the entire result set and returns one [`ColumnInfo`]
+    /// for each row. See [`crate::Connection::list_columns`].
+    pub fn list_columns(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<Vec<ColumnInfo>, Error> {
+        let cursor = self.columns(catalog_name, schema_name, table_name, column_name)?;
+        column_infos(cursor)
+    }
+
+    /// A cursor describing the indexes of a table, together with statistics about the table. The
+    /// returned cursor has the columns:
+    /// `TABLE_CAT`, `TABLE_SCHEM`, `TABLE_NAME`, `NON_UNIQUE`, `INDEX_QUALIFIER`, `INDEX_NAME`,
+    /// `TYPE`, `ORDINAL_POSITION`, `COLUMN_NAME`, `ASC_OR_DESC`, `CARDINALITY`, `PAGES`,
+    /// `FILTER_CONDITION`.
+    ///
+    /// There is one row for each column of each index. In addition to that there may be a row with
+    /// `TYPE` equal to `SQL_TABLE_STAT` (`0`) holding the cardinality and number of pages of the
+    /// table itself. For this row `INDEX_NAME`, `COLUMN_NAME` and `ORDINAL_POSITION` are `NULL`.
+    ///
+    /// # Parameters
+    ///
+    /// * `catalog_name`: Catalog of the table. Not a search pattern. `None` if not applicable to
+    ///   the data source.
+    /// * `schema_name`: Schema of the table. Not a search pattern. `None` if not applicable to the
+    ///   data source.
+    /// * `table_name`: Name of the table. Not a search pattern.
+    /// * `unique_only`: If `true` only unique indexes are returned, otherwise all indexes.
+    /// * `quick`: If `true` `CARDINALITY` and `PAGES` are only returned if they are readily
+    ///   available, otherwise the driver is requested to retrieve them unconditionally.
+    pub fn statistics(
+        &mut self,
+        catalog_name: Option<&str>,
+        schema_name: Option<&str>,
+        table_name: &str,
+        unique_only: bool,
+        quick: bool,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_statistics(
+            &mut self.statement,
+            catalog_name.map(U16String::from_str).as_deref(),
+            schema_name.map(U16String::from_str).as_deref(),
+            &U16String::from_str(table_name),
+            unique_only,
+            quick,
+        )
+    }
+
+    /// A cursor describing the data types supported by the data source. See
+    /// [`crate::Connection::type_info`] for the columns of the result set.
+    ///
+    /// # Parameters
+    ///
+    /// * `data_type`: SQL data type to describe. Pass [`crate::sys::SqlDataType::UNKNOWN_TYPE`],
+    ///   which has the same value as `SQL_ALL_TYPES`, in order to list every type supported by the
+    ///   data source.
+    pub fn type_info(
+        &mut self,
+        data_type: SqlDataType,
+    ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        execute_type_info(&mut self.statement, data_type)
+    }
 }
@@ -1,3 +1,5 @@
+use std::{ffi::c_void, mem};
+
 use crate::{
     execute::execute,
     handles::{Statement, StatementImpl},
@@ -11,9 +13,23 @@ use crate::{
 /// prepared query with different parameters. Using this instead of [`crate::Prepared::execute`]
 /// directly results in the parameter buffers only to be bound once and modified between calls,
 /// instead of new buffers bound.
+///
+/// Columnar buffers (`&mut ColumnarBuffer<_>`) can be bound as well, to insert many batches of
+/// rows with one prepared statement. Fill the buffer for each batch using [`Self::params_mut`]. The
+/// number of rows is updated before each execution. The buffer must outlive all executions, which
+/// is enforced by borrowing it for the lifetime of the `Prebound` instance.
 pub struct Prebound<'open_connection, Parameters> {
     statement: StatementImpl<'open_connection>,
     parameters: Parameters,
+    /// Parameter set size the statement has been configured with last.
+    paramset_size: usize,
+    /// Locations of the parameter buffers the time they have been bound.
+    locations: Vec<BoundLocation>,
+    /// Reused to query the current locations of the parameter buffers before each execution,
+    /// without allocating.
+    current_locations: Vec<BoundLocation>,
+    /// Client side row limit for cursors. See [`crate::Prepared::set_max_rows`].
+    max_rows: usize,
 }
 
 impl<'o, P> Prebound<'o, P>
@@ -29,17 +45,56 @@ where
     pub unsafe fn new(mut statement: StatementImpl<'o>, mut parameters: P) -> Result<Self, Error> {
         statement.reset_parameters().into_result(&statement)?;
         let paramset_size = parameters.parameter_set_size();
-        statement.set_paramset_size(paramset_size);
+        statement
+            .set_paramset_size(paramset_size)
+            .into_result(&statement)?;
         parameters.bind_parameters_to(&mut statement)?;
+        let mut locations = Vec::new();
+        parameters.bound_locations(&mut locations);
         Ok(Self {
             statement,
             parameters,
+            paramset_size,
+            current_locations: Vec::with_capacity(locations.len()),
+            locations,
+            max_rows: 0,
         })
     }
 
-    /// Execute the prepared statement
+    /// Inherits the row limit of the prepared statement the parameters have been bound to.
+    pub(crate) fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Execute the prepared statement. Only calls into ODBC to change the parameter set size, if
+    /// the number of rows in the parameter buffers changed since the last execution. Parameters are
+    /// only bound anew, if any of the buffers has been reallocated, e.g. to hold longer text.
+    ///
+    /// Nothing is executed if the parameter set size is `0`, i.e. a columnar buffer holds no rows.
     pub fn execute(&mut self) -> Result<Option<CursorImpl<&mut StatementImpl<'o>>>, Error> {
-        unsafe { execute(&mut self.statement, None) }
+        let paramset_size = self.parameters.parameter_set_size();
+        if paramset_size == 0 {
+            return Ok(None);
+        }
+        unsafe {
+            if paramset_size != self.paramset_size {
+                self.statement
+                    .set_paramset_size(paramset_size)
+                    .into_result(&self.statement)?;
+                self.paramset_size = paramset_size;
+            }
+            self.parameters.bound_locations(&mut self.current_locations);
+            if self.current_locations != self.locations {
+                self.statement
+                    .reset_parameters()
+                    .into_result(&self.statement)?;
+                self.parameters.bind_parameters_to(&mut self.statement)?;
+                mem::swap(&mut self.locations, &mut self.current_locations);
+            }
+            let maybe_cursor = execute(&mut self.statement, None)?;
+            Ok(maybe_cursor.map(|cursor| cursor.with_max_rows(self.max_rows)))
+        }
     }
 
     /// Provides write access to the bound parameters. Used to change arguments betwenn statement
@@ -49,10 +104,13 @@ where
     }
 }
 
+/// Value pointer, indicator pointer and buffer length of a bound parameter buffer.
+pub type BoundLocation = (*const c_void, *const isize, isize);
+
 /// # Safety
 ///
 /// The changes made through the reference returned by `as_mut` may not invalidate the parameter
-/// pointers bound to a statement.
+/// pointers bound to a statement, unless they are reflected by `bound_locations`.
 pub unsafe trait ParameterMutCollection: ParameterRefCollection {
     /// Mutable projection used to change parameter values in between statement executions.
     type Mut;
@@ -60,6 +118,14 @@ pub unsafe trait ParameterMutCollection: ParameterRefCollection {
     /// Acquire a mutable projection of the parameters to change values between executions of the
     /// statement.
     fn as_mut(&mut self) -> &mut Self::Mut;
+
+    /// Replaces the contents of `locations` with the locations of the buffers bound as
+    /// parameters. If they change between executions, the parameters are bound anew. Parameters
+    /// which can not be moved in memory through `as_mut` report no locations at all, which is the
+    /// default.
+    fn bound_locations(&self, locations: &mut Vec<BoundLocation>) {
+        locations.clear();
+    }
 }
 
 unsafe impl<T> ParameterMutCollection for &mut T
@@ -1,4 +1,6 @@
 use crate::{
+    borrow_mut_statement::BorrowMutStatement,
+    error::with_query_text,
     execute::execute_with_parameters,
     handles::{ParameterDescription, Statement, StatementImpl},
     prebound::ParameterMutCollection,
@@ -7,13 +9,28 @@ use crate::{
 
 /// A prepared query. Prepared queries are useful if the similar queries should executed more than
 /// once.
+///
+/// The schema of the result set can be inspected before executing the query, using the methods of
+/// [`ResultSetMetadata`], e.g. [`ResultSetMetadata::num_result_cols`] or
+/// [`ResultSetMetadata::describe_all`]. Not all drivers support this though. Some defer
+/// populating the result set metadata until the statement is executed and report `0` columns
+/// until then. In that case execute the query and inspect the cursor instead.
 pub struct Prepared<'open_connection> {
     statement: StatementImpl<'open_connection>,
+    /// Text of the statement, attached to errors executing it. Only `Some` if enabled with
+    /// [`crate::Connection::set_query_text_in_errors`].
+    query_text: Option<String>,
+    /// Client side row limit for cursors. See [`Self::set_max_rows`].
+    max_rows: usize,
 }
 
 impl<'o> Prepared<'o> {
-    pub(crate) fn new(statement: StatementImpl<'o>) -> Self {
-        Self { statement }
+    pub(crate) fn new(statement: StatementImpl<'o>, query_text: Option<String>) -> Self {
+        Self {
+            statement,
+            query_text,
+            max_rows: 0,
+        }
     }
 
     /// Transfer ownership to the underlying statement handle.
@@ -28,6 +45,44 @@ impl<'o> Prepared<'o> {
         self.statement
     }
 
+    /// Limits the number of rows in result sets of subsequent executions of the prepared statement
+    /// to `max_rows`. `0` means unlimited, which is also the default.
+    ///
+    /// Drivers which do not support `SQL_ATTR_MAX_ROWS` may ignore it and return all rows
+    /// nonetheless. Cursors created by executing the prepared statement stop fetching after
+    /// `max_rows` anyway, be it row by row or in row sets. The last row set is truncated if it
+    /// would exceed the limit.
+    pub fn set_max_rows(&mut self, max_rows: usize) -> Result<(), Error> {
+        self.statement
+            .set_max_rows(max_rows)
+            .into_result(&self.statement)?;
+        self.max_rows = max_rows;
+        Ok(())
+    }
+
+    /// Caps the number of bytes the driver returns for a single value of a character or binary
+    /// column (`SQL_ATTR_MAX_LENGTH`) to `max_length`, for subsequent executions of the prepared
+    /// statement. `0` means unlimited, which is also the default.
+    ///
+    /// This is only a hint to the driver. Drivers which do not support it may ignore it and return
+    /// the entire value nonetheless. Truncated values are not reported as truncated. Yet their
+    /// indicator reports a length equal to `max_length`, so compare against it, if you need to know
+    /// whether a value might have been cut short.
+    pub fn set_max_length(&mut self, max_length: usize) -> Result<(), Error> {
+        self.statement
+            .set_max_length(max_length)
+            .into_result(&self.statement)
+    }
+
+    /// Number of seconds to wait for subsequent executions of the prepared statement to finish,
+    /// before returning an error with SQLSTATE `HYT00` (see [`Error::is_timeout`]). `0` means no
+    /// timeout, which is also the default. Drivers which do not support timeouts may ignore it.
+    pub fn set_query_timeout_sec(&mut self, timeout_sec: usize) -> Result<(), Error> {
+        self.statement
+            .set_query_timeout_sec(timeout_sec)
+            .into_result(&self.statement)
+    }
+
     /// Execute the prepared statement.
     ///
     /// * `params`: Used to bind these parameters before executing the statement. You can use `()`
@@ -39,7 +94,69 @@ impl<'o> Prepared<'o> {
         &mut self,
         params: impl ParameterRefCollection,
     ) -> Result<Option<CursorImpl<&mut StatementImpl<'o>>>, Error> {
-        execute_with_parameters(move || Ok(&mut self.statement), None, params)
+        let statement = &mut self.statement;
+        let result = execute_with_parameters(move || Ok(statement), None, params);
+        let maybe_cursor = with_query_text(result, self.query_text.as_deref())?;
+        Ok(maybe_cursor.map(|cursor| cursor.with_max_rows(self.max_rows)))
+    }
+
+    /// Number of rows affected by the last execution of the statement, e.g. the number of rows
+    /// inserted by an `INSERT` statement. `None` if the driver can not report it.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error, IntoParameter};
+    ///
+    /// fn delete_movie(conn: &Connection<'_>, title: &str) -> Result<bool, Error> {
+    ///     let mut prepared = conn.prepare("DELETE FROM Movies WHERE title=?;")?;
+    ///     prepared.execute(&title.into_parameter())?;
+    ///     Ok(prepared.row_count()?.unwrap_or(0) > 0)
+    /// }
+    /// ```
+    pub fn row_count(&mut self) -> Result<Option<usize>, Error> {
+        let row_count = self.statement.row_count().into_result(&self.statement)?;
+        // Negative values (usually `-1`) indicate that the row count is not available.
+        Ok(row_count.try_into().ok())
+    }
+
+    /// Execute the prepared statement, transferring ownership of it to the returned cursor. In
+    /// contrast to [`Self::execute`] the cursor does not borrow the prepared statement, so it can
+    /// be returned from functions or stored alongside other state. Use
+    /// [`crate::CursorImpl::close_and_reuse`] to get the prepared statement back, once you are
+    /// done with the result set, in order to execute it again with different parameters.
+    ///
+    /// * `params`: Used to bind these parameters before executing the statement. You can use `()`
+    ///   to represent no parameters. Should `params` specify a parameter set size of `0`, nothing
+    ///   is executed, and `Ok(None)` is returned. The same happens if the statement does not
+    ///   produce a result set. In both cases the prepared statement is dropped.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, Error};
+    ///
+    /// fn titles_by_year(conn: &Connection<'_>, years: &[i32]) -> Result<(), Error> {
+    ///     let mut prepared = conn.prepare("SELECT title FROM Movies WHERE year=?;")?;
+    ///     for year in years {
+    ///         if let Some(mut cursor) = prepared.into_cursor(year)? {
+    ///             while let Some(_row) = cursor.next_row()? {
+    ///                 // ... process row ...
+    ///             }
+    ///             // Close the cursor and get the prepared statement back for the next year.
+    ///             prepared = cursor.close_and_reuse()?;
+    ///         } else {
+    ///             break;
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn into_cursor(
+        self,
+        params: impl ParameterRefCollection,
+    ) -> Result<Option<CursorImpl<Self>>, Error> {
+        let query_text = self.query_text.clone();
+        let max_rows = self.max_rows;
+        let result = execute_with_parameters(move || Ok(self), None, params);
+        let maybe_cursor = with_query_text(result, query_text.as_deref())?;
+        Ok(maybe_cursor.map(|cursor| cursor.with_max_rows(max_rows)))
     }
 
     /// Describes parameter marker associated with a prepared SQL statement.
@@ -63,6 +180,9 @@ impl<'o> Prepared<'o> {
     /// the requirements for these permantent buffers are higher, as they may not become invalid
     /// after the statment is executed, and if the [`Prebound`] instance is moved.
     ///
+    /// A mutable reference to a [`crate::buffers::ColumnarBuffer`] can be bound, too, in order to
+    /// insert many batches of rows, refilling the same buffer for each batch. See [`Prebound`].
+    ///
     /// ```
     /// use odbc_api::{Connection, Error, Prebound};
     /// use std::io::{self, stdin, Read};
@@ -97,8 +217,22 @@ impl<'o> Prepared<'o> {
     where
         P: ParameterMutCollection,
     {
+        let max_rows = self.max_rows;
         // We know that statement is a prepared statement.
-        unsafe { Prebound::new(self.into_statement(), parameters) }
+        let prebound = unsafe { Prebound::new(self.into_statement(), parameters) }?;
+        Ok(prebound.with_max_rows(max_rows))
+    }
+}
+
+impl<'o> BorrowMutStatement for Prepared<'o> {
+    type Statement = StatementImpl<'o>;
+
+    fn borrow(&self) -> &Self::Statement {
+        &self.statement
+    }
+
+    fn borrow_mut(&mut self) -> &mut Self::Statement {
+        &mut self.statement
     }
 }
 
@@ -1,6 +1,6 @@
 use std::char::REPLACEMENT_CHARACTER;
 
-use odbc_sys::SqlDataType;
+use odbc_sys::{Desc, SqlDataType};
 use widestring::decode_utf16;
 
 use crate::{handles::Statement, ColumnDescription, DataType, Error};
@@ -48,6 +48,23 @@ pub trait ResultSetMetadata {
             .into_result(stmt)
     }
 
+    /// Descriptions of all columns in the result set, in order. Use this if you want to allocate a
+    /// [`ColumnDescription`] for each column.
+    ///
+    /// This is a wrapper around [`Self::describe_col`] introduced for convenience.
+    fn describe_all(&self) -> Result<Vec<ColumnDescription>, Error> {
+        let num_cols: u16 = self.num_result_cols()?.try_into().unwrap();
+        (1..=num_cols)
+            .map(|column_number| {
+                // A fresh description for each column, so names of previous columns do not
+                // bleed into the next one.
+                let mut column_description = ColumnDescription::default();
+                self.describe_col(column_number, &mut column_description)?;
+                Ok(column_description)
+            })
+            .collect()
+    }
+
     /// Number of columns in result set. Can also be used to see wether execting a prepared
     /// Statement ([`crate::Prepared`]) would yield a result set, as this would return `0` if it
     /// does not.
@@ -106,6 +123,60 @@ pub trait ResultSetMetadata {
         stmt.col_name(column_number, buf).into_result(stmt)
     }
 
+    /// The column label or title, e.g. the alias in `SELECT a AS b`. If the column is unnamed, an
+    /// empty string is returned.
+    fn col_label(&self, column_number: u16, buf: &mut Vec<u16>) -> Result<(), Error> {
+        let stmt = self.stmt_ref();
+        unsafe { stmt.string_col_attribute(Desc::Label, column_number, buf) }.into_result(stmt)
+    }
+
+    /// The name of the column in the base table the result set column originates from. Empty if it
+    /// can not be determined, e.g. for expressions.
+    fn col_base_column_name(&self, column_number: u16, buf: &mut Vec<u16>) -> Result<(), Error> {
+        let stmt = self.stmt_ref();
+        unsafe { stmt.string_col_attribute(Desc::BaseColumnName, column_number, buf) }
+            .into_result(stmt)
+    }
+
+    /// The name of the base table containing the column. Empty if it can not be determined, e.g.
+    /// for expressions or columns originating from a join, depending on the driver.
+    fn col_base_table_name(&self, column_number: u16, buf: &mut Vec<u16>) -> Result<(), Error> {
+        let stmt = self.stmt_ref();
+        unsafe { stmt.string_col_attribute(Desc::BaseTableName, column_number, buf) }
+            .into_result(stmt)
+    }
+
+    /// The catalog of the table containing the column. Empty if the data source does not support
+    /// catalogs or the catalog can not be determined.
+    fn col_catalog_name(&self, column_number: u16, buf: &mut Vec<u16>) -> Result<(), Error> {
+        let stmt = self.stmt_ref();
+        unsafe { stmt.string_col_attribute(Desc::CatalogName, column_number, buf) }
+            .into_result(stmt)
+    }
+
+    /// The schema of the table containing the column. Empty if the data source does not support
+    /// schemas or the schema can not be determined.
+    fn col_schema_name(&self, column_number: u16, buf: &mut Vec<u16>) -> Result<(), Error> {
+        let stmt = self.stmt_ref();
+        unsafe { stmt.string_col_attribute(Desc::SchemaName, column_number, buf) }.into_result(stmt)
+    }
+
+    /// Character or characters the driver recognizes as prefix for a literal of the data type of
+    /// this column, e.g. `'` for character types. Empty if not applicable.
+    fn col_literal_prefix(&self, column_number: u16, buf: &mut Vec<u16>) -> Result<(), Error> {
+        let stmt = self.stmt_ref();
+        unsafe { stmt.string_col_attribute(Desc::LiteralPrefix, column_number, buf) }
+            .into_result(stmt)
+    }
+
+    /// Character or characters the driver recognizes as suffix for a literal of the data type of
+    /// this column. Empty if not applicable.
+    fn col_literal_suffix(&self, column_number: u16, buf: &mut Vec<u16>) -> Result<(), Error> {
+        let stmt = self.stmt_ref();
+        unsafe { stmt.string_col_attribute(Desc::LiteralSuffix, column_number, buf) }
+            .into_result(stmt)
+    }
+
     /// Use this if you want to iterate over all column names and allocate a `String` for each one.
     ///
     /// This is a wrapper around `col_name` introduced for convenience.
@@ -113,75 +184,158 @@ pub trait ResultSetMetadata {
         ColumnNamesIt::new(self)
     }
 
+    /// One based index of the column named `name`, or `None` if the result set has no column of
+    /// that name. Names are compared ASCII case insensitive, see
+    /// [`Self::column_index_by_name_case_sensitive`] for exact matches. If several columns share
+    /// the same name (e.g. the result of a join), the first one wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{Cursor, Error};
+    ///
+    /// fn title(cursor: &mut impl Cursor) -> Result<Option<String>, Error> {
+    ///     let column_number = match cursor.column_index_by_name("title")? {
+    ///         Some(column_number) => column_number,
+    ///         None => return Ok(None),
+    ///     };
+    ///     let mut title = Vec::new();
+    ///     if let Some(mut row) = cursor.next_row()? {
+    ///         row.get_text(column_number, &mut title)?;
+    ///     }
+    ///     Ok(Some(String::from_utf8_lossy(&title).into_owned()))
+    /// }
+    /// ```
+    fn column_index_by_name(&self, name: &str) -> Result<Option<u16>, Error> {
+        find_column(self, |column_name| column_name.eq_ignore_ascii_case(name))
+    }
+
+    /// Like [`Self::column_index_by_name`], but only matches column names which are exactly equal
+    /// to `name`.
+    fn column_index_by_name_case_sensitive(&self, name: &str) -> Result<Option<u16>, Error> {
+        find_column(self, |column_name| column_name == name)
+    }
+
+    /// Display sizes of all columns in the result set, in order. See [`Self::col_display_size`].
+    /// Negative sizes reported by the driver are mapped to `0`.
+    fn column_display_sizes(&self) -> Result<Vec<usize>, Error> {
+        let num_cols: u16 = self.num_result_cols()?.try_into().unwrap();
+        (1..=num_cols)
+            .map(|column_number| {
+                let size = self.col_display_size(column_number)?;
+                Ok(size.try_into().unwrap_or(0))
+            })
+            .collect()
+    }
+
+    /// Data types of all columns in the result set, in order. See [`Self::col_data_type`].
+    fn column_types(&self) -> Result<Vec<DataType>, Error> {
+        let num_cols: u16 = self.num_result_cols()?.try_into().unwrap();
+        (1..=num_cols)
+            .map(|column_number| self.col_data_type(column_number))
+            .collect()
+    }
+
     /// Data type of the specified column.
     ///
     /// `column_number`: Index of the column, starting at 1.
     fn col_data_type(&self, column_number: u16) -> Result<DataType, Error> {
-        let stmt = self.stmt_ref();
-        let kind = stmt.col_concise_type(column_number).into_result(stmt)?;
-        let dt = match kind {
-            SqlDataType::UNKNOWN_TYPE => DataType::Unknown,
-            SqlDataType::EXT_VAR_BINARY => DataType::Varbinary {
-                length: self.col_octet_length(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::EXT_LONG_VAR_BINARY => DataType::LongVarbinary {
-                length: self.col_octet_length(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::EXT_BINARY => DataType::Binary {
-                length: self.col_octet_length(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::EXT_W_VARCHAR => DataType::WVarchar {
-                length: self.col_display_size(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::EXT_W_CHAR => DataType::WChar {
-                length: self.col_display_size(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::EXT_LONG_VARCHAR => DataType::LongVarchar {
-                length: self.col_display_size(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::CHAR => DataType::Char {
-                length: self.col_display_size(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::VARCHAR => DataType::Varchar {
-                length: self.col_display_size(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::NUMERIC => DataType::Numeric {
-                precision: self.col_precision(column_number)?.try_into().unwrap(),
-                scale: self.col_scale(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::DECIMAL => DataType::Decimal {
-                precision: self.col_precision(column_number)?.try_into().unwrap(),
-                scale: self.col_scale(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::INTEGER => DataType::Integer,
-            SqlDataType::SMALLINT => DataType::SmallInt,
-            SqlDataType::FLOAT => DataType::Float {
-                precision: self.col_precision(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::REAL => DataType::Real,
-            SqlDataType::DOUBLE => DataType::Double,
-            SqlDataType::DATE => DataType::Date,
-            SqlDataType::TIME => DataType::Time {
-                precision: self.col_precision(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::TIMESTAMP => DataType::Timestamp {
-                precision: self.col_precision(column_number)?.try_into().unwrap(),
-            },
-            SqlDataType::EXT_BIG_INT => DataType::BigInt,
-            SqlDataType::EXT_TINY_INT => DataType::TinyInt,
-            SqlDataType::EXT_BIT => DataType::Bit,
-            other => {
-                let mut column_description = ColumnDescription::default();
-                self.describe_col(column_number, &mut column_description)?;
-                DataType::Other {
-                    data_type: other,
-                    column_size: column_description.data_type.column_size(),
-                    decimal_digits: column_description.data_type.decimal_digits(),
-                }
+        fetch_col_data_type(self, column_number)
+    }
+}
+
+/// Queries the data type of the column from the driver. Length, precision and scale are obtained
+/// using the respective methods of `meta`. Shared between the default implementation of
+/// [`ResultSetMetadata::col_data_type`] and implementations caching its result.
+pub(crate) fn fetch_col_data_type<M>(meta: &M, column_number: u16) -> Result<DataType, Error>
+where
+    M: ResultSetMetadata + ?Sized,
+{
+    let stmt = meta.stmt_ref();
+    let kind = stmt.col_concise_type(column_number).into_result(stmt)?;
+    let dt = match kind {
+        SqlDataType::UNKNOWN_TYPE => DataType::Unknown,
+        SqlDataType::EXT_VAR_BINARY => DataType::Varbinary {
+            length: meta.col_octet_length(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::EXT_LONG_VAR_BINARY => DataType::LongVarbinary {
+            length: meta.col_octet_length(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::EXT_BINARY => DataType::Binary {
+            length: meta.col_octet_length(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::EXT_W_VARCHAR => DataType::WVarchar {
+            length: meta.col_display_size(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::EXT_W_CHAR => DataType::WChar {
+            length: meta.col_display_size(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::EXT_LONG_VARCHAR => DataType::LongVarchar {
+            length: meta.col_display_size(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::CHAR => DataType::Char {
+            length: meta.col_display_size(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::VARCHAR => DataType::Varchar {
+            length: meta.col_display_size(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::NUMERIC => DataType::Numeric {
+            precision: meta.col_precision(column_number)?.try_into().unwrap(),
+            scale: meta.col_scale(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::DECIMAL => DataType::Decimal {
+            precision: meta.col_precision(column_number)?.try_into().unwrap(),
+            scale: meta.col_scale(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::INTEGER => DataType::Integer,
+        SqlDataType::SMALLINT => DataType::SmallInt,
+        SqlDataType::FLOAT => DataType::Float {
+            precision: meta.col_precision(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::REAL => DataType::Real,
+        SqlDataType::DOUBLE => DataType::Double,
+        SqlDataType::DATE => DataType::Date,
+        SqlDataType::TIME => DataType::Time {
+            precision: meta.col_precision(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::TIMESTAMP => DataType::Timestamp {
+            precision: meta.col_precision(column_number)?.try_into().unwrap(),
+        },
+        SqlDataType::EXT_BIG_INT => DataType::BigInt,
+        SqlDataType::EXT_TINY_INT => DataType::TinyInt,
+        SqlDataType::EXT_BIT => DataType::Bit,
+        other => {
+            let mut column_description = ColumnDescription::default();
+            meta.describe_col(column_number, &mut column_description)?;
+            DataType::Other {
+                data_type: other,
+                column_size: column_description.data_type.column_size(),
+                decimal_digits: column_description.data_type.decimal_digits(),
             }
-        };
-        Ok(dt)
+        }
+    };
+    Ok(dt)
+}
+
+/// One based index of the first column, whose name matches `predicate`. Iterates the columns
+/// directly rather than using [`ColumnNamesIt`], so it can be called for unsized implementations of
+/// [`ResultSetMetadata`].
+fn find_column<M: ResultSetMetadata + ?Sized>(
+    meta: &M,
+    mut predicate: impl FnMut(&str) -> bool,
+) -> Result<Option<u16>, Error> {
+    let num_cols: u16 = meta.num_result_cols()?.try_into().unwrap();
+    let mut buffer = Vec::with_capacity(128);
+    for column_number in 1..=num_cols {
+        meta.col_name(column_number, &mut buffer)?;
+        let column_name: String = decode_utf16(buffer.iter().copied())
+            .map(|decoding_result| decoding_result.unwrap_or(REPLACEMENT_CHARACTER))
+            .collect();
+        if predicate(&column_name) {
+            return Ok(Some(column_number));
+        }
     }
+    Ok(None)
 }
 
 /// An iterator calling `col_name` for each column_name and converting the result into UTF-8. See
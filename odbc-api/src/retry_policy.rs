@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+/// Controls how often and how fast [`crate::Environment::connect_with_retry`] attempts to
+/// reconnect after a transient connection failure.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy {
+///     max_attempts: 5,
+///     ..RetryPolicy::default()
+/// };
+///
+/// // Backoff doubles with each attempt.
+/// assert_eq!(Duration::from_millis(100), policy.backoff(1));
+/// assert_eq!(Duration::from_millis(200), policy.backoff(2));
+/// assert_eq!(Duration::from_millis(400), policy.backoff(3));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. A value of `1` disables retries.
+    pub max_attempts: u32,
+    /// Time to wait before the second attempt.
+    pub initial_backoff: Duration,
+    /// The time to wait is multiplied by this factor after each failed attempt.
+    pub backoff_multiplier: u32,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, waiting 100ms before the second one and 200ms before the third.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            backoff_multiplier: 2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Time to wait after the failed attempt with the (one based) number `attempt`, before trying
+    /// again.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self
+            .backoff_multiplier
+            .saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor)
+    }
+}
@@ -0,0 +1,133 @@
+/// Splits an SQL script into its individual statements at each semicolon (`;`). Semicolons within
+/// string literals (`'...'`), quoted identifiers (`"..."`), line comments (`-- ...`) and block
+/// comments (`/* ... */`) do not terminate a statement.
+///
+/// The statements are returned in order, without the terminating semicolon and without leading or
+/// trailing whitespace. Statements consisting only of whitespace and comments are skipped, so the
+/// last statement of a script does not need to be terminated by a semicolon, yet it may be.
+///
+/// # Limitations
+///
+/// The splitter does not parse SQL, it only tracks quotes and comments. Therefore:
+///
+/// * Semicolons within the body of compound statements are treated as terminators. E.g. a
+///   `CREATE PROCEDURE` or `CREATE TRIGGER` statement with a `BEGIN ... END;` block is split into
+///   several incomplete statements. Execute these statements individually instead.
+/// * Quotes escaped with a backslash (e.g. `'It\'s'`, as accepted by MySQL and MariaDB) are not
+///   recognized, so the literal seems to end early. Escape quotes by doubling them (`'It''s'`), as
+///   the SQL standard specifies.
+/// * Client side commands which are not SQL, like `GO` or `DELIMITER`, are passed on as is.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api::split_sql_script;
+///
+/// let statements = split_sql_script(
+///     "CREATE TABLE t (a VARCHAR(10)); -- Create table;\n\
+///     INSERT INTO t (a) VALUES ('a;b');"
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         "CREATE TABLE t (a VARCHAR(10))",
+///         "-- Create table;\nINSERT INTO t (a) VALUES ('a;b')"
+///     ],
+///     statements
+/// );
+/// ```
+pub fn split_sql_script(script: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    // Start of the current statement
+    let mut start = 0;
+    // `true` if the current statement contains anything besides whitespace and comments.
+    let mut has_content = false;
+    let mut chars = script.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            // Skip literals and quoted identifiers until the closing quote. An escaped quote (e.g.
+            // `''`) is handled by simply entering the literal once more.
+            '\'' | '"' => {
+                has_content = true;
+                for (_, inner) in chars.by_ref() {
+                    if inner == c {
+                        break;
+                    }
+                }
+            }
+            // Line comment. Skip until end of line.
+            '-' if script[index..].starts_with("--") => {
+                for (_, inner) in chars.by_ref() {
+                    if inner == '\n' {
+                        break;
+                    }
+                }
+            }
+            // Block comment. Skip until it is closed.
+            '/' if script[index..].starts_with("/*") => {
+                let end = script[index + 2..]
+                    .find("*/")
+                    .map(|offset| index + 2 + offset + 2)
+                    .unwrap_or(script.len());
+                while chars.next_if(|&(next, _)| next < end).is_some() {}
+            }
+            ';' => {
+                if has_content {
+                    statements.push(script[start..index].trim());
+                }
+                start = index + 1;
+                has_content = false;
+            }
+            other => has_content |= !other.is_whitespace(),
+        }
+    }
+    if has_content {
+        statements.push(script[start..].trim());
+    }
+
+    statements
+}
+
+#[cfg(test)]
+mod test {
+    use super::split_sql_script;
+
+    #[test]
+    fn ignore_semicolons_in_literals_and_comments() {
+        let script = "INSERT INTO t (a) VALUES ('it''s; fine');\n\
+            /* block; comment */ SELECT \"odd;name\" FROM t;\n\
+            -- line; comment\n\
+            DELETE FROM t";
+
+        let statements = split_sql_script(script);
+
+        assert_eq!(
+            vec![
+                "INSERT INTO t (a) VALUES ('it''s; fine')",
+                "/* block; comment */ SELECT \"odd;name\" FROM t",
+                "-- line; comment\nDELETE FROM t",
+            ],
+            statements
+        );
+    }
+
+    #[test]
+    fn skip_empty_statements() {
+        let statements = split_sql_script(" ; SELECT 1;;\n-- trailing comment\n");
+        assert_eq!(vec!["SELECT 1"], statements);
+    }
+
+    /// Documents a known limitation: the splitter does not understand compound statements.
+    #[test]
+    fn semicolon_within_begin_end_block_terminates_statement() {
+        let script = "CREATE TRIGGER t BEFORE INSERT ON a FOR EACH ROW BEGIN SET NEW.b = 1; END;";
+        assert_eq!(
+            vec![
+                "CREATE TRIGGER t BEFORE INSERT ON a FOR EACH ROW BEGIN SET NEW.b = 1",
+                "END"
+            ],
+            split_sql_script(script)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::mem::ManuallyDrop;
+
 use odbc_sys::{HStmt, Handle, HandleType};
 
 use crate::{
@@ -8,22 +10,44 @@ use crate::{
 /// Statement handle which also takes ownership of Connection
 pub struct StatementConnection<'env> {
     handle: HStmt,
-    _parent: Connection<'env>,
+    /// Dropped explicitly in [`Drop::drop`], strictly after the statement handle has been freed.
+    /// ODBC requires child handles to be freed before their parent connection is disconnected.
+    parent: ManuallyDrop<Connection<'env>>,
 }
 
 impl<'env> StatementConnection<'env> {
     pub(crate) unsafe fn new(handle: HStmt, parent: Connection<'env>) -> Self {
         Self {
-            _parent: parent,
+            parent: ManuallyDrop::new(parent),
             handle,
         }
     }
 }
 
+/// Safety argument: `StatementConnection` owns both of its handles exclusively. Neither the statement
+/// nor the connection handle can be reached from anywhere else, so moving the value to another
+/// thread transfers all access to these handles to that thread. The handles are never used
+/// concurrently, only moved, which the ODBC standard permits. The `'env` lifetime only ties the
+/// connection to an [`crate::Environment`], which is `Sync`, so sharing a reference to it with
+/// another thread is sound and the environment is still guaranteed to outlive the connection.
+///
+/// This also makes `CursorImpl<StatementConnection>` (as returned by
+/// [`crate::Connection::into_cursor`]) `Send`.
+///
+/// See: <https://docs.microsoft.com/en-us/sql/odbc/reference/develop-app/multithreading?view=sql-server-ver15>
+unsafe impl<'env> Send for StatementConnection<'env> {}
+
 impl<'s> Drop for StatementConnection<'s> {
     fn drop(&mut self) {
+        // Order matters: ODBC requires the statement to be freed before its connection is
+        // disconnected. `parent` is wrapped in `ManuallyDrop`, so rather than being dropped
+        // implicitly after this method returns, it is dropped exactly once, explicitly, after the
+        // statement handle has been freed.
         unsafe {
             drop_handle(self.handle as Handle, HandleType::Stmt);
+            // Safe: `parent` is not accessed after this, since we are in the middle of dropping
+            // `self`.
+            ManuallyDrop::drop(&mut self.parent);
         }
     }
 }
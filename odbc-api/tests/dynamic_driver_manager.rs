@@ -0,0 +1,43 @@
+//! The driver manager is loaded once per process, so these tests have to run in their own process.
+#![cfg(feature = "dynamic-driver-manager")]
+
+use lazy_static::lazy_static;
+use odbc_api::{Cursor, Environment, DEFAULT_DRIVER_MANAGER};
+
+const MSSQL_CONNECTION: &str =
+    "Driver={ODBC Driver 17 for SQL Server};Server=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;";
+
+// Rust by default executes tests in parallel. Yet only one environment is allowed at a time.
+lazy_static! {
+    pub static ref ENV: Environment = {
+        let _ = env_logger::builder().is_test(true).try_init();
+        Environment::with_driver_manager(DEFAULT_DRIVER_MANAGER).unwrap()
+    };
+}
+
+#[test]
+fn connect_with_dynamically_loaded_driver_manager() {
+    let conn = ENV
+        .connect_with_connection_string(MSSQL_CONNECTION)
+        .unwrap();
+
+    let mut cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+    let mut row_count = 0;
+    while cursor.next_row().unwrap().is_some() {
+        row_count += 1;
+    }
+    assert_eq!(1, row_count);
+}
+
+#[test]
+fn loading_another_driver_manager_fails() {
+    // Make sure the default driver manager is loaded.
+    let _ = &*ENV;
+
+    let result = Environment::with_driver_manager("libiodbc.so.2");
+
+    assert!(matches!(
+        result,
+        Err(odbc_api::Error::DriverManagerAlreadyLoaded { .. })
+    ));
+}
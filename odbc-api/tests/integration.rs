@@ -12,20 +12,26 @@ use odbc_api::{
     buffers::{
         buffer_from_description, buffer_from_description_and_indices, AnyColumnView,
         AnyColumnViewMut, BufferDescription, BufferKind, ColumnarBuffer, Indicator, Item,
-        TextColumn, TextRowSet,
+        MaxStrLen, TextColumn, TextRowSet, Value,
     },
-    handles::{OutputStringBuffer, Statement},
-    parameter::InputParameter,
+    escape_search_pattern, expand_parameter_marker,
+    handles::{Concurrency, CursorType, HasDataType, OutputStringBuffer, Record, State, Statement},
+    parameter::{AsSqlParameter, InputParameter},
     parameter::{
-        Blob, BlobRead, BlobSlice, VarBinaryArray, VarCharArray, VarCharSlice, WithDataType,
+        Blob, BlobRead, BlobSlice, TypedNull, VarBinaryArray, VarCharArray, VarCharSlice,
+        WithDataType,
     },
-    sys, Bit, ColumnDescription, Cursor, DataType, InOut, IntoParameter, Nullability, Nullable,
-    Out, ResultSetMetadata, U16String,
+    sys, AccessMode, Bit, BrowseConnect, ColumnDescription, ConnectionOptions, Cursor, CursorImpl,
+    DataType, Error, Guid, InOut, IntoParameter, Nullability, Nullable, Out, ResultSetMetadata,
+    RetryPolicy, RowSetBuffer, ScrollPosition, U16String,
 };
 use std::{
     ffi::CString,
-    io::{self, Write},
-    iter, str, thread,
+    io::{self, BufReader, Read, Write},
+    iter, str,
+    task::Poll,
+    thread,
+    time::Duration,
 };
 
 const MSSQL_CONNECTION: &str =
@@ -116,6 +122,195 @@ fn connect_to_db(profile: &Profile) {
     assert!(!conn.is_dead().unwrap())
 }
 
+/// Login timeout must be set before connecting, so it is passed as part of the connection options.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn connect_with_login_timeout(profile: &Profile) {
+    let options = ConnectionOptions {
+        login_timeout_sec: Some(5),
+        ..ConnectionOptions::default()
+    };
+    let conn = ENV
+        .connect_with_options(profile.connection_string, options)
+        .unwrap();
+    assert!(!conn.is_dead().unwrap())
+}
+
+/// Every connection created by a builder is configured with its options.
+#[test]
+fn connection_builder_applies_options_to_each_connection() {
+    let builder = ENV.connection_builder(ConnectionOptions {
+        login_timeout_sec: Some(7),
+        ..ConnectionOptions::default()
+    });
+
+    let first = builder
+        .connect_with_connection_string(MSSQL_CONNECTION)
+        .unwrap();
+    let second = builder
+        .connect_with_connection_string(MSSQL_CONNECTION)
+        .unwrap();
+
+    assert_eq!(7, first.login_timeout_sec().unwrap());
+    assert_eq!(7, second.login_timeout_sec().unwrap());
+}
+
+/// The login timeout also applies to connections established with `SQLDriverConnect`.
+#[test]
+fn connection_builder_applies_options_to_driver_connect() {
+    let builder = ENV.connection_builder(ConnectionOptions {
+        login_timeout_sec: Some(7),
+        ..ConnectionOptions::default()
+    });
+
+    let conn = builder
+        .driver_connect(
+            MSSQL_CONNECTION,
+            None,
+            odbc_api::DriverCompleteOption::NoPrompt,
+        )
+        .unwrap();
+
+    assert_eq!(7, conn.login_timeout_sec().unwrap());
+}
+
+/// A larger packet size is negotiated before connecting and large result sets can still be
+/// fetched.
+#[test]
+fn connect_with_packet_size() {
+    let options = ConnectionOptions {
+        packet_size: Some(16384),
+        ..ConnectionOptions::default()
+    };
+    let conn = ENV.connect_with_options(MSSQL_CONNECTION, options).unwrap();
+    assert_eq!(16384, conn.packet_size().unwrap());
+
+    let table_name = "ConnectWithPacketSize";
+    setup_empty_table(&conn, MSSQL.index_type, table_name, &["VARCHAR(100)"]).unwrap();
+    let value = "x".repeat(100);
+    let mut buffer = TextRowSet::from_max_str_lens(1000, iter::once(100));
+    for _ in 0..1000 {
+        buffer.append(iter::once(Some(value.as_bytes())));
+    }
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &buffer,
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+    let mut buffer = TextRowSet::for_cursor(1000, &cursor, None).unwrap();
+    let mut cursor = cursor.bind_buffer(&mut buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    assert_eq!(1000, batch.num_rows());
+    assert_eq!(Some(value.as_bytes()), batch.at(0, 999));
+}
+
+/// Statement text passed as UTF-8 to the narrow ODBC functions is executed and prepared just like
+/// the default UTF-16 text.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn narrow_query_text(profile: &Profile) {
+    let table_name = "NarrowQueryText";
+    let mut conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(20)"])
+        .unwrap();
+    conn.set_narrow_query_text(true);
+    assert!(conn.is_narrow_query_text());
+
+    let insert = format!("INSERT INTO {} (a, b) VALUES (?, ?)", table_name);
+    conn.execute(&insert, (&1, &"one".into_parameter()))
+        .unwrap();
+    let mut prepared = conn.prepare(&insert).unwrap();
+    prepared.execute((&2, &"two".into_parameter())).unwrap();
+    let mut preallocated = conn.preallocate().unwrap();
+    preallocated
+        .execute(&insert, (&3, &"three".into_parameter()))
+        .unwrap();
+
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    assert_eq!("1,one\n2,two\n3,three", actual);
+}
+
+/// Narrow query text can also be chosen as part of the connection options.
+#[test]
+fn narrow_query_text_from_connection_options() {
+    let options = ConnectionOptions {
+        narrow_query_text: true,
+        ..ConnectionOptions::default()
+    };
+    let conn = ENV
+        .connect_with_options(MSSQL.connection_string, options)
+        .unwrap();
+    assert!(conn.is_narrow_query_text());
+
+    let cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+    assert_eq!("42", cursor_to_string(cursor));
+}
+
+/// Read only access mode is set before connecting and reported back by the connection. Whether
+/// writes are actually rejected is up to the driver, e.g. MSSQL treats the attribute as a mere
+/// hint, so this is not asserted here.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn read_only_access_mode(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+    assert_eq!(AccessMode::ReadWrite, conn.access_mode().unwrap());
+
+    let options = ConnectionOptions {
+        access_mode: Some(AccessMode::ReadOnly),
+        ..ConnectionOptions::default()
+    };
+    let read_only = ENV
+        .connect_with_options(profile.connection_string, options)
+        .unwrap();
+    assert_eq!(AccessMode::ReadOnly, read_only.access_mode().unwrap());
+}
+
+/// The driver reports the server as a required attribute, if only the driver is specified.
+#[test]
+fn browse_connect() {
+    let step = ENV
+        .browse_connect("Driver={ODBC Driver 17 for SQL Server};")
+        .unwrap();
+    let browse = match step {
+        BrowseConnect::NeedData(browse) => browse,
+        BrowseConnect::Connected(_) => panic!("Server must be required to connect."),
+    };
+    assert!(browse.attributes().to_uppercase().contains("SERVER"));
+
+    let mut step = browse
+        .browse("Server=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;")
+        .unwrap();
+    // The driver may still offer optional attributes (e.g. the database). Skip them.
+    let conn = loop {
+        match step {
+            BrowseConnect::NeedData(browse) => step = browse.browse("").unwrap(),
+            BrowseConnect::Connected(conn) => break conn,
+        }
+    };
+    assert!(!conn.is_dead().unwrap())
+}
+
+/// SQLite does not support browsing for connection attributes. This is reported as an unsupported
+/// optional feature, rather than a generic error.
+#[test]
+fn browse_connect_unsupported_by_driver() {
+    match ENV.browse_connect(SQLITE_3_CONNECTION) {
+        Err(Error::UnsupportedOptionalFeature { record, .. }) => {
+            assert_eq!(State::DRIVER_DOES_NOT_SUPPORT_FUNCTION, record.state)
+        }
+        Err(other) => panic!("Unexpected error: {}", other),
+        Ok(_) => panic!("SQLite is not expected to support SQLBrowseConnect."),
+    }
+}
+
 #[test]
 fn describe_columns() {
     let conn = MSSQL.connection().unwrap();
@@ -224,6 +419,511 @@ fn describe_columns() {
     assert_eq!(kind, cursor.col_data_type(11).unwrap());
 }
 
+/// Names of previous columns must not bleed into shorter names of following ones.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn describe_all(profile: &Profile) {
+    let table_name = "DescribeAll";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(255) NOT NULL", "INTEGER"])
+        .unwrap();
+    let sql = format!("SELECT a AS long_column_name, b FROM {};", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    let actual = cursor.describe_all().unwrap();
+
+    let names: Vec<_> = actual
+        .iter()
+        .map(|desc| desc.name_to_string().unwrap())
+        .collect();
+    assert_eq!(["long_column_name", "b"], names.as_slice());
+    assert_eq!(DataType::Varchar { length: 255 }, actual[0].data_type);
+    assert_eq!(DataType::Integer, actual[1].data_type);
+    assert_eq!(Nullability::NoNulls, actual[0].nullability);
+    assert_eq!(Nullability::Nullable, actual[1].nullability);
+}
+
+/// Display sizes and types are reported for every column of the result set.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn column_display_sizes_and_types(profile: &Profile) {
+    let table_name = "ColumnDisplaySizesAndTypes";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(20)", "INTEGER"])
+        .unwrap();
+    let sql = format!("SELECT a, b FROM {};", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    let display_sizes = cursor.column_display_sizes().unwrap();
+    let types = cursor.column_types().unwrap();
+
+    let num_cols = cursor.num_result_cols().unwrap() as usize;
+    assert_eq!(num_cols, display_sizes.len());
+    assert_eq!(num_cols, types.len());
+    assert!(display_sizes[0] > 0);
+    assert_eq!(DataType::Integer, types[1]);
+}
+
+/// Fetch a single value without binding a buffer.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn fetch_one_scalar(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+    let mut cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+
+    let value: Option<i32> = cursor.fetch_one_scalar().unwrap();
+
+    assert_eq!(Some(42), value);
+}
+
+/// An empty result set yields `None`, more than one row an error.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn fetch_one_scalar_empty_and_too_many_rows(profile: &Profile) {
+    let table_name = "FetchOneScalar";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+    let sql = format!("SELECT a FROM {}", table_name);
+
+    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let value: Option<i32> = cursor.fetch_one_scalar().unwrap();
+    assert_eq!(None, value);
+    drop(cursor);
+
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (1), (2)", table_name),
+        (),
+    )
+    .unwrap();
+    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let result = cursor.fetch_one_scalar::<i32>();
+    assert!(matches!(result, Err(Error::TooManyRows)));
+    drop(cursor);
+
+    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let value: Option<i32> = cursor.fetch_first_scalar().unwrap();
+    assert!(value.is_some());
+}
+
+/// The batch size is derived from the memory limit and the width of the columns.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn text_row_set_with_memory_limit(profile: &Profile) {
+    let table_name = "TextRowSetWithMemoryLimit";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(4000)"])
+        .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a, b FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+
+    // The wide column alone requires way more than 1 KiB for each row.
+    let buffer = TextRowSet::for_cursor_with_memory_limit(1024 * 1024, &cursor, None).unwrap();
+    assert!(buffer.row_array_size() < 1024);
+    assert!(buffer.row_array_size() >= 1);
+
+    // At least one row, even if it exceeds the limit.
+    let buffer = TextRowSet::for_cursor_with_memory_limit(1, &cursor, None).unwrap();
+    assert_eq!(1, buffer.row_array_size());
+
+    // Limiting the string length allows for more rows.
+    let buffer = TextRowSet::for_cursor_with_memory_limit(1024 * 1024, &cursor, Some(10)).unwrap();
+    assert!(buffer.row_array_size() > 1024);
+}
+
+/// Iterate over a result set row by row, collecting typed values.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn fetch_rows_as_values(profile: &Profile) {
+    let table_name = "FetchRowsAsValues";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(10)", "DOUBLE PRECISION"])
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {} (a, b, c) VALUES (1, 'one', 1.5), (NULL, 'two', NULL), (3, NULL, 3.5)",
+            table_name
+        ),
+        (),
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(
+            &format!("SELECT a, b, c FROM {} ORDER BY id", table_name),
+            (),
+        )
+        .unwrap()
+        .unwrap();
+    let descs = [
+        BufferDescription {
+            kind: BufferKind::I32,
+            nullable: true,
+        },
+        BufferDescription {
+            kind: BufferKind::Text { max_str_len: 10 },
+            nullable: true,
+        },
+        BufferDescription {
+            kind: BufferKind::F64,
+            nullable: true,
+        },
+    ];
+    // Use a batch size smaller than the number of rows, to fetch more than one row set.
+    let buffer = buffer_from_description(2, descs.iter().copied());
+    let rows = cursor
+        .bind_buffer(buffer)
+        .unwrap()
+        .rows()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let expected = vec![
+        vec![
+            Value::I32(1),
+            Value::Text("one".to_string()),
+            Value::F64(1.5),
+        ],
+        vec![Value::Null, Value::Text("two".to_string()), Value::Null],
+        vec![Value::I32(3), Value::Null, Value::F64(3.5)],
+    ];
+    assert_eq!(expected, rows);
+}
+
+/// Recover the concrete column views of a text and an integer column from `AnyColumnView`.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn downcast_any_column_view(profile: &Profile) {
+    let table_name = "DowncastAnyColumnView";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(10)", "INTEGER"])
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {} (a, b) VALUES ('one', 1), ('two', NULL)",
+            table_name
+        ),
+        (),
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a, b FROM {} ORDER BY id", table_name), ())
+        .unwrap()
+        .unwrap();
+    let descs = [
+        BufferDescription {
+            kind: BufferKind::Text { max_str_len: 10 },
+            nullable: true,
+        },
+        BufferDescription {
+            kind: BufferKind::I32,
+            nullable: true,
+        },
+    ];
+    let buffer = buffer_from_description(10, descs.iter().copied());
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    let texts: Vec<_> = batch.column(0).as_text().unwrap().collect();
+    assert_eq!([Some(&b"one"[..]), Some(&b"two"[..])], texts.as_slice());
+    assert!(batch.column(1).as_text().is_none());
+
+    let integers: Vec<_> = batch
+        .column(1)
+        .as_nullable_slice::<i32>()
+        .unwrap()
+        .map(|value| value.copied())
+        .collect();
+    assert_eq!([Some(1), None], integers.as_slice());
+    assert!(batch.column(0).as_nullable_slice::<i32>().is_none());
+    // Column is nullable, so there is no plain slice of integers
+    assert!(batch.column(1).as_slice::<i32>().is_none());
+}
+
+/// A script mixing DDL, an insert with a semicolon inside a string literal and a query is executed
+/// statement by statement.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn execute_batch(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+    let script = "
+        DROP TABLE IF EXISTS ExecuteBatch;
+        CREATE TABLE ExecuteBatch (a VARCHAR(20));
+        -- Semicolons in literals; and comments do not end a statement
+        INSERT INTO ExecuteBatch (a) VALUES ('one;two');
+        /* The result set is closed; before the next statement is executed */
+        SELECT a FROM ExecuteBatch;
+        INSERT INTO ExecuteBatch (a) VALUES ('three')
+    ";
+
+    let num_statements = conn.execute_batch(script).unwrap();
+
+    assert_eq!(5, num_statements);
+    let actual = table_to_string(&conn, "ExecuteBatch", &["a"]);
+    assert_eq!("one;two\nthree", actual);
+}
+
+/// Execution of a script stops at the first failing statement, which is reported by position.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn execute_batch_reports_failing_statement(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+    let script = "SELECT 1; SELECT a FROM ExecuteBatchNonExistingTable; SELECT 2";
+
+    let result = conn.execute_batch(script);
+
+    match result {
+        Err(Error::FailedExecutingScriptStatement {
+            position,
+            statement,
+            source: _,
+        }) => {
+            assert_eq!(2, position);
+            assert_eq!("SELECT a FROM ExecuteBatchNonExistingTable", statement);
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+/// Two text columns of very different sizes are bound to buffers sized independently from each
+/// other.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn columnar_buffer_with_capacity_hints(profile: &Profile) {
+    let table_name = "ColumnarBufferWithCapacityHints";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(3)", "VARCHAR(2000)"])
+        .unwrap();
+    let description = "x".repeat(2000);
+    conn.execute(
+        &format!("INSERT INTO {} (a, b) VALUES (?, ?)", table_name),
+        (
+            &"ABC".into_parameter(),
+            &description.as_str().into_parameter(),
+        ),
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a, b FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarBuffer::with_capacity_hints(
+        10,
+        [
+            BufferKind::Text { max_str_len: 3 },
+            BufferKind::Text { max_str_len: 2000 },
+        ],
+    );
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    let codes: Vec<_> = batch.column(0).as_text().unwrap().collect();
+    assert_eq!([Some(&b"ABC"[..])], codes.as_slice());
+    let descriptions: Vec<_> = batch.column(1).as_text().unwrap().collect();
+    assert_eq!([Some(description.as_bytes())], descriptions.as_slice());
+}
+
+/// Insert a GUID as text and as parameter and fetch both back into a columnar buffer of GUIDs.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn insert_and_fetch_guid(profile: &Profile) {
+    let table_name = "InsertAndFetchGuid";
+    let conn = profile
+        .setup_empty_table(table_name, &["UNIQUEIDENTIFIER"])
+        .unwrap();
+    let text = "6F9619FF-8B86-D011-B42D-00C04FC964FF";
+    let guid: Guid = text.parse().unwrap();
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES ('{}')", table_name, text),
+        (),
+    )
+    .unwrap();
+    conn.execute(&format!("INSERT INTO {} (a) VALUES (?)", table_name), &guid)
+        .unwrap();
+    // Bind GUID buffer directly to the column
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {} ORDER BY id", table_name), ())
+        .unwrap()
+        .unwrap();
+    let desc = BufferDescription {
+        kind: BufferKind::from_data_type(cursor.col_data_type(1).unwrap()).unwrap(),
+        nullable: true,
+    };
+    assert_eq!(BufferKind::Guid, desc.kind);
+    let buffer = buffer_from_description(10, iter::once(desc));
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let values: Vec<_> = Guid::as_nullable_slice(batch.column(0))
+        .unwrap()
+        .map(|guid| guid.unwrap().to_string())
+        .collect();
+    assert_eq!([text, text], values.as_slice());
+    // The driver renders the GUID as text the same way we do.
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!(format!("{}\n{}", text, text), actual);
+}
+
+/// Bind only the first column and fetch the second one for each row of the row set.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn get_data_of_unbound_column_in_row_set(profile: &Profile) {
+    let table_name = "GetDataOfUnboundColumnInRowSet";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER NOT NULL", "VARCHAR(50)"])
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {} (a, b) VALUES (1, 'one'), (2, NULL), (3, 'three')",
+            table_name
+        ),
+        (),
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a, b FROM {} ORDER BY id", table_name), ())
+        .unwrap()
+        .unwrap();
+    let desc = BufferDescription {
+        kind: BufferKind::I32,
+        nullable: false,
+    };
+    let buffer = buffer_from_description_and_indices(10, iter::once((1, desc)));
+    let mut row_set_cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = row_set_cursor.fetch().unwrap().unwrap();
+    let ids = i32::as_slice(batch.column(0)).unwrap().to_vec();
+
+    let mut actual = Vec::new();
+    let mut buf = Vec::new();
+    for (row_index, id) in ids.into_iter().enumerate() {
+        let mut row = row_set_cursor.row_at(row_index).unwrap();
+        let text = if row.get_text(2, &mut buf).unwrap() {
+            String::from_utf8(buf.clone()).unwrap()
+        } else {
+            "NULL".to_string()
+        };
+        actual.push(format!("{},{}", id, text));
+    }
+
+    assert_eq!(vec!["1,one", "2,NULL", "3,three"], actual);
+    // Rows beyond the ones fetched last can not be positioned on, even though they fit into the
+    // buffer.
+    assert!(matches!(
+        row_set_cursor.row_at(3).map(|_| ()),
+        Err(Error::RowIndexOutOfBounds {
+            row_index: 3,
+            num_rows: 3
+        })
+    ));
+}
+
+/// Binding a buffer with fewer columns than the result set fails right away, rather than during
+/// fetch.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn bind_buffer_with_too_few_columns(profile: &Profile) {
+    let table_name = "BindBufferWithTooFewColumns";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "INTEGER", "INTEGER"])
+        .unwrap();
+    let cursor = conn
+        .execute(&format!("SELECT a, b, c FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+    let desc = BufferDescription {
+        kind: BufferKind::I32,
+        nullable: true,
+    };
+    let buffer = buffer_from_description(10, [desc, desc].iter().copied());
+
+    let result = cursor.bind_buffer_checked(buffer);
+
+    assert!(matches!(
+        result,
+        Err(Error::TooFewColumnsInBuffer {
+            expected: 3,
+            actual: 2
+        })
+    ));
+}
+
+/// Bind the two leading columns to a buffer and read the trailing one for each row of the row set.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn extra_column_after_bound_columns(profile: &Profile) {
+    let table_name = "ExtraColumnAfterBoundColumns";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER NOT NULL", "VARCHAR(10)", "INTEGER"])
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {} (a, b, c) VALUES (1, 'one', 10), (2, 'two', NULL)",
+            table_name
+        ),
+        (),
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(
+            &format!("SELECT a, b, c FROM {} ORDER BY id", table_name),
+            (),
+        )
+        .unwrap()
+        .unwrap();
+    let buffer = buffer_from_description(
+        10,
+        [
+            BufferDescription {
+                kind: BufferKind::I32,
+                nullable: false,
+            },
+            BufferDescription {
+                kind: BufferKind::Text { max_str_len: 10 },
+                nullable: true,
+            },
+        ]
+        .into_iter(),
+    );
+    let mut row_set_cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = row_set_cursor.fetch().unwrap().unwrap();
+    let ids = i32::as_slice(batch.column(0)).unwrap().to_vec();
+    let texts: Vec<_> = match batch.column(1) {
+        AnyColumnView::Text(col) => col
+            .map(|text| String::from_utf8(text.unwrap().to_vec()).unwrap())
+            .collect(),
+        _ => panic!("Unexpected buffer type"),
+    };
+
+    let mut actual = Vec::new();
+    for (row_index, (id, text)) in ids.into_iter().zip(texts).enumerate() {
+        let mut c = Nullable::<i32>::null();
+        row_set_cursor.extra_column(3, row_index, &mut c).unwrap();
+        actual.push((id, text, c.into_opt()));
+    }
+
+    assert_eq!(
+        vec![
+            (1, "one".to_string(), Some(10)),
+            (2, "two".to_string(), None)
+        ],
+        actual
+    );
+}
+
 /// Fetch text from data source using the TextBuffer type
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
@@ -289,10 +989,53 @@ fn into_cursor(profile: &Profile) {
     };
     let cursor = make_cursor();
 
-    // Cursor to string helper utilizes the text buffer
-    let actual = cursor_to_string(cursor);
-    let expected = "Interstellar,NULL\n2001: A Space Odyssey,1968\nJurassic Park,1993";
-    assert_eq!(expected, actual);
+    // Cursor to string helper utilizes the text buffer
+    let actual = cursor_to_string(cursor);
+    let expected = "Interstellar,NULL\n2001: A Space Odyssey,1968\nJurassic Park,1993";
+    assert_eq!(expected, actual);
+}
+
+/// Dropping a cursor which owns its connection frees the statement before the connection is
+/// disconnected. Disconnecting with an open transaction rolls it back.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn drop_cursor_owning_connection_within_transaction(profile: &Profile) {
+    let table_name = "DropCursorOwningConnectionWithinTransaction";
+    profile.setup_empty_table(table_name, &["INT"]).unwrap();
+
+    let conn = profile.connection().unwrap();
+    conn.set_autocommit(false).unwrap();
+    let insert_sql = format!("INSERT INTO {} (a) VALUES (1),(2),(3)", table_name);
+    conn.execute(&insert_sql, ()).unwrap();
+    let query = format!("SELECT a FROM {} ORDER BY id", table_name);
+    let cursor = conn.into_cursor(&query, ()).unwrap().unwrap();
+    // Drop the cursor without consuming the result set. Closing the cursor, freeing the statement,
+    // rolling back and disconnecting must all succeed, otherwise dropping panics.
+    drop(cursor);
+
+    let conn = profile.connection().unwrap();
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("", actual);
+}
+
+/// A cursor owning its connection can be moved to another thread and fetched there.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn send_cursor_to_other_thread(profile: &Profile) {
+    let table_name = "SendCursorToOtherThread";
+    let conn = profile.setup_empty_table(table_name, &["INT"]).unwrap();
+    let insert_sql = format!("INSERT INTO {} (a) VALUES (1),(2),(3)", table_name);
+    conn.execute(&insert_sql, ()).unwrap();
+
+    let query = format!("SELECT a FROM {} ORDER BY id", table_name);
+    let cursor = conn.into_cursor(&query, ()).unwrap().unwrap();
+
+    let handle = thread::spawn(move || cursor_to_string(cursor));
+
+    let actual = handle.join().unwrap();
+    assert_eq!("1\n2\n3", actual)
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -319,6 +1062,97 @@ fn column_name(profile: &Profile) {
     assert_eq!("b", name.to_string().unwrap());
 }
 
+/// Base table and base column name of a column, which has been given an alias in the query.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn column_base_table_and_column_name(profile: &Profile) {
+    let table_name = "ColumnBaseTableAndColumnName";
+    let conn = profile.setup_empty_table(table_name, &["INT"]).unwrap();
+
+    let sql = format!("SELECT a AS alias FROM {};", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    let mut buf = Vec::new();
+    cursor.col_base_table_name(1, &mut buf).unwrap();
+    assert_eq!(table_name, String::from_utf16(&buf).unwrap());
+    cursor.col_base_column_name(1, &mut buf).unwrap();
+    assert_eq!("a", String::from_utf16(&buf).unwrap());
+    cursor.col_label(1, &mut buf).unwrap();
+    assert_eq!("alias", String::from_utf16(&buf).unwrap());
+}
+
+/// Fetch a value into a text buffer which is too small to hold it and expect an error, rather than
+/// a silently truncated value.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn fetch_with_truncation_check(profile: &Profile) {
+    let table_name = "FetchWithTruncationCheck";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(50)"])
+        .unwrap();
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES ('Hello, World!')", table_name),
+        (),
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+    let buffer = TextRowSet::for_cursor(10, &cursor, Some(5)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    match cursor.fetch_with_truncation_check() {
+        Err(Error::TextTruncation { column, needed_len }) => {
+            assert_eq!(1, column);
+            assert_eq!(Some(13), needed_len);
+        }
+        Err(other) => panic!("Unexpected error: {}", other),
+        Ok(_) => panic!("Truncation must be reported as an error."),
+    }
+}
+
+/// Each policy for the maximum string length either clamps the reported column length, or reports
+/// an error if it is exceeded.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn text_row_set_max_str_len_policies(profile: &Profile) {
+    let table_name = "TextRowSetMaxStrLenPolicies";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(50)"])
+        .unwrap();
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+
+    let unbounded = TextRowSet::for_cursor(1, &cursor, MaxStrLen::Unbounded).unwrap();
+    let reported_len = unbounded.max_len(0);
+    assert!(reported_len >= 50);
+
+    let clamped = TextRowSet::for_cursor(1, &cursor, MaxStrLen::Clamp(10)).unwrap();
+    assert_eq!(10, clamped.max_len(0));
+
+    let within_limit =
+        TextRowSet::for_cursor(1, &cursor, MaxStrLen::ClampOrError(reported_len)).unwrap();
+    assert_eq!(reported_len, within_limit.max_len(0));
+
+    match TextRowSet::for_cursor(1, &cursor, MaxStrLen::ClampOrError(10)) {
+        Err(Error::ColumnExceedsMaxStrLen {
+            column,
+            reported_len: actual_reported_len,
+            max_str_len,
+        }) => {
+            assert_eq!(1, column);
+            assert_eq!(reported_len, actual_reported_len);
+            assert_eq!(10, max_str_len);
+        }
+        Err(other) => panic!("Unexpected error: {}", other),
+        Ok(_) => panic!("Exceeding the maximum string length must be reported as an error."),
+    }
+}
+
 /// Bind a CHAR column to a character buffer.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
@@ -382,6 +1216,38 @@ fn bind_bit(profile: &Profile) {
     assert!(buf.get()[1].as_bool());
 }
 
+/// Insert `true`, `false` and `NULL` using booleans as parameters and read them back.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn insert_and_fetch_bool(profile: &Profile) {
+    let table_name = "InsertAndFetchBool";
+
+    let conn = profile.setup_empty_table(table_name, &["BIT"]).unwrap();
+    let insert_sql = format!("INSERT INTO {} (a) VALUES (?);", table_name);
+    let mut prepared = conn.prepare(&insert_sql).unwrap();
+    prepared.execute(&true.into_parameter()).unwrap();
+    prepared.execute(&false.into_parameter()).unwrap();
+    prepared.execute(&None::<bool>.into_parameter()).unwrap();
+
+    let sql = format!("SELECT a FROM {} ORDER BY id;", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let buffer_desc = BufferDescription {
+        kind: BufferKind::Bit,
+        nullable: true,
+    };
+    let row_set_buffer = buffer_from_description(3, iter::once(buffer_desc));
+    let mut cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let col_it = if let AnyColumnView::NullableBit(col_it) = batch.column(0) {
+        col_it
+    } else {
+        panic!("Column View expected to be nullable bit")
+    };
+    let actual: Vec<_> = col_it.map(|bit| bit.map(|bit| bit.as_bool())).collect();
+    assert_eq!(vec![Some(true), Some(false), None], actual);
+}
+
 /// Binds a buffer which is too short to a fixed sized character type. This provokes an indicator of
 /// `NO_TOTAL` on MSSQL.
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -734,6 +1600,89 @@ fn columnar_insert_timestamp(profile: &Profile) {
     assert_eq!(expected, actual);
 }
 
+/// Round trip interleaved `NULL`s and values through a nullable integer column and copy the fetched
+/// values into a vector owning them.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn nullable_slice_to_vec(profile: &Profile) {
+    let table_name = "NullableSliceToVec";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+    let input = vec![None, Some(1), None, Some(2), Some(3), None];
+
+    let desc = BufferDescription {
+        kind: BufferKind::I32,
+        nullable: true,
+    };
+    let mut buffer = buffer_from_description(10, iter::once(desc));
+    buffer.set_num_rows(input.len());
+    if let AnyColumnViewMut::NullableI32(mut writer) = buffer.column_mut(0) {
+        writer.write(input.iter().copied());
+    } else {
+        panic!("Expected nullable i32 column writer");
+    };
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &buffer,
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {} ORDER BY id", table_name), ())
+        .unwrap()
+        .unwrap();
+    let mut cursor = cursor
+        .bind_buffer(buffer_from_description(10, iter::once(desc)))
+        .unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let actual = batch.column(0).as_nullable_slice::<i32>().unwrap().to_vec();
+
+    assert_eq!(input, actual);
+}
+
+/// `REAL` values, including `NULL`s, are fetched as `f32` without being widened to `f64`, so they
+/// are identical bit for bit to the inserted ones.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn real_round_trips_as_f32(profile: &Profile) {
+    let table_name = "RealRoundTripsAsF32";
+    let conn = profile.setup_empty_table(table_name, &["REAL"]).unwrap();
+    let input = [Some(0.1f32), None, Some(-1.234_567_9e-20), Some(f32::MAX)];
+
+    let desc = BufferDescription {
+        kind: BufferKind::F32,
+        nullable: true,
+    };
+    let mut buffer = buffer_from_description(10, iter::once(desc));
+    buffer.set_num_rows(input.len());
+    if let AnyColumnViewMut::NullableF32(mut writer) = buffer.column_mut(0) {
+        writer.write(input.iter().copied());
+    } else {
+        panic!("Expected nullable f32 column writer");
+    };
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &buffer,
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {} ORDER BY id", table_name), ())
+        .unwrap()
+        .unwrap();
+    let mut cursor = cursor
+        .bind_buffer(buffer_from_description(10, iter::once(desc)))
+        .unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let actual = batch.column(0).as_nullable_slice::<f32>().unwrap().to_vec();
+
+    let bits = |values: &[Option<f32>]| -> Vec<Option<u32>> {
+        values.iter().map(|value| value.map(f32::to_bits)).collect()
+    };
+    assert_eq!(bits(&input), bits(&actual));
+}
+
 /// Insert values into a DATETIME2(3) column using a columnar buffer. Milliseconds precision is
 /// different from the default precision 7 (100ns).
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -801,6 +1750,39 @@ fn columnar_insert_timestamp_ms(profile: &Profile) {
     assert_eq!(expected, actual);
 }
 
+/// Bind a timestamp with seven fractional digits as parameter and read back the exact value.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn bind_timestamp_with_precision(profile: &Profile) {
+    let table_name = "BindTimestampWithPrecision";
+    let conn = profile
+        .setup_empty_table(table_name, &["DATETIME2(7)"])
+        .unwrap();
+    let timestamp = Timestamp {
+        year: 2021,
+        month: 3,
+        day: 20,
+        hour: 16,
+        minute: 13,
+        second: 54,
+        fraction: 123456700,
+    };
+
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &WithDataType::timestamp(timestamp, 7),
+    )
+    .unwrap();
+
+    let mut cursor = conn
+        .execute(&format!("SELECT a FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+    let actual: Option<Timestamp> = cursor.fetch_one_scalar().unwrap();
+    assert_eq!(Some(timestamp), actual);
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("2021-03-20 16:13:54.1234567", actual);
+}
+
 /// Insert values into a varbinary column using a columnar buffer
 #[test_case(MSSQL; "Microsoft SQL Server")]
 // #[test_case(MARIADB; "Maria DB")] different binary text representation
@@ -854,6 +1836,45 @@ fn columnar_insert_varbinary(profile: &Profile) {
     assert_eq!(expected, actual);
 }
 
+/// Grow the maximum string length of a text column in the middle of filling a batch, without
+/// losing the rows written so far.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn grow_text_column_mid_batch(profile: &Profile) {
+    let table_name = "GrowTextColumnMidBatch";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(64)"])
+        .unwrap();
+
+    let desc = BufferDescription {
+        kind: BufferKind::Text { max_str_len: 8 },
+        nullable: true,
+    };
+    let mut buffer = buffer_from_description(3, iter::once(desc));
+    let long_text = "x".repeat(64);
+    buffer.set_num_rows(3);
+    if let AnyColumnViewMut::Text(mut writer) = buffer.column_mut(0) {
+        writer.set_value(0, Some(&b"12345678"[..]));
+        writer.set_value(1, None);
+        writer.set_max_len(64);
+        assert_eq!(64, writer.max_len());
+        writer.set_value(2, Some(long_text.as_bytes()));
+    } else {
+        panic!("Expected text column writer");
+    };
+
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &buffer,
+    )
+    .unwrap();
+
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = format!("12345678\nNULL\n{}", long_text);
+    assert_eq!(expected, actual);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -988,75 +2009,232 @@ fn adaptive_columnar_insert_varchar(profile: &Profile) {
         panic!("Expected text column writer");
     };
 
-    // Bind buffer and insert values.
+    // Bind buffer and insert values.
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &buffer,
+    )
+    .unwrap();
+
+    // Query values and compare with expectation
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {} ORDER BY Id", table_name), ())
+        .unwrap()
+        .unwrap();
+    let actual = cursor_to_string(cursor);
+    let expected = "Hi\nHello\nWorld\nNULL\nHello, World!";
+    assert_eq!(expected, actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(SQLITE_3; "SQLite 3")]
+fn adaptive_columnar_insert_varbin(profile: &Profile) {
+    let table_name = "AdaptiveColumnarInsertVarbin";
+    // Setup
+    let conn = profile
+        .setup_empty_table(table_name, &["VARBINARY(13)"])
+        .unwrap();
+
+    // Fill buffer with values
+    let desc = BufferDescription {
+        // Buffer size purposefully chosen too small, so we need to increase the buffer size if we
+        // encounter larger inputs.
+        kind: BufferKind::Binary { length: 1 },
+        nullable: true,
+    };
+
+    // Input values to insert.
+    let input = [
+        Some(&b"Hi"[..]),
+        Some(&b"Hello"[..]),
+        Some(&b"World"[..]),
+        None,
+        Some(&b"Hello, World!"[..]),
+    ];
+
+    let mut buffer = buffer_from_description(input.len(), iter::once(desc));
+
+    buffer.set_num_rows(input.len());
+    if let AnyColumnViewMut::Binary(mut writer) = buffer.column_mut(0) {
+        for (index, &bytes) in input.iter().enumerate() {
+            writer.append(index, bytes)
+        }
+    } else {
+        panic!("Expected binary column writer");
+    };
+
+    // Bind buffer and insert values.
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &buffer,
+    )
+    .unwrap();
+
+    // Query values and compare with expectation
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {} ORDER BY Id", table_name), ())
+        .unwrap()
+        .unwrap();
+    let actual = cursor_to_string(cursor);
+    let expected = "4869\n48656C6C6F\n576F726C64\nNULL\n48656C6C6F2C20576F726C6421";
+    assert_eq!(expected, actual);
+}
+
+/// Fill a columnar buffer of mixed types cell by cell and insert it.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn columnar_insert_mixed_types_with_setters(profile: &Profile) {
+    let table_name = "ColumnarInsertMixedTypesWithSetters";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(10)", "INTEGER", "BIGINT NOT NULL"])
+        .unwrap();
+
+    let descriptions = [
+        BufferDescription {
+            kind: BufferKind::Text { max_str_len: 10 },
+            nullable: true,
+        },
+        BufferDescription {
+            kind: BufferKind::I32,
+            nullable: true,
+        },
+        BufferDescription {
+            kind: BufferKind::I64,
+            nullable: false,
+        },
+    ];
+    let mut buffer = buffer_from_description(10, descriptions.iter().copied());
+
+    let rows = [
+        (Some("one"), Some(1), 10),
+        (None, Some(2), 20),
+        (Some("three"), None, 30),
+    ];
+    // Only the first three rows are inserted.
+    buffer.set_num_rows(rows.len());
+    for (index, &(text, int, big_int)) in rows.iter().enumerate() {
+        buffer
+            .column_mut(0)
+            .set_text(index, text.map(str::as_bytes));
+        buffer.column_mut(1).set_i32(index, int);
+        buffer.column_mut(2).set_i64(index, Some(big_int));
+    }
+
     conn.execute(
-        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &format!("INSERT INTO {} (a, b, c) VALUES (?, ?, ?)", table_name),
         &buffer,
     )
     .unwrap();
 
-    // Query values and compare with expectation
     let cursor = conn
-        .execute(&format!("SELECT a FROM {} ORDER BY Id", table_name), ())
+        .execute(
+            &format!("SELECT a, b, c FROM {} ORDER BY Id", table_name),
+            (),
+        )
         .unwrap()
         .unwrap();
     let actual = cursor_to_string(cursor);
-    let expected = "Hi\nHello\nWorld\nNULL\nHello, World!";
+    let expected = "one,1,10\nNULL,2,20\nthree,NULL,30";
     assert_eq!(expected, actual);
 }
 
+/// Read a result set into a `Vec` of structs deriving `FromRow`.
+#[cfg(feature = "derive")]
 #[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(SQLITE_3; "SQLite 3")]
-fn adaptive_columnar_insert_varbin(profile: &Profile) {
-    let table_name = "AdaptiveColumnarInsertVarbin";
-    // Setup
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn derive_from_row(profile: &Profile) {
+    use odbc_api::FromRow;
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct MyRow {
+        a: i32,
+        #[odbc(name = "b")]
+        text: String,
+        c: Option<f64>,
+    }
+
+    let table_name = "DeriveFromRow";
     let conn = profile
-        .setup_empty_table(table_name, &["VARBINARY(13)"])
+        .setup_empty_table(
+            table_name,
+            &["INTEGER NOT NULL", "VARCHAR(20) NOT NULL", "FLOAT"],
+        )
         .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {} (a, b, c) VALUES (1, 'one', 1.5), (2, 'two', NULL), (3, 'three', 3.5)",
+            table_name
+        ),
+        (),
+    )
+    .unwrap();
 
-    // Fill buffer with values
-    let desc = BufferDescription {
-        // Buffer size purposefully chosen too small, so we need to increase the buffer size if we
-        // encounter larger inputs.
-        kind: BufferKind::Binary { length: 1 },
-        nullable: true,
-    };
-
-    // Input values to insert.
-    let input = [
-        Some(&b"Hi"[..]),
-        Some(&b"Hello"[..]),
-        Some(&b"World"[..]),
-        None,
-        Some(&b"Hello, World!"[..]),
+    let cursor = conn
+        .execute(
+            &format!("SELECT c, b, a FROM {} ORDER BY id", table_name),
+            (),
+        )
+        .unwrap()
+        .unwrap();
+    // Batch size smaller than the number of rows, to fetch more than one batch.
+    let rows = MyRow::from_cursor(cursor, 2).unwrap();
+
+    let expected = vec![
+        MyRow {
+            a: 1,
+            text: "one".to_string(),
+            c: Some(1.5),
+        },
+        MyRow {
+            a: 2,
+            text: "two".to_string(),
+            c: None,
+        },
+        MyRow {
+            a: 3,
+            text: "three".to_string(),
+            c: Some(3.5),
+        },
     ];
+    assert_eq!(expected, rows);
+}
 
-    let mut buffer = buffer_from_description(input.len(), iter::once(desc));
+/// Reading `NULL` into a field which is not an `Option` must be reported as an error.
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn derive_from_row_null_in_required_field(profile: &Profile) {
+    use odbc_api::FromRow;
 
-    buffer.set_num_rows(input.len());
-    if let AnyColumnViewMut::Binary(mut writer) = buffer.column_mut(0) {
-        for (index, &bytes) in input.iter().enumerate() {
-            writer.append(index, bytes)
-        }
-    } else {
-        panic!("Expected binary column writer");
-    };
+    #[derive(FromRow, Debug)]
+    struct MyRow {
+        #[allow(dead_code)]
+        a: String,
+    }
 
-    // Bind buffer and insert values.
+    let table_name = "DeriveFromRowNullInRequiredField";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(4000)"])
+        .unwrap();
     conn.execute(
-        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
-        &buffer,
+        &format!("INSERT INTO {} (a) VALUES ('text'), (NULL)", table_name),
+        (),
     )
     .unwrap();
 
-    // Query values and compare with expectation
     let cursor = conn
-        .execute(&format!("SELECT a FROM {} ORDER BY Id", table_name), ())
+        .execute(&format!("SELECT a FROM {} ORDER BY id", table_name), ())
         .unwrap()
         .unwrap();
-    let actual = cursor_to_string(cursor);
-    let expected = "4869\n48656C6C6F\n576F726C64\nNULL\n48656C6C6F2C20576F726C6421";
-    assert_eq!(expected, actual);
+    let result = MyRow::from_cursor(cursor, 10);
+
+    assert!(matches!(
+        result,
+        Err(Error::NullInRequiredField { column }) if column == "a"
+    ));
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -1189,6 +2367,306 @@ fn prepared_statement(profile: &Profile) {
     }
 }
 
+/// Execute a prepared statement three times in a row, getting it back from the cursor each time.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn prepared_statement_close_and_reuse(profile: &Profile) {
+    // Setup
+    let table_name = "PreparedStatementCloseAndReuse";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(13)", "INTEGER"])
+        .unwrap();
+    let insert = format!(
+        "INSERT INTO {} (a,b) VALUES ('First', 1), ('Second', 2), ('Third', 3);",
+        table_name
+    );
+    conn.execute(&insert, ()).unwrap();
+
+    // Prepare the statement once
+    let sql = format!("SELECT a FROM {} where b=?;", table_name);
+    let mut prepared = conn.prepare(&sql).unwrap();
+
+    // Execute it three times with different parameters
+    let mut titles = Vec::new();
+    for b in 1..=3 {
+        let mut cursor = prepared.into_cursor(&b).unwrap().unwrap();
+        let mut row = cursor.next_row().unwrap().unwrap();
+        let mut title = Vec::new();
+        row.get_text(1, &mut title).unwrap();
+        titles.push(String::from_utf8(title).unwrap());
+        drop(row);
+        prepared = cursor.close_and_reuse().unwrap();
+    }
+
+    assert_eq!(["First", "Second", "Third"], titles.as_slice());
+}
+
+/// Closing the cursor explicitly returns the statement, which can be used to execute another query.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn close_cursor_and_reuse_statement(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+    let cursor = conn.execute("SELECT 1", ()).unwrap().unwrap();
+
+    let mut statement = cursor.close().unwrap();
+
+    let query = U16String::from_str("SELECT 2");
+    let has_result_set = unsafe { statement.exec_direct(&query) }
+        .into_result(&statement)
+        .unwrap();
+    assert!(has_result_set);
+    let mut cursor = unsafe { CursorImpl::new(statement) };
+    let actual: i32 = cursor.fetch_one_scalar().unwrap().unwrap();
+    assert_eq!(2, actual);
+}
+
+/// Execute a trivial query asynchronously and poll it until it is finished.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn execute_polling(profile: &Profile) {
+    let table_name = "ExecutePolling";
+    let conn = profile.setup_empty_table(table_name, &["INT"]).unwrap();
+    let insert_sql = format!("INSERT INTO {} (a) VALUES (1),(2),(3)", table_name);
+    conn.execute(&insert_sql, ()).unwrap();
+
+    let query = format!("SELECT a FROM {} ORDER BY id", table_name);
+    let mut execution = conn.execute_polling(&query, ()).unwrap();
+    let cursor = loop {
+        if let Poll::Ready(maybe_cursor) = execution.poll().unwrap() {
+            break maybe_cursor.unwrap();
+        }
+    };
+
+    let actual = cursor_to_string(cursor);
+    assert_eq!("1\n2\n3", actual);
+}
+
+/// Cancel a long running query from another thread, while the main thread is blocked executing it.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn cancel_long_running_query_from_other_thread(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+    let mut statement = conn.preallocate().unwrap();
+    let (cancel_handle, mut statement) = statement.split_cancel_handle();
+
+    let result = thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(500));
+            cancel_handle.cancel().unwrap();
+        });
+        statement
+            .execute("WAITFOR DELAY '00:00:30'", ())
+            .map(|_| ())
+    });
+
+    match result {
+        Err(Error::Diagnostics { record, .. }) => {
+            assert_eq!(State::OPERATION_CANCELED, record.state)
+        }
+        other => panic!("Expected execution to be canceled, got: {:?}", other),
+    }
+}
+
+/// A query exceeding the query timeout fails with an error classified as timeout, which still
+/// carries the diagnostic record.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn query_timeout(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+    let mut statement = conn.preallocate().unwrap();
+    statement.set_query_timeout_sec(1).unwrap();
+
+    let result = statement
+        .execute("WAITFOR DELAY '00:00:10'", ())
+        .map(|_| ());
+
+    let error = result.unwrap_err();
+    assert!(error.is_timeout(), "{}", error);
+    match error {
+        Error::Diagnostics { record, .. } => assert_eq!(State::TIMEOUT_EXPIRED, record.state),
+        other => panic!("Expected diagnostics, got: {:?}", other),
+    }
+}
+
+/// If enabled, the error of a query failing to execute carries the query text, but not the
+/// parameter values.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn failed_query_error_contains_query_text(profile: &Profile) {
+    let mut conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+
+    // Query text is not attached by default
+    let error = conn.execute("SELEKT 42", ()).map(|_| ()).unwrap_err();
+    assert!(matches!(error, Error::Diagnostics { .. }), "{:?}", error);
+
+    conn.set_query_text_in_errors(true);
+    let error = conn
+        .execute("SELEKT ?", &"Secret".into_parameter())
+        .map(|_| ())
+        .unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("SELEKT ?"), "{}", message);
+    assert!(!message.contains("Secret"), "{}", message);
+
+    // Also applies to prepared statements, regardless of whether the driver reports the error
+    // during preparation or execution.
+    let error = conn
+        .prepare("SELEKT 42")
+        .and_then(|mut prepared| prepared.execute(()).map(|_| ()))
+        .unwrap_err();
+    assert!(
+        matches!(error, Error::FailedExecutingQuery { .. }),
+        "{:?}",
+        error
+    );
+}
+
+/// Errors unrelated to timeouts, e.g. syntax errors, are not classified as timeout.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn syntax_error_is_not_timeout(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+
+    let error = conn.execute("SELEKT 42", ()).map(|_| ()).unwrap_err();
+
+    assert!(!error.is_timeout());
+}
+
+/// Limit the number of rows returned by the driver, without changing the query.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn max_rows(profile: &Profile) {
+    let table_name = "MaxRows";
+    let conn = profile.setup_empty_table(table_name, &["INT"]).unwrap();
+    let insert_sql = format!("INSERT INTO {} (a) VALUES (1),(2),(3),(4),(5)", table_name);
+    conn.execute(&insert_sql, ()).unwrap();
+    let query = format!("SELECT a FROM {} ORDER BY id", table_name);
+
+    let mut statement = conn.preallocate().unwrap();
+    statement.set_max_rows(3).unwrap();
+    let cursor = statement.execute(&query, ()).unwrap().unwrap();
+    assert_eq!("1\n2\n3", cursor_to_string(cursor));
+
+    let mut prepared = conn.prepare(&query).unwrap();
+    prepared.set_max_rows(3).unwrap();
+    let cursor = prepared.execute(()).unwrap().unwrap();
+    assert_eq!("1\n2\n3", cursor_to_string(cursor));
+
+    // `0` means unlimited
+    prepared.set_max_rows(0).unwrap();
+    let cursor = prepared.execute(()).unwrap().unwrap();
+    assert_eq!("1\n2\n3\n4\n5", cursor_to_string(cursor));
+}
+
+/// The row limit is also enforced on the client side, both for row by row and for bulk fetches.
+/// The last row set is truncated, if it would exceed the limit.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn max_rows_client_side(profile: &Profile) {
+    let table_name = "MaxRowsClientSide";
+    let conn = profile.setup_empty_table(table_name, &["INT"]).unwrap();
+    let insert_sql = format!("INSERT INTO {} (a) VALUES (1),(2),(3),(4),(5)", table_name);
+    conn.execute(&insert_sql, ()).unwrap();
+    let query = format!("SELECT a FROM {} ORDER BY id", table_name);
+    let mut prepared = conn.prepare(&query).unwrap();
+    prepared.set_max_rows(3).unwrap();
+
+    let mut cursor = prepared.execute(()).unwrap().unwrap();
+    let mut num_rows = 0;
+    while cursor.next_row().unwrap().is_some() {
+        num_rows += 1;
+    }
+    assert_eq!(3, num_rows);
+    drop(cursor);
+
+    let cursor = prepared.execute(()).unwrap().unwrap();
+    let buffer = TextRowSet::for_cursor(2, &cursor, None).unwrap();
+    let mut row_set_cursor = cursor.bind_buffer(buffer).unwrap();
+    assert_eq!(2, row_set_cursor.fetch().unwrap().unwrap().num_rows());
+    assert_eq!(1, row_set_cursor.fetch().unwrap().unwrap().num_rows());
+    assert!(row_set_cursor.fetch().unwrap().is_none());
+}
+
+/// Cap the length of long values returned by the driver. Truncated values report the cap as their
+/// length.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn max_length(profile: &Profile) {
+    let table_name = "MaxLength";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARBINARY(MAX)"])
+        .unwrap();
+    let value = [42u8; 100];
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (?)", table_name),
+        &(&value[..]).into_parameter(),
+    )
+    .unwrap();
+    let query = format!("SELECT a FROM {}", table_name);
+
+    let mut statement = conn.preallocate().unwrap();
+    statement.set_max_length(10).unwrap();
+    let mut cursor = statement.execute(&query, ()).unwrap().unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut actual = VarBinaryArray::<1000>::NULL;
+    row.get_data(1, &mut actual).unwrap();
+    assert_eq!(Some(&value[..10]), actual.as_bytes());
+    assert_eq!(Indicator::Length(10), actual.indicator());
+    drop(cursor);
+
+    // `0` means unlimited
+    statement.set_max_length(0).unwrap();
+    let mut cursor = statement.execute(&query, ()).unwrap().unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    row.get_data(1, &mut actual).unwrap();
+    assert_eq!(Some(&value[..]), actual.as_bytes());
+}
+
+/// Request a scrollable cursor and fetch the last row of a small result set directly.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn scroll_to_last_row(profile: &Profile) {
+    let table_name = "ScrollToLastRow";
+    let conn = profile.setup_empty_table(table_name, &["INT"]).unwrap();
+    let insert_sql = format!("INSERT INTO {} (a) VALUES (1),(2),(3)", table_name);
+    conn.execute(&insert_sql, ()).unwrap();
+
+    let mut statement = conn.preallocate().unwrap();
+    statement.set_scrollable(true).unwrap();
+    let query = format!("SELECT a FROM {} ORDER BY id", table_name);
+    let mut cursor = statement.execute(&query, ()).unwrap().unwrap();
+
+    let mut actual = Nullable::<i32>::null();
+    let mut row = cursor.scroll_to(ScrollPosition::Last).unwrap().unwrap();
+    row.get_data(1, &mut actual).unwrap();
+    assert_eq!(Some(3), actual.into_opt());
+    drop(row);
+
+    let mut row = cursor
+        .scroll_to(ScrollPosition::Absolute(1))
+        .unwrap()
+        .unwrap();
+    row.get_data(1, &mut actual).unwrap();
+    assert_eq!(Some(1), actual.into_opt());
+    drop(row);
+
+    assert!(cursor
+        .scroll_to(ScrollPosition::Absolute(4))
+        .unwrap()
+        .is_none());
+}
+
 /// Reuse a preallocated handle, two times in a row.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
@@ -1219,6 +2697,31 @@ fn preallocated(profile: &Profile) {
     }
 }
 
+/// Execute many tiny queries through one preallocated handle, resetting it after each one.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn preallocated_execute_and_reset(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+    let mut prealloc = conn.preallocate().unwrap();
+
+    let mut sum = 0;
+    for i in 0..10_000 {
+        prealloc
+            .execute_and_reset("SELECT ?", &i, |cursor| {
+                let mut cursor = cursor.unwrap();
+                let mut row = cursor.next_row()?.unwrap();
+                let mut value = Nullable::<i32>::null();
+                row.get_data(1, &mut value)?;
+                sum += value.into_opt().unwrap() as i64;
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    assert_eq!((0..10_000i64).sum::<i64>(), sum);
+}
+
 /// Reuse a preallocated handle. Verify that columns bound to the statement during a previous
 /// execution are not dereferenced during a second one.
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -1389,6 +2892,36 @@ fn wchar_as_char() {
     assert_eq!("A\nÜ", output);
 }
 
+/// Multibyte characters at the maximum length of the column must be fetched without truncation by
+/// the text buffer sized with `for_cursor`.
+#[test]
+#[cfg(not(target_os = "windows"))] // Windows does not use UTF-8 locale by default
+fn multibyte_text_at_max_length_is_not_truncated() {
+    let conn = ENV
+        .connect_with_connection_string(MSSQL.connection_string)
+        .unwrap();
+    let table_name = "MultibyteTextAtMaxLength";
+    setup_empty_table(&conn, MSSQL.index_type, table_name, &["NVARCHAR(10)"]).unwrap();
+
+    // Ten characters, each of which takes three bytes in UTF-8.
+    let text = "€€€€€€€€€€";
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (N'{}');", table_name, text),
+        (),
+    )
+    .unwrap();
+
+    let sql = format!("SELECT a FROM {} ORDER BY id;", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let row_set_buffer = TextRowSet::for_cursor(1, &cursor, None).unwrap();
+    let mut row_set_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = row_set_cursor.fetch().unwrap().unwrap();
+
+    assert_eq!(Indicator::Length(text.len()), batch.indicator_at(0, 0));
+    assert!(text.len() <= batch.max_len(0));
+    assert_eq!(text, batch.at_as_str(0, 0).unwrap().unwrap());
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -1450,6 +2983,31 @@ fn column_names_iterator(profile: &Profile) {
     assert_eq!(&["a", "b"], names.as_slice());
 }
 
+/// Look up the one based index of a present and an absent column by name.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn column_index_by_name(profile: &Profile) {
+    let table_name = "ColumnIndexByName";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(13)"])
+        .unwrap();
+    let sql = format!("SELECT a, b AS Title FROM {};", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    assert_eq!(Some(2), cursor.column_index_by_name("title").unwrap());
+    assert_eq!(Some(1), cursor.column_index_by_name("A").unwrap());
+    assert_eq!(None, cursor.column_index_by_name("c").unwrap());
+    assert_eq!(
+        Some(2),
+        cursor.column_index_by_name_case_sensitive("Title").unwrap()
+    );
+    assert_eq!(
+        None,
+        cursor.column_index_by_name_case_sensitive("title").unwrap()
+    );
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -1477,9 +3035,30 @@ fn metadata_from_prepared_insert_query(profile: &Profile) {
     let conn = profile
         .setup_empty_table(table_name, &["INTEGER", "VARCHAR(13)"])
         .unwrap();
-    let sql = format!("INSERT INTO {} (a, b) VALUES (42, 'Hello');", table_name);
+    let sql = format!("INSERT INTO {} (a, b) VALUES (42, 'Hello');", table_name);
+    let prepared = conn.prepare(&sql).unwrap();
+    assert_eq!(0, prepared.num_result_cols().unwrap());
+}
+
+/// The output schema of a prepared query is known before executing it.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn describe_prepared_query_before_execution(profile: &Profile) {
+    let table_name = "DescribePreparedQueryBeforeExecution";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(13)"])
+        .unwrap();
+    let sql = format!("SELECT a, b FROM {};", table_name);
     let prepared = conn.prepare(&sql).unwrap();
-    assert_eq!(0, prepared.num_result_cols().unwrap());
+
+    assert_eq!(2, prepared.num_result_cols().unwrap());
+    let actual = prepared.describe_all().unwrap();
+    let names: Vec<_> = actual
+        .iter()
+        .map(|desc| desc.name_to_string().unwrap())
+        .collect();
+    assert_eq!(["a", "b"], names.as_slice());
+    assert_eq!(DataType::Integer, actual[0].data_type);
+    assert_eq!(DataType::Varchar { length: 13 }, actual[1].data_type);
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -1644,6 +3223,139 @@ fn parameter_option_bytes(profile: &Profile) {
     assert_eq!(expected, actual);
 }
 
+/// Bind one text column narrow and another one wide, yet read both as UTF-8.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn mixed_narrow_and_wide_text_columns(profile: &Profile) {
+    let table_name = "MixedNarrowAndWideTextColumns";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(10)", "NVARCHAR(10)"])
+        .unwrap();
+    let insert = format!(
+        "INSERT INTO {} (a, b) VALUES ('Hello', N'Grüße'), (NULL, NULL);",
+        table_name
+    );
+    conn.execute(&insert, ()).unwrap();
+
+    let sql = format!("SELECT a, b FROM {} ORDER BY id", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let descs = [
+        BufferDescription {
+            kind: BufferKind::Text { max_str_len: 10 },
+            nullable: true,
+        },
+        BufferDescription {
+            kind: BufferKind::WText { max_str_len: 10 },
+            nullable: true,
+        },
+    ];
+    let buffer = buffer_from_description(10, descs.iter().copied());
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    assert!(matches!(batch.column(0), AnyColumnView::Text(_)));
+    assert!(matches!(batch.column(1), AnyColumnView::WText(_)));
+    let to_strings = |col_index| -> Vec<Option<String>> {
+        batch
+            .column(col_index)
+            .as_utf8()
+            .unwrap()
+            .map(|text| text.map(|text| text.into_owned()))
+            .collect()
+    };
+    assert_eq!([Some("Hello".to_string()), None], to_strings(0).as_slice());
+    assert_eq!([Some("Grüße".to_string()), None], to_strings(1).as_slice());
+}
+
+/// Bind a list of values to an `IN` clause, by expanding its parameter marker.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn in_list_with_expanded_parameter_marker(profile: &Profile) {
+    let table_name = "InListWithExpandedParameterMarker";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+    let insert = format!(
+        "INSERT INTO {} (a) VALUES (1), (2), (3), (4), (5);",
+        table_name
+    );
+    conn.execute(&insert, ()).unwrap();
+
+    let values = [1, 3, 5];
+    let sql = format!(
+        "SELECT a FROM {} WHERE '?' <> 'x?' AND a IN (?) ORDER BY id",
+        table_name
+    );
+    let sql = expand_parameter_marker(&sql, 0, values.len()).unwrap();
+    let cursor = conn.execute(&sql, &values[..]).unwrap().unwrap();
+    let actual = cursor_to_string(cursor);
+
+    assert_eq!("1\n3\n5", actual);
+}
+
+/// Map every row of a block cursor into a `Vec` using `fetch_into`.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn fetch_into_vec_of_tuples(profile: &Profile) {
+    let table_name = "FetchIntoVecOfTuples";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(20)"])
+        .unwrap();
+    let insert = format!(
+        "INSERT INTO {} (a, b) VALUES (1, 'one'), (2, 'two'), (3, 'three');",
+        table_name
+    );
+    conn.execute(&insert, ()).unwrap();
+
+    let sql = format!("SELECT a, b FROM {} ORDER BY id", table_name);
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    // Batch size smaller than the number of rows, so more than one row set is fetched.
+    let buffer = TextRowSet::for_cursor(2, &cursor, None).unwrap();
+    let mut row_set_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut actual: Vec<(i32, String)> = Vec::new();
+    row_set_cursor
+        .fetch_into(&mut actual, |batch, row_index| {
+            let a = batch.at_as_str(0, row_index).unwrap().unwrap();
+            let b = batch.at_as_str(1, row_index).unwrap().unwrap();
+            (a.parse().unwrap(), b.to_owned())
+        })
+        .unwrap();
+
+    let expected = [
+        (1, "one".to_string()),
+        (2, "two".to_string()),
+        (3, "three".to_string()),
+    ];
+    assert_eq!(&expected[..], actual.as_slice());
+}
+
+/// An empty slice is inserted as an empty binary value, not as `NULL`.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn insert_and_fetch_byte_slices(profile: &Profile) {
+    let table_name = "InsertAndFetchByteSlices";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARBINARY(10)"])
+        .unwrap();
+    let sql = format!("INSERT INTO {} (a) VALUES (?);", table_name);
+    let mut prepared = conn.prepare(&sql).unwrap();
+    prepared
+        .execute(&(&[1u8, 2, 3][..]).into_parameter())
+        .unwrap();
+    let empty: &[u8] = &[];
+    prepared.execute(&empty.into_parameter()).unwrap();
+    prepared.execute(&None::<&[u8]>.into_parameter()).unwrap();
+
+    let sql = format!("SELECT a FROM {} ORDER BY id", table_name);
+    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let mut actual = Vec::new();
+    let mut values = Vec::new();
+    while let Some(mut row) = cursor.next_row().unwrap() {
+        let is_not_null = row.get_binary(1, &mut actual).unwrap();
+        values.push(is_not_null.then(|| actual.clone()));
+    }
+    assert_eq!(vec![Some(vec![1, 2, 3]), Some(Vec::new()), None], values);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -1817,6 +3529,42 @@ fn output_parameter(profile: &Profile) {
     assert_eq!(Some(7 + 5), param.into_opt());
 }
 
+/// Bind input and output parameters within the same tuple.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn mixed_input_and_output_parameters(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+    conn.execute(
+        r#"
+        IF EXISTS (SELECT name FROM sysobjects WHERE name = 'TestMixedParams')
+        DROP PROCEDURE TestMixedParams
+        "#,
+        (),
+    )
+    .unwrap();
+
+    conn.execute(
+        r#"CREATE PROCEDURE TestMixedParams
+        @InParm int,
+        @OutParm int OUTPUT
+        AS
+        SELECT @OutParm = @InParm * 2
+        "#,
+        (),
+    )
+    .unwrap();
+
+    let in_val = 21;
+    let mut out_param = Nullable::<i32>::null();
+
+    conn.execute(
+        "{call TestMixedParams(?, ?)}",
+        (&in_val, Out(&mut out_param)),
+    )
+    .unwrap();
+
+    assert_eq!(Some(42), out_param.into_opt());
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2347,6 +4095,91 @@ fn arbitrary_input_parameters(profile: &Profile) {
     assert_eq!("Hello, World!,42", actual)
 }
 
+/// Pass ownership of heterogeneous parameters assembled at runtime, including a `NULL`.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn vec_of_boxed_input_parameters(profile: &Profile) {
+    let table_name = "VecOfBoxedInputParameters";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(20)", "INTEGER"])
+        .unwrap();
+
+    let insert_statement = format!("INSERT INTO {} (a, b, c) VALUES (?, ?, ?);", table_name);
+    let parameters: Vec<Box<dyn InputParameter>> = vec![
+        Box::new(42),
+        Box::new("Hello".into_parameter()),
+        Box::new(None::<i32>.into_parameter()),
+    ];
+    conn.execute(&insert_statement, parameters).unwrap();
+
+    let actual = table_to_string(&conn, table_name, &["a", "b", "c"]);
+    assert_eq!("42,Hello,NULL", actual)
+}
+
+/// Bind a newtype implementing `AsSqlParameter` directly, within a tuple and as an `Option`.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn domain_type_as_sql_parameter(profile: &Profile) {
+    struct Cents(i64);
+
+    impl AsSqlParameter for Cents {
+        type Parameter = i64;
+
+        fn as_sql_parameter(&self) -> &i64 {
+            &self.0
+        }
+    }
+
+    let table_name = "DomainTypeAsSqlParameter";
+    let conn = profile
+        .setup_empty_table(table_name, &["BIGINT", "BIGINT"])
+        .unwrap();
+
+    let insert_statement = format!("INSERT INTO {} (a, b) VALUES (?, ?);", table_name);
+    conn.execute(
+        &insert_statement,
+        (&Cents(1999), &Some(Cents(500)).into_parameter()),
+    )
+    .unwrap();
+    conn.execute(
+        &insert_statement,
+        (&Cents(42), &None::<Cents>.into_parameter()),
+    )
+    .unwrap();
+
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    assert_eq!("1999,500\n42,NULL", actual)
+}
+
+/// Find the driver used to connect to Microsoft SQL Server in the tests and inspect its attributes.
+#[test]
+fn drivers_filtered() {
+    let drivers = ENV
+        .drivers_filtered(|driver| driver.description.contains("SQL Server"))
+        .unwrap();
+
+    assert!(!drivers.is_empty());
+    assert!(drivers
+        .iter()
+        .all(|driver| driver.description.contains("SQL Server")));
+    assert_eq!(
+        drivers.len(),
+        ENV.drivers()
+            .unwrap()
+            .iter()
+            .filter(|driver| driver.description.contains("SQL Server"))
+            .count()
+    );
+    // Attribute keys are compared case insensitive.
+    let driver = &drivers[0];
+    assert_eq!(
+        driver.attributes.get("Description").map(String::as_str),
+        driver.attribute("description")
+    );
+}
+
 /// Ensures access to driver and data source info is synchronized correctly when multiple threads
 /// attempt to query it at the same time. First, we query the list of the known drivers and data
 /// sources on the main thread. Then we spawn multiple threads that attempt to query these lists in
@@ -2425,6 +4258,51 @@ fn send_long_data_binary_vec(profile: &Profile) {
     assert_eq!(input, output);
 }
 
+/// Streaming a blob which provides fewer or more bytes than declared fails with a descriptive error.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn blob_length_mismatch(profile: &Profile) {
+    let table_name = "BlobLengthMismatch";
+    let conn = profile
+        .setup_empty_table(table_name, &[profile.blob_type])
+        .unwrap();
+    let insert = format!("INSERT INTO {} (a) VALUES (?)", table_name);
+
+    // Declared length matches
+    let mut blob = SizedBlob::new(5, b"12345");
+    conn.execute(&insert, &mut blob.as_blob_param()).unwrap();
+
+    // Fewer bytes than declared
+    let mut blob = SizedBlob::new(10, b"12345");
+    let result = conn.execute(&insert, &mut blob.as_blob_param());
+    assert!(matches!(
+        result,
+        Err(Error::BlobLengthMismatch {
+            declared: 10,
+            actual: 5
+        })
+    ));
+
+    // More bytes than declared
+    let mut blob = SizedBlob::new(3, b"12345");
+    let result = conn.execute(&insert, &mut blob.as_blob_param());
+    assert!(matches!(
+        result,
+        Err(Error::BlobLengthMismatch {
+            declared: 3,
+            actual: 5
+        })
+    ));
+
+    // Only the blob with the correct length has been inserted
+    let mut cursor = conn
+        .execute(&format!("SELECT COUNT(*) FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+    let count: Option<i32> = cursor.fetch_one_scalar().unwrap();
+    assert_eq!(Some(1), count);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2512,6 +4390,123 @@ fn send_long_data_binary_file(profile: &Profile) {
     assert_eq!(input, output);
 }
 
+/// Stream a value from a reader, which does not implement `BufRead`, in many small batches.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn send_long_data_binary_from_read(profile: &Profile) {
+    let table_name = "SendLongDataBinaryFromRead";
+    let conn = profile
+        .setup_empty_table(table_name, &[profile.blob_type])
+        .unwrap();
+
+    let len = 12000;
+    // `Take<Repeat>` only implements `Read`.
+    let read = io::repeat(42).take(len as u64);
+    let mut blob = BlobRead::from_read(read, 1000, 14000);
+
+    let insert = format!("INSERT INTO {} (a) VALUES (?)", table_name);
+    conn.execute(&insert, &mut blob.as_blob_param()).unwrap();
+
+    let select = format!("SELECT a FROM {}", table_name);
+    let mut result = conn.execute(&select, ()).unwrap().unwrap();
+    let mut row = result.next_row().unwrap().unwrap();
+    let mut output = Vec::new();
+    row.get_binary(1, &mut output).unwrap();
+
+    assert_eq!(vec![42u8; len], output);
+}
+
+/// A reader without any data inserts an empty value, not `NULL`.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn send_long_data_binary_from_empty_read(profile: &Profile) {
+    let table_name = "SendLongDataBinaryFromEmptyRead";
+    let conn = profile
+        .setup_empty_table(table_name, &[profile.blob_type])
+        .unwrap();
+    let mut blob = BlobRead::from_read(io::empty(), 1000, 14000);
+
+    let insert = format!("INSERT INTO {} (a) VALUES (?)", table_name);
+    conn.execute(&insert, &mut blob.as_blob_param()).unwrap();
+
+    let select = format!("SELECT a FROM {}", table_name);
+    let mut result = conn.execute(&select, ()).unwrap().unwrap();
+    let mut row = result.next_row().unwrap().unwrap();
+    let mut output = vec![42u8];
+    let not_null = row.get_binary(1, &mut output).unwrap();
+
+    assert!(not_null);
+    assert!(output.is_empty());
+}
+
+/// Errors of the reader are reported, rather than inserting incomplete data.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn send_long_data_binary_from_failing_read(profile: &Profile) {
+    struct FailingRead;
+
+    impl Read for FailingRead {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "Connection reset"))
+        }
+    }
+
+    let table_name = "SendLongDataBinaryFromFailingRead";
+    let conn = profile
+        .setup_empty_table(table_name, &[profile.blob_type])
+        .unwrap();
+    let mut blob = BlobRead::from_read(FailingRead, 1000, 14000);
+
+    let insert = format!("INSERT INTO {} (a) VALUES (?)", table_name);
+    let result = conn.execute(&insert, &mut blob.as_blob_param());
+
+    assert!(matches!(result, Err(Error::FailedReadingInput(_))));
+}
+
+/// Copy a large binary value into a writer, without holding all of it in memory at once.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn stream_binary_through_buf_reader(profile: &Profile) {
+    let table_name = "StreamBinaryThroughBufReader";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARBINARY(MAX)"])
+        .unwrap();
+
+    // Multiple megabytes, so the value is fetched in many chunks.
+    let input: Vec<_> = (0..3_000_000).map(|i| (i % 251) as u8).collect();
+    let mut blob = BlobSlice::from_byte_slice(&input);
+    let insert = format!("INSERT INTO {} (a) VALUES (?)", table_name);
+    conn.execute(&insert, &mut blob.as_blob_param()).unwrap();
+
+    let select = format!("SELECT a FROM {}", table_name);
+    let mut cursor = conn.execute(&select, ()).unwrap().unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut reader = BufReader::new(row.binary_reader(1));
+    let mut output = Vec::new();
+    io::copy(&mut reader, &mut output).unwrap();
+
+    assert_eq!(input.len(), output.len());
+    assert!(input == output);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn binary_reader_reads_null_as_empty(profile: &Profile) {
+    let table_name = "BinaryReaderReadsNullAsEmpty";
+    let conn = profile
+        .setup_empty_table(table_name, &[profile.blob_type])
+        .unwrap();
+    let insert = format!("INSERT INTO {} (a) VALUES (NULL)", table_name);
+    conn.execute(&insert, ()).unwrap();
+
+    let select = format!("SELECT a FROM {}", table_name);
+    let mut cursor = conn.execute(&select, ()).unwrap().unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut output = Vec::new();
+    io::copy(&mut row.binary_reader(1), &mut output).unwrap();
+
+    assert!(output.is_empty());
+}
+
 /// Demonstrate how to strip abstractions and access raw functionality as exposed by `odbc-sys`.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
@@ -2536,12 +4531,53 @@ fn escape_hatch(profile: &Profile) {
         assert_eq!(ret, sys::SqlReturn::SUCCESS);
     }
 
-    // If we use `.into_sys` we need to drop the handle manually
-    let hstmt = statement.into_sys();
-    unsafe {
-        let ret = sys::SQLFreeHandle(sys::HandleType::Stmt, hstmt as sys::Handle);
-        assert_eq!(ret, sys::SqlReturn::SUCCESS);
-    }
+    // If we use `.into_sys` we need to drop the handle manually
+    let hstmt = statement.into_sys();
+    unsafe {
+        let ret = sys::SQLFreeHandle(sys::HandleType::Stmt, hstmt as sys::Handle);
+        assert_eq!(ret, sys::SqlReturn::SUCCESS);
+    }
+}
+
+/// Update a row of the result set in place using `SQLSetPos`.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn update_row_via_set_pos(profile: &Profile) {
+    let table_name = "UpdateRowViaSetPos";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+    conn.execute(&format!("INSERT INTO {} (a) VALUES (1)", table_name), ())
+        .unwrap();
+
+    let mut preallocated = conn.preallocate().unwrap();
+    preallocated
+        .set_cursor_type(CursorType::KeysetDriven)
+        .unwrap();
+    preallocated.set_concurrency(Concurrency::Lock).unwrap();
+    let mut statement = preallocated.into_statement();
+
+    let mut value = Nullable::<i32>::null();
+    unsafe {
+        let select = U16String::from_str(&format!("SELECT id, a FROM {}", table_name));
+        statement
+            .exec_direct(&select)
+            .into_result(&statement)
+            .unwrap();
+        statement
+            .bind_col(2, &mut value)
+            .into_result(&statement)
+            .unwrap();
+        statement.fetch().unwrap().into_result(&statement).unwrap();
+        assert_eq!(Some(1), value.into_opt());
+
+        value = Nullable::new(42);
+        statement
+            .set_pos(1, sys::Operation::Update, sys::Lock::NoChange)
+            .into_result(&statement)
+            .unwrap();
+        statement.close_cursor().into_result(&statement).unwrap();
+    }
+
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("42", actual);
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -2561,6 +4597,44 @@ fn varchar_null(profile: &Profile) {
     assert_eq!("NULL", actual)
 }
 
+/// Insert typed NULLs into columns of types other than text.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn typed_null(profile: &Profile) {
+    let table_name = "TypedNull";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "DATETIME"])
+        .unwrap();
+
+    let insert = format!("INSERT INTO {} (a, b) VALUES (?, ?)", table_name);
+    conn.execute(
+        &insert,
+        (
+            &TypedNull::new(DataType::Integer),
+            &TypedNull::new(DataType::Timestamp { precision: 3 }),
+        ),
+    )
+    .unwrap();
+
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    assert_eq!("NULL,NULL", actual)
+}
+
+/// The data source learns the type of the NULL parameter. Here it is reflected in the type of the
+/// result set column.
+#[test]
+fn typed_null_determines_parameter_type() {
+    let conn = MSSQL.connection().unwrap();
+
+    let cursor = conn
+        .execute("SELECT ? AS a", &TypedNull::new(DataType::Integer))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(DataType::Integer, cursor.col_data_type(1).unwrap());
+}
+
 /// Connect to database with connection string, and check the output connection string with
 /// attributes complemented by the driver.
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -2583,6 +4657,46 @@ fn get_full_connection_string(profile: &Profile) {
     assert!(profile.connection_string.len() <= completed_connection_string.len());
 }
 
+/// Completing the connection string must not require a prompt, if the information provided is
+/// already sufficient. This is also supported on non windows platforms.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn complete_required_connection_string(profile: &Profile) {
+    let mut completed_connection_string = OutputStringBuffer::with_buffer_size(1023);
+    ENV.driver_connect(
+        profile.connection_string,
+        Some(&mut completed_connection_string),
+        odbc_api::DriverCompleteOption::CompleteRequired,
+    )
+    .unwrap();
+
+    assert!(!completed_connection_string.is_truncated());
+
+    let completed_connection_string = completed_connection_string.to_utf8();
+
+    // The driver adds attributes not specified in the input.
+    assert!(profile.connection_string.len() < completed_connection_string.len());
+}
+
+/// The driver completes a partial connection string with the attributes it defaulted, without
+/// prompting. The completed string also contains the password, which is why applications must not
+/// log it as is.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn partial_connection_string_is_completed_without_prompt(profile: &Profile) {
+    let mut completed_connection_string = OutputStringBuffer::with_buffer_size(1023);
+    ENV.driver_connect(
+        profile.connection_string,
+        Some(&mut completed_connection_string),
+        odbc_api::DriverCompleteOption::NoPrompt,
+    )
+    .unwrap();
+
+    assert!(!completed_connection_string.is_truncated());
+    let completed_connection_string = completed_connection_string.to_utf8();
+    assert!(profile.connection_string.len() < completed_connection_string.len());
+    assert!(completed_connection_string.contains("PWD="));
+}
+
 /// We must be able to detect truncation in case we provide a buffer too small to hold the output
 /// connection string
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -2645,6 +4759,18 @@ fn name_limits(
     );
 }
 
+/// Escape sequences are translated into the native dialect of the data source.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn native_sql(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+    let query = "SELECT {fn UCASE('hello')}";
+
+    let native = conn.native_sql(query).unwrap();
+
+    assert_ne!(query, native);
+    assert!(!native.contains("{fn"));
+}
+
 // Check the current catalog being used by the connection.
 #[test_case(MSSQL, "master"; "Microsoft SQL Server")]
 #[test_case(MARIADB, "test_db"; "Maria DB")]
@@ -2655,6 +4781,19 @@ fn current_catalog(profile: &Profile, expected_catalog: &str) {
     assert_eq!(conn.current_catalog().unwrap(), expected_catalog);
 }
 
+// Switch the catalog used by the connection and check the getter reflects the change.
+#[test_case(MSSQL, "tempdb"; "Microsoft SQL Server")]
+fn set_current_catalog(profile: &Profile, other_catalog: &str) {
+    let conn = profile.connection().unwrap();
+    let original = conn.current_catalog().unwrap();
+
+    conn.set_current_catalog(other_catalog).unwrap();
+    assert_eq!(other_catalog, conn.current_catalog().unwrap());
+
+    conn.set_current_catalog(&original).unwrap();
+    assert_eq!(original, conn.current_catalog().unwrap());
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2797,6 +4936,141 @@ fn list_tables_preallocated(profile: &Profile, expected: &str) {
     assert_eq!(expected, actual);
 }
 
+/// Requesting type information for `SQL_INTEGER` yields at least one row naming an integer type.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn type_info_integer(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+
+    let mut cursor = conn.type_info(SqlDataType::INTEGER).unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut type_name = Vec::new();
+    row.get_text(1, &mut type_name).unwrap();
+    let mut data_type = Nullable::<i16>::null();
+    row.get_data(2, &mut data_type).unwrap();
+
+    let type_name = String::from_utf8(type_name).unwrap().to_lowercase();
+    assert!(type_name.contains("int"), "{}", type_name);
+    assert_eq!(Some(SqlDataType::INTEGER.0), data_type.into_opt());
+}
+
+/// Requesting type information for all types lists more than a single type.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn type_info_all_types(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+    let mut preallocated = conn.preallocate().unwrap();
+
+    let mut cursor = preallocated.type_info(SqlDataType::UNKNOWN_TYPE).unwrap();
+    let mut num_types = 0;
+    while cursor.next_row().unwrap().is_some() {
+        num_types += 1;
+    }
+
+    assert!(num_types > 1);
+}
+
+/// Parsed table information contains the table created beforehand.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn list_tables_parsed(profile: &Profile) {
+    let table_name = "ListTablesParsed";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+
+    let tables = conn
+        .list_tables(None, None, Some(table_name), None)
+        .unwrap();
+
+    assert_eq!(1, tables.len());
+    assert_eq!(table_name, tables[0].name);
+    assert_eq!("TABLE", tables[0].table_type);
+}
+
+/// Parsed column information lists the columns of the table in order.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn list_columns_parsed(profile: &Profile) {
+    let table_name = "ListColumnsParsed";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(10) NOT NULL"])
+        .unwrap();
+
+    let columns = conn.list_columns("", "", table_name, "%").unwrap();
+
+    let names: Vec<_> = columns.iter().map(|c| c.column_name.as_str()).collect();
+    assert_eq!(vec!["id", "a", "b"], names);
+    assert_eq!(table_name, columns[1].table_name);
+    assert_eq!(sys::SqlDataType::INTEGER, columns[1].data_type);
+    assert_eq!(2, columns[1].ordinal_position);
+    assert_eq!(Some(10), columns[2].column_size);
+    assert_eq!(Nullability::NoNulls, columns[2].nullability);
+}
+
+/// Parsed column information translates into the data types used to create the table.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn list_columns_data_types(profile: &Profile) {
+    let table_name = "ListColumnsDataTypes";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER NOT NULL", "VARCHAR(13)"])
+        .unwrap();
+
+    let columns = conn.list_columns("", "", table_name, "%").unwrap();
+
+    assert_eq!(DataType::Integer, columns[1].to_data_type());
+    assert_eq!(Nullability::NoNulls, columns[1].nullability);
+    assert_eq!(DataType::Varchar { length: 13 }, columns[2].to_data_type());
+    assert_eq!(Nullability::Nullable, columns[2].nullability);
+}
+
+/// The primary key index shows up in the statistics of a table.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn statistics_list_primary_key(profile: &Profile) {
+    let table_name = "StatisticsListPrimaryKey";
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {}", table_name), ())
+        .unwrap();
+    conn.execute(
+        &format!(
+            "CREATE TABLE {} (id INTEGER NOT NULL PRIMARY KEY, a INTEGER)",
+            table_name
+        ),
+        (),
+    )
+    .unwrap();
+
+    let cursor = conn.statistics(None, None, table_name, true, true).unwrap();
+    let column_name_index = cursor
+        .column_names()
+        .unwrap()
+        .map(Result::unwrap)
+        .position(|name| name.eq_ignore_ascii_case("COLUMN_NAME"))
+        .unwrap();
+    let mut buffer = TextRowSet::for_cursor(10, &cursor, Some(255)).unwrap();
+    let mut row_set_cursor = cursor.bind_buffer(&mut buffer).unwrap();
+    let mut indexed_columns = Vec::new();
+    while let Some(batch) = row_set_cursor.fetch().unwrap() {
+        for row_index in 0..batch.num_rows() {
+            // Rows describing the table itself, rather than an index, have no column name.
+            if let Some(name) = batch.at_as_str(column_name_index, row_index).unwrap() {
+                indexed_columns.push(name.to_string());
+            }
+        }
+    }
+
+    assert_eq!(vec!["id".to_string()], indexed_columns);
+}
+
 /// List columns for various data sources
 #[test_case(MSSQL, "master,dbo,ListColumns,a,4,int,10,4,0,10,1,NULL,NULL,4,NULL,NULL,2,YES,0,0,0,0,NULL,NULL,NULL,NULL,NULL,NULL,38"; "Microsoft SQL Server")]
 #[test_case(MARIADB, "test_db,NULL,ListColumns,a,4,INT,10,4,0,10,1,,NULL,4,NULL,2,2,YES"; "Maria DB")]
@@ -2826,6 +5100,41 @@ fn list_columns_preallocated(profile: &Profile, expected: &str) {
     assert_eq!(expected, actual);
 }
 
+/// Query the columns of several tables, reusing the same preallocated statement handle.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn list_columns_of_many_tables_preallocated(profile: &Profile) {
+    let tables = [
+        ("ListColumnsOfManyTablesA", &["INT"][..]),
+        ("ListColumnsOfManyTablesB", &["INT", "VARCHAR(10)"][..]),
+        (
+            "ListColumnsOfManyTablesC",
+            &["INT", "VARCHAR(10)", "INT"][..],
+        ),
+    ];
+    let conn = profile.connection().unwrap();
+    for (table_name, column_types) in tables {
+        setup_empty_table(&conn, profile.index_type, table_name, column_types).unwrap();
+    }
+    let mut preallocated = conn.preallocate().unwrap();
+
+    for (table_name, column_types) in tables {
+        let cursor = preallocated.columns("", "", table_name, "%").unwrap();
+        let num_rows = cursor_to_string(cursor).lines().count();
+        assert_eq!(column_types.len() + 1, num_rows);
+
+        let columns = preallocated.list_columns("", "", table_name, "%").unwrap();
+        let names: Vec<_> = columns
+            .iter()
+            .map(|column| column.column_name.as_str())
+            .collect();
+        let expected: Vec<_> = ["id", "a", "b", "c"][..column_types.len() + 1].to_vec();
+        assert_eq!(expected, names);
+        assert!(columns.iter().all(|column| column.table_name == table_name));
+    }
+}
+
 /// Some drivers seem to have trouble binding buffers beyond `u16::MAX`. This has been seen failing
 /// in the wild with SAP anywhere, but that ODBC driver is not part of this test suite.
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -2914,6 +5223,64 @@ fn modifying_bound_param_buffer_on_heap(profile: &Profile) {
     assert_eq!("2", cursor_to_string(cursor));
 }
 
+/// Bind a columnar buffer once to a prepared insert statement and refill it for several batches of
+/// different sizes. The text column is reallocated to hold longer values in between.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn prebound_columnar_buffer_refilled_for_each_batch(profile: &Profile) {
+    let table_name = "PreboundColumnarBufferRefilledForEachBatch";
+    let conn = profile
+        .setup_empty_table(table_name, &["INTEGER", "VARCHAR(20)"])
+        .unwrap();
+    let prepared = conn
+        .prepare(&format!("INSERT INTO {} (a, b) VALUES (?, ?)", table_name))
+        .unwrap();
+    let mut buffer = buffer_from_description(
+        3,
+        [
+            BufferDescription {
+                kind: BufferKind::I32,
+                nullable: false,
+            },
+            BufferDescription {
+                kind: BufferKind::Text { max_str_len: 1 },
+                nullable: true,
+            },
+        ]
+        .into_iter(),
+    );
+    let mut prebound = prepared.bind_parameters(&mut buffer).unwrap();
+
+    let batches: [&[(i32, Option<&str>)]; 3] = [
+        &[(1, Some("a")), (2, None), (3, Some("c"))],
+        &[(4, Some("longer text"))],
+        &[(5, Some("e")), (6, Some("f"))],
+    ];
+    for batch in batches {
+        let buffer = prebound.params_mut();
+        buffer.set_num_rows(batch.len());
+        if let AnyColumnViewMut::I32(ids) = buffer.column_mut(0) {
+            for (row_index, &(id, _)) in batch.iter().enumerate() {
+                ids[row_index] = id;
+            }
+        } else {
+            panic!("Expected i32 column");
+        }
+        if let AnyColumnViewMut::Text(mut texts) = buffer.column_mut(1) {
+            for (row_index, &(_, text)) in batch.iter().enumerate() {
+                texts.append(row_index, text.map(str::as_bytes));
+            }
+        } else {
+            panic!("Expected text column");
+        }
+        prebound.execute().unwrap();
+    }
+
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    assert_eq!("1,a\n2,NULL\n3,c\n4,longer text\n5,e\n6,f", actual);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2985,3 +5352,182 @@ fn many_diagnostic_messages() {
 
     // We do not have an explicit assertion, we are just happy if no integer addition overflows.
 }
+
+/// Unsigned integer columns are fetched into unsigned buffers, so values beyond the range of `i64`
+/// do not overflow. Only MariaDB supports unsigned column types.
+#[test_case(MARIADB; "Maria DB")]
+fn fetch_unsigned_big_int(profile: &Profile) {
+    let table_name = "FetchUnsignedBigInt";
+    let conn = profile
+        .setup_empty_table(table_name, &["BIGINT UNSIGNED"])
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {} (a) VALUES (18446744073709551615);",
+            table_name
+        ),
+        (),
+    )
+    .unwrap();
+
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {}", table_name), ())
+        .unwrap()
+        .unwrap();
+    let data_type = cursor.col_data_type(1).unwrap();
+    let is_unsigned = cursor.is_unsigned_column(1).unwrap();
+    let kind = BufferKind::from_data_type_and_sign(data_type, is_unsigned).unwrap();
+    assert_eq!(BufferKind::U64, kind);
+
+    let desc = BufferDescription {
+        nullable: false,
+        kind,
+    };
+    let buffer = buffer_from_description(1, iter::once(desc));
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    assert_eq!(&[u64::MAX][..], u64::as_slice(batch.column(0)).unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn execute_returning_row_count(profile: &Profile) {
+    let table_name = "ExecuteReturningRowCount";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (1), (2), (3), (4);", table_name),
+        (),
+    )
+    .unwrap();
+
+    let num_rows = conn
+        .execute_returning_row_count(&format!("DELETE FROM {} WHERE a < ?;", table_name), &4)
+        .unwrap();
+
+    assert_eq!(Some(3), num_rows);
+}
+
+/// Underscores in table names must be escaped to not match any character.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn list_tables_with_escaped_search_pattern(profile: &Profile) {
+    let table_name = "Escaped_Pattern";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+    // Sibling table which would be matched by the unescaped pattern.
+    profile
+        .setup_empty_table("EscapedXPattern", &["INTEGER"])
+        .unwrap();
+
+    let pattern = escape_search_pattern(table_name);
+    let mut cursor = conn.tables(None, None, Some(&pattern), None).unwrap();
+    let mut table_names = Vec::new();
+    let mut buf = Vec::new();
+    while let Some(mut row) = cursor.next_row().unwrap() {
+        // Third column is `TABLE_NAME`
+        row.get_text(3, &mut buf).unwrap();
+        table_names.push(String::from_utf8(buf.clone()).unwrap());
+    }
+
+    assert_eq!(vec![table_name.to_string()], table_names);
+}
+
+/// A closure failing with a transient connection error is called again with a new connection.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn connect_with_retry_after_transient_error(profile: &Profile) {
+    let policy = RetryPolicy {
+        max_attempts: 2,
+        initial_backoff: Duration::from_millis(1),
+        backoff_multiplier: 1,
+    };
+    let mut num_calls = 0;
+
+    let result = ENV.connect_with_retry(profile.connection_string, policy, |conn| {
+        num_calls += 1;
+        if num_calls == 1 {
+            // Simulate the connection being lost during the first attempt.
+            return Err(Error::Diagnostics {
+                record: Record {
+                    state: State::COMMUNICATION_LINK_FAILURE,
+                    native_error: 0,
+                    message: Vec::new(),
+                },
+                function: "SQLExecDirectW",
+            });
+        }
+        assert!(!conn.is_dead()?);
+        Ok(42)
+    });
+
+    assert_eq!(42, result.unwrap());
+    assert_eq!(2, num_calls);
+}
+
+/// Iterate over several result sets returned by one statement. Unbind the buffer from the first one
+/// in order to move on to the next.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn more_results(profile: &Profile) {
+    let conn = ENV
+        .connect_with_connection_string(profile.connection_string)
+        .unwrap();
+
+    let cursor = conn
+        .execute("SELECT 1 AS a; SELECT 'two' AS b, 3 AS c;", ())
+        .unwrap()
+        .unwrap();
+    let mut buffer = TextRowSet::for_cursor(10, &cursor, None).unwrap();
+    let mut row_set_cursor = cursor.bind_buffer(&mut buffer).unwrap();
+    let batch = row_set_cursor.fetch().unwrap().unwrap();
+    assert_eq!(Some(&b"1"[..]), batch.at(0, 0));
+    let (cursor, _buffer) = row_set_cursor.unbind().unwrap();
+
+    let cursor = cursor.more_results().unwrap().unwrap();
+    let names: Vec<_> = cursor
+        .column_names()
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(&["b", "c"], names.as_slice());
+
+    assert!(cursor.more_results().unwrap().is_none());
+}
+
+/// Blob providing its data in a single batch, declaring an arbitrary length as its size hint.
+struct SizedBlob<'a> {
+    declared: usize,
+    data: Option<&'a [u8]>,
+}
+
+impl<'a> SizedBlob<'a> {
+    fn new(declared: usize, data: &'a [u8]) -> Self {
+        Self {
+            declared,
+            data: Some(data),
+        }
+    }
+}
+
+impl HasDataType for SizedBlob<'_> {
+    fn data_type(&self) -> DataType {
+        DataType::LongVarbinary {
+            length: self.declared,
+        }
+    }
+}
+
+unsafe impl Blob for SizedBlob<'_> {
+    fn c_data_type(&self) -> sys::CDataType {
+        sys::CDataType::Binary
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.declared)
+    }
+
+    fn next_batch(&mut self) -> io::Result<Option<&[u8]>> {
+        Ok(self.data.take())
+    }
+}
@@ -0,0 +1,31 @@
+//! Since the ODBC version is declared on the environment and there may only be one environment per
+//! process, these tests have to run in their own process.
+
+use lazy_static::lazy_static;
+use odbc_api::{Cursor, Environment};
+use odbc_sys::AttrOdbcVersion;
+
+const MSSQL_CONNECTION: &str =
+    "Driver={ODBC Driver 17 for SQL Server};Server=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;";
+
+// Rust by default executes tests in parallel. Yet only one environment is allowed at a time.
+lazy_static! {
+    pub static ref ENV: Environment = {
+        let _ = env_logger::builder().is_test(true).try_init();
+        Environment::new_with_version(AttrOdbcVersion::Odbc3).unwrap()
+    };
+}
+
+#[test]
+fn connect_with_odbc_3() {
+    let conn = ENV
+        .connect_with_connection_string(MSSQL_CONNECTION)
+        .unwrap();
+
+    let mut cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+    let mut row_count = 0;
+    while cursor.next_row().unwrap().is_some() {
+        row_count += 1;
+    }
+    assert_eq!(1, row_count);
+}
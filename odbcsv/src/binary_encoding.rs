@@ -0,0 +1,68 @@
+use anyhow::{bail, Error};
+use std::str::FromStr;
+
+/// Textual representation of binary data within the fields of a csv file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    /// Standard base64 alphabet as described in RFC 4648. Padding is optional.
+    Base64,
+    /// Two hexadecimal digits per byte. Both upper and lower case digits are accepted.
+    Hex,
+}
+
+impl FromStr for BinaryEncoding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "base64" => Ok(BinaryEncoding::Base64),
+            "hex" => Ok(BinaryEncoding::Hex),
+            other => bail!(
+                "Unknown binary encoding '{}'. Supported are 'base64' and 'hex'.",
+                other
+            ),
+        }
+    }
+}
+
+impl BinaryEncoding {
+    /// Decodes the textual representation of a field into raw bytes.
+    pub fn decode(self, text: &[u8]) -> Result<Vec<u8>, Error> {
+        match self {
+            BinaryEncoding::Base64 => Ok(base64::decode_config(text, base64::STANDARD)?),
+            BinaryEncoding::Hex => decode_hex(text),
+        }
+    }
+}
+
+fn decode_hex(text: &[u8]) -> Result<Vec<u8>, Error> {
+    if text.len() % 2 == 1 {
+        bail!("Hex encoded field must have an even number of digits.")
+    }
+    text.chunks_exact(2)
+        .map(|pair| Ok(hex_digit(pair[0])? << 4 | hex_digit(pair[1])?))
+        .collect()
+}
+
+fn hex_digit(digit: u8) -> Result<u8, Error> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        other => bail!("Invalid hex digit '{}'.", other as char),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BinaryEncoding;
+
+    #[test]
+    fn decode_base64_with_and_without_padding() {
+        let base64 = BinaryEncoding::Base64;
+        assert_eq!(b"foo".to_vec(), base64.decode(b"Zm9v").unwrap());
+        assert_eq!(b"a".to_vec(), base64.decode(b"YQ==").unwrap());
+        assert_eq!(b"a".to_vec(), base64.decode(b"YQ").unwrap());
+        assert!(base64.decode(b"Y*==").is_err());
+    }
+}
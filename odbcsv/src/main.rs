@@ -1,13 +1,23 @@
+mod binary_encoding;
+
 use anyhow::{bail, Error};
-use log::info;
+use binary_encoding::BinaryEncoding;
+use log::{info, warn};
 use odbc_api::{
-    buffers::TextRowSet, escape_attribute_value, Connection, Cursor, DriverCompleteOption,
+    buffers::{
+        buffer_from_description, AnyColumnView, AnyColumnViewMut, BufferDescription, BufferKind,
+        MaxStrLen, TextRowSet,
+    },
+    escape_attribute_value,
+    handles::OutputStringBuffer,
+    rewrite_named_placeholders, ColumnDescription, Connection, Cursor, DriverCompleteOption,
     Environment, IntoParameter,
 };
+use serde_json::json;
 use std::{
     fs::{read_to_string, File},
-    io::{stdin, stdout, Read, Write},
-    path::PathBuf,
+    io::{stdin, stdout, BufWriter, Read, Write},
+    path::{Path, PathBuf},
 };
 use structopt::StructOpt;
 
@@ -38,6 +48,17 @@ enum Command {
         #[structopt(flatten)]
         insert_opt: InsertOpt,
     },
+    /// Execute the statements of an SQL script (e.g. a migration) one after another. Statements
+    /// are separated by semicolons (`;`).
+    Execute {
+        #[structopt(flatten)]
+        execute_opt: ExecuteOpt,
+    },
+    /// Count the rows of a table, or of the result set of a query, and print the number.
+    Count {
+        #[structopt(flatten)]
+        count_opt: CountOpt,
+    },
     /// List tables, schemas, views and catalogs provided by the datasource.
     ListTables {
         #[structopt(flatten)]
@@ -92,19 +113,39 @@ struct QueryOpt {
     #[structopt(long, default_value = "5000")]
     batch_size: usize,
     /// Maximum string length in bytes. If omitted no limit is applied and the ODBC driver is taken
-    /// for its word regarding the maximum length of the columns.
+    /// for its word regarding the maximum length of the columns. Columns for which the driver does
+    /// not report any length (e.g. `TEXT`) are fetched with buffers of this length.
     #[structopt(long, short = "m")]
     max_str_len: Option<usize>,
+    /// Fetch text as UTF-16 and convert it to UTF-8, rather than relying on the driver to emit
+    /// UTF-8. This avoids losing characters outside of the system code page (e.g. on Windows), but
+    /// may be slower. Invalid UTF-16 is replaced with `U+FFFD`. If specified `--max-str-len` is
+    /// the maximum string length in UTF-16 code units rather than bytes.
+    #[structopt(long)]
+    wide: bool,
     /// Path to the output csv file the returned values are going to be written to. If omitted the
-    /// csv is going to be printed to standard out.
+    /// csv is going to be printed to standard out. If the path is an existing directory, every
+    /// result set returned by the query (e.g. by a stored procedure) is written to a file of its
+    /// own within it, named `result_0.csv`, `result_1.csv`, ...
     #[structopt(long, short = "o")]
     output: Option<PathBuf>,
+    /// Path to a JSON file, which is going to describe the columns of the output. For each column
+    /// name, data type, column size, decimal digits and nullability are written, as reported by
+    /// the driver. The data type is rendered as it would be written in a `CREATE TABLE` statement
+    /// (e.g. `VARCHAR(255)`), or `null` if it can not be rendered.
+    #[structopt(long)]
+    schema_out: Option<PathBuf>,
     /// Query executed against the ODBC data source. Question marks (`?`) can be used as
     /// placeholders for positional parameters.
     query: String,
     /// For each placeholder question mark (`?`) in the query text one parameter must be passed at
     /// the end of the command line.
     parameters: Vec<String>,
+    /// Value for a named placeholder (`:name`) in the query text, given as `name=value`. Pass it
+    /// once for each distinct name. Every occurrence of the placeholder in the query is bound to
+    /// the same value.
+    #[structopt(long = "param", parse(try_from_str = parse_named_parameter))]
+    named_parameters: Vec<(String, String)>,
 }
 
 #[derive(StructOpt)]
@@ -116,13 +157,28 @@ struct FetchOpt {
     #[structopt(long, default_value = "5000")]
     batch_size: usize,
     /// Maximum string length in bytes. If omitted no limit is applied and the ODBC driver is taken
-    /// for its word regarding the maximum length of the columns.
+    /// for its word regarding the maximum length of the columns. Columns for which the driver does
+    /// not report any length (e.g. `TEXT`) are fetched with buffers of this length.
     #[structopt(long, short = "m")]
     max_str_len: Option<usize>,
+    /// Fetch text as UTF-16 and convert it to UTF-8, rather than relying on the driver to emit
+    /// UTF-8. This avoids losing characters outside of the system code page (e.g. on Windows), but
+    /// may be slower. Invalid UTF-16 is replaced with `U+FFFD`. If specified `--max-str-len` is
+    /// the maximum string length in UTF-16 code units rather than bytes.
+    #[structopt(long)]
+    wide: bool,
     /// Path to the output csv file the returned values are going to be written to. If omitted the
-    /// csv is going to be printed to standard out.
+    /// csv is going to be printed to standard out. If the path is an existing directory, every
+    /// result set returned by the query (e.g. by a stored procedure) is written to a file of its
+    /// own within it, named `result_0.csv`, `result_1.csv`, ...
     #[structopt(long, short = "o")]
     output: Option<PathBuf>,
+    /// Path to a JSON file, which is going to describe the columns of the output. For each column
+    /// name, data type, column size, decimal digits and nullability are written, as reported by
+    /// the driver. The data type is rendered as it would be written in a `CREATE TABLE` statement
+    /// (e.g. `VARCHAR(255)`), or `null` if it can not be rendered.
+    #[structopt(long)]
+    schema_out: Option<PathBuf>,
     /// Query executed against the ODBC data source. Within the SQL text Question marks (`?`) can be
     /// used as placeholders for positional parameters.
     #[structopt(long, short = "q", conflicts_with = "sql_file")]
@@ -135,6 +191,11 @@ struct FetchOpt {
     /// For each placeholder question mark (`?`) in the query text one parameter must be passed at
     /// the end of the command line.
     parameters: Vec<String>,
+    /// Value for a named placeholder (`:name`) in the query text, given as `name=value`. Pass it
+    /// once for each distinct name. Every occurrence of the placeholder in the query is bound to
+    /// the same value.
+    #[structopt(long = "param", parse(try_from_str = parse_named_parameter))]
+    named_parameters: Vec<(String, String)>,
 }
 #[derive(StructOpt)]
 struct InsertOpt {
@@ -148,11 +209,51 @@ struct InsertOpt {
     /// omitted standard input is used.
     #[structopt(long, short = "i")]
     input: Option<PathBuf>,
+    /// Comma separated names of columns holding binary data. Fields of these columns are decoded
+    /// (see `--binary-encoding`) and inserted as raw bytes. All other columns are inserted as text.
+    #[structopt(long, use_delimiter = true)]
+    binary_columns: Vec<String>,
+    /// Encoding of the fields in the binary columns. Either `base64` or `hex`.
+    #[structopt(long, default_value = "base64")]
+    binary_encoding: BinaryEncoding,
+    /// Comma separated mapping of csv columns to table columns, e.g. `name=country,pop=population`.
+    /// Only the mapped csv columns are inserted. If omitted, every csv column is inserted into the
+    /// table column with the same name as its header.
+    #[structopt(long, use_delimiter = true, parse(try_from_str = parse_column_mapping))]
+    columns: Vec<(String, String)>,
+    /// Delete all rows from the table before inserting the values of the csv file. Deleting and
+    /// inserting happens within one transaction, so the rows are kept if inserting fails.
+    #[structopt(long)]
+    truncate: bool,
     /// Name of the table to insert the values into. No precautions against SQL injection are
     /// taken.
     table: String,
 }
 
+#[derive(StructOpt)]
+struct ExecuteOpt {
+    #[structopt(flatten)]
+    connect_opts: ConnectOpts,
+    /// Path to the SQL script. Semicolons within string literals, quoted identifiers and comments
+    /// do not separate statements. Result sets created by the statements are discarded.
+    #[structopt(long, short = "f")]
+    sql_file: PathBuf,
+}
+
+#[derive(StructOpt)]
+struct CountOpt {
+    #[structopt(flatten)]
+    connect_opts: ConnectOpts,
+    /// Name of the table to count the rows of. May be qualified with schema and catalog names
+    /// separated by dots. Only letters, digits and underscores are accepted within names. Use
+    /// `--query` for anything else.
+    #[structopt(long, short = "t", conflicts_with = "query", required_unless = "query")]
+    table: Option<String>,
+    /// Count the rows in the result set of this query, rather than the rows of a table.
+    #[structopt(long, short = "q", conflicts_with = "table", required_unless = "table")]
+    query: Option<String>,
+}
+
 #[derive(StructOpt)]
 struct ListTablesOpt {
     #[structopt(flatten)]
@@ -221,6 +322,12 @@ fn main() -> Result<(), Error> {
             }
             insert(&environment, &insert_opt)?;
         }
+        Command::Execute { execute_opt } => {
+            execute(&environment, &execute_opt)?;
+        }
+        Command::Count { count_opt } => {
+            count(&environment, &count_opt)?;
+        }
         Command::ListTables { table_opt } => {
             tables(&environment, &table_opt)?;
         }
@@ -305,9 +412,78 @@ fn open_connection<'e>(
         bail!("Either DSN, connection string or prompt must be specified.")
     }
 
-    environment
-        .driver_connect(&cs, None, driver_completion)
-        .map_err(|e| e.into())
+    let mut completed_connection_string = OutputStringBuffer::with_buffer_size(1023);
+    let connection = environment.driver_connect(
+        &cs,
+        Some(&mut completed_connection_string),
+        driver_completion,
+    )?;
+    if completed_connection_string.is_truncated() {
+        // A truncated string may cut the password attribute in half, so it can not be redacted
+        // reliably.
+        warn!("Completed connection string has been truncated and is not logged.");
+    } else {
+        // Some drivers do not fill the buffer at all, so only log the string if there is one.
+        let completed_connection_string = completed_connection_string.to_utf8();
+        if !completed_connection_string.is_empty() {
+            info!(
+                "Completed connection string: {}",
+                redact_password(&completed_connection_string)
+            );
+        }
+    }
+    Ok(connection)
+}
+
+/// Replaces the value of the `PWD` or `Password` attribute within `connection_string` with `***`,
+/// so the connection string can be logged without revealing the password. Keys are compared case
+/// insensitive.
+fn redact_password(connection_string: &str) -> String {
+    let mut redacted = String::with_capacity(connection_string.len());
+    let mut rest = connection_string;
+    while let Some(equals) = rest.find('=') {
+        let key = &rest[..equals];
+        let value_and_rest = &rest[equals + 1..];
+        let value_len = attribute_value_len(value_and_rest);
+        redacted.push_str(key);
+        redacted.push('=');
+        let key_trimmed = key.trim();
+        if key_trimmed.eq_ignore_ascii_case("PWD") || key_trimmed.eq_ignore_ascii_case("Password") {
+            redacted.push_str("***");
+        } else {
+            redacted.push_str(&value_and_rest[..value_len]);
+        }
+        rest = &value_and_rest[value_len..];
+        match rest.strip_prefix(';') {
+            Some(next) => {
+                redacted.push(';');
+                rest = next;
+            }
+            None => break,
+        }
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+/// Length of the attribute value at the start of `text`. A value enclosed in braces may contain
+/// `;` and ends with the first `}` which is not escaped by doubling it.
+fn attribute_value_len(text: &str) -> usize {
+    if !text.starts_with('{') {
+        return text.find(';').unwrap_or(text.len());
+    }
+    let bytes = text.as_bytes();
+    let mut index = 1;
+    while index < bytes.len() {
+        if bytes[index] == b'}' {
+            if bytes.get(index + 1) != Some(&b'}') {
+                return index + 1;
+            }
+            index += 1;
+        }
+        index += 1;
+    }
+    text.len()
 }
 
 /// Execute a query and writes the result to csv.
@@ -315,10 +491,13 @@ fn fetch(environment: &Environment, opt: FetchOpt) -> Result<(), Error> {
     let FetchOpt {
         connect_opts,
         output,
+        schema_out,
         parameters,
+        named_parameters,
         query: query_literal,
         batch_size,
         max_str_len,
+        wide,
         sql_file,
     } = opt;
 
@@ -332,9 +511,12 @@ fn fetch(environment: &Environment, opt: FetchOpt) -> Result<(), Error> {
         connect_opts,
         batch_size,
         max_str_len,
+        wide,
         output,
+        schema_out,
         query: query_str,
         parameters,
+        named_parameters,
     };
 
     query(environment, &query_opt)
@@ -345,35 +527,75 @@ fn query(environment: &Environment, opt: &QueryOpt) -> Result<(), Error> {
     let QueryOpt {
         connect_opts,
         output,
+        schema_out,
         parameters,
+        named_parameters,
         query,
         batch_size,
         max_str_len,
+        wide,
     } = opt;
 
-    // If an output file has been specified write to it, otherwise use stdout instead.
+    // If an existing directory has been specified, write each result set to a file of its own
+    // within it. Otherwise only the first result set is written.
+    let output_dir = output.as_deref().filter(|path| path.is_dir());
+
+    // If an output file has been specified write to it, otherwise use stdout instead. Files within
+    // an output directory are only created once there is a result set to write into them.
     let hold_stdout; // Prolongs scope of `stdout()` so we can lock() it.
-    let out: Box<dyn Write> = if let Some(path) = output {
-        Box::new(File::create(path)?)
+    let out: Option<Box<dyn Write>> = if output_dir.is_some() {
+        None
+    } else if let Some(path) = output {
+        Some(Box::new(File::create(path)?))
     } else {
         hold_stdout = stdout();
-        Box::new(hold_stdout.lock())
+        Some(Box::new(hold_stdout.lock()))
     };
-    let mut writer = csv::Writer::from_writer(out);
 
     let connection = open_connection(environment, connect_opts)?;
 
+    let (query, parameters) = resolve_parameters(query, parameters, named_parameters)?;
+
     // Convert the input strings into parameters suitable to for use with ODBC.
     let params: Vec<_> = parameters
         .iter()
-        .map(|param| param.as_str().into_parameter())
+        .map(|param| param.into_parameter())
         .collect();
 
     // Execute the query as a one off, and pass the parameters.
-    match connection.execute(query, params.as_slice())? {
+    match connection.execute(&query, params.as_slice())? {
         Some(cursor) => {
-            // Write column names.
-            cursor_to_csv(cursor, &mut writer, *batch_size, *max_str_len)?;
+            if let Some(path) = schema_out {
+                write_schema(&cursor, path)?;
+            }
+            let write_csv = |cursor, writer: &mut csv::Writer<_>| {
+                if *wide {
+                    wide_cursor_to_csv(cursor, writer, *batch_size, *max_str_len)
+                } else {
+                    cursor_to_csv(cursor, writer, *batch_size, *max_str_len)
+                }
+            };
+            let out = match (out, output_dir) {
+                (Some(out), _) => out,
+                (None, Some(dir)) => Box::new(File::create(dir.join("result_0.csv"))?),
+                (None, None) => unreachable!("Output is only deferred for output directories."),
+            };
+            let mut writer = csv::Writer::from_writer(out);
+            let cursor = write_csv(cursor, &mut writer)?;
+            if let Some(dir) = output_dir {
+                // Result sets without any columns (e.g. row counts) are already skipped by
+                // `more_results`, so each file written holds an actual result set.
+                let mut maybe_cursor = cursor.more_results()?;
+                let mut index = 1;
+                while let Some(cursor) = maybe_cursor {
+                    let path = dir.join(format!("result_{}.csv", index));
+                    info!("Writing result set {} to {}.", index, path.display());
+                    let out: Box<dyn Write> = Box::new(File::create(path)?);
+                    let mut writer = csv::Writer::from_writer(out);
+                    maybe_cursor = write_csv(cursor, &mut writer)?.more_results()?;
+                    index += 1;
+                }
+            }
         }
         None => {
             eprintln!("Query came back empty (not even a schema has been returned). No output has been created.");
@@ -382,6 +604,63 @@ fn query(environment: &Environment, opt: &QueryOpt) -> Result<(), Error> {
     Ok(())
 }
 
+/// Parses a named parameter passed as `name=value`.
+fn parse_named_parameter(arg: &str) -> Result<(String, String), Error> {
+    match arg.split_once('=') {
+        Some((name, value)) => Ok((name.to_string(), value.to_string())),
+        None => bail!(
+            "Named parameter '{}' must be specified as `name=value`.",
+            arg
+        ),
+    }
+}
+
+fn parse_column_mapping(arg: &str) -> Result<(String, String), Error> {
+    match arg.split_once('=') {
+        Some((csv_column, table_column)) => Ok((csv_column.to_string(), table_column.to_string())),
+        None => bail!(
+            "Column mapping '{}' must be specified as `csv_column=table_column`.",
+            arg
+        ),
+    }
+}
+
+/// Rewrites named placeholders in `query` into positional ones, if any named parameters have been
+/// specified. Returns the query text to execute, together with the values of its positional
+/// parameters in order.
+fn resolve_parameters<'a>(
+    query: &str,
+    positional: &'a [String],
+    named: &'a [(String, String)],
+) -> Result<(String, Vec<&'a str>), Error> {
+    if named.is_empty() {
+        let values = positional.iter().map(String::as_str).collect();
+        return Ok((query.to_string(), values));
+    }
+
+    let (query, placeholders) = rewrite_named_placeholders(query);
+    let mut positional = positional.iter();
+    let values = placeholders
+        .iter()
+        .enumerate()
+        .map(|(index, placeholder)| match placeholder {
+            Some(name) => match named.iter().find(|(candidate, _)| candidate == name) {
+                Some((_, value)) => Ok(value.as_str()),
+                None => bail!("No value specified for named placeholder ':{}'.", name),
+            },
+            // Question marks still consume the positional parameters in order.
+            None => match positional.next() {
+                Some(value) => Ok(value.as_str()),
+                None => bail!(
+                    "No value specified for placeholder '?' (parameter {} of the query).",
+                    index + 1
+                ),
+            },
+        })
+        .collect::<Result<_, Error>>()?;
+    Ok((query, values))
+}
+
 /// Read the content of a csv and insert it into a table.
 fn insert(environment: &Environment, insert_opt: &InsertOpt) -> Result<(), Error> {
     let InsertOpt {
@@ -389,6 +668,10 @@ fn insert(environment: &Environment, insert_opt: &InsertOpt) -> Result<(), Error
         connect_opts,
         table,
         batch_size,
+        binary_columns,
+        binary_encoding,
+        columns,
+        truncate,
     } = insert_opt;
 
     // If an input file has been specified, read from it. Use stdin otherwise.
@@ -403,25 +686,58 @@ fn insert(environment: &Environment, insert_opt: &InsertOpt) -> Result<(), Error
     let connection = open_connection(environment, connect_opts)?;
 
     // Generate statement text from table name and headline
-    let headline = reader.byte_headers()?;
+    let headline = reader.byte_headers()?.clone();
     let column_names: Vec<&str> = headline
         .iter()
         .map(std::str::from_utf8)
         .collect::<Result<_, _>>()?;
-    let columns = column_names.join(", ");
-    let values = column_names
+    // Index of the csv field and name of the csv and table column for each inserted column.
+    let mapping: Vec<(usize, &str, &str)> = if columns.is_empty() {
+        column_names
+            .iter()
+            .enumerate()
+            .map(|(index, &name)| (index, name, name))
+            .collect()
+    } else {
+        columns
+            .iter()
+            .map(|(csv_column, table_column)| {
+                match column_names.iter().position(|&name| name == csv_column) {
+                    Some(index) => Ok((index, csv_column.as_str(), table_column.as_str())),
+                    None => bail!(
+                        "Column '{}' of the column mapping is not part of the csv headline.",
+                        csv_column
+                    ),
+                }
+            })
+            .collect::<Result<_, _>>()?
+    };
+    let table_columns = mapping
         .iter()
-        .map(|_| "?")
+        .map(|&(_, _, table_column)| table_column)
         .collect::<Vec<_>>()
         .join(", ");
-    let statement_text = format!("INSERT INTO {} ({}) VALUES ({});", table, columns, values);
+    let values = mapping.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+    let statement_text = format!(
+        "INSERT INTO {} ({}) VALUES ({});",
+        table, table_columns, values
+    );
     info!("Insert statement Text: {}", statement_text);
 
+    if *truncate {
+        // Delete and insert within one transaction, so the table is not left empty if inserting
+        // fails. Dropping the connection without committing rolls the transaction back.
+        connection.set_autocommit(false)?;
+        let delete_text = format!("DELETE FROM {};", table);
+        info!("Delete statement text: {}", delete_text);
+        connection.execute(&delete_text, ())?;
+    }
+
     let mut statement = connection.prepare(&statement_text)?;
 
     // Log column types.
     // Could get required buffer sizes from parameter description.
-    let _parameter_descriptions: Vec<_> = (1..=headline.len())
+    let _parameter_descriptions: Vec<_> = (1..=mapping.len())
         .map(|parameter_number| {
             statement
                 .describe_param(parameter_number as u16)
@@ -432,11 +748,38 @@ fn insert(environment: &Environment, insert_opt: &InsertOpt) -> Result<(), Error
         })
         .collect::<Result<_, _>>()?;
 
-    // Allocate buffer
-    let mut buffer = TextRowSet::from_max_str_lens(*batch_size, (0..headline.len()).map(|_| 0));
+    // Allocate buffer. Binary columns are bound as raw bytes, everything else as text. Start with
+    // the smallest possible element size, the buffers grow as longer values are appended.
+    for binary_column in binary_columns {
+        if !column_names.contains(&binary_column.as_str()) {
+            bail!(
+                "Binary column '{}' is not part of the csv headline.",
+                binary_column
+            );
+        }
+    }
+    let is_binary: Vec<bool> = mapping
+        .iter()
+        .map(|&(_, csv_column, _)| {
+            binary_columns
+                .iter()
+                .any(|binary_column| binary_column == csv_column)
+        })
+        .collect();
+    let descs = is_binary.iter().map(|&is_binary| BufferDescription {
+        nullable: true,
+        kind: if is_binary {
+            BufferKind::Binary { length: 1 }
+        } else {
+            BufferKind::Text { max_str_len: 0 }
+        },
+    });
+    let mut buffer = buffer_from_description(*batch_size, descs);
 
     // Used to log batch number
     let mut num_batch = 0;
+    // Total number of rows inserted, as reported by the driver. `None` if it does not report it.
+    let mut num_affected = Some(0);
 
     for try_record in reader.into_byte_records() {
         if buffer.num_rows() == *batch_size as usize {
@@ -444,6 +787,7 @@ fn insert(environment: &Environment, insert_opt: &InsertOpt) -> Result<(), Error
             // Batch is full. We need to send it to the data base and clear it, before we insert
             // more rows into it.
             statement.execute(&buffer)?;
+            num_affected = add_row_count(num_affected, statement.row_count()?);
             info!(
                 "Insert batch {} with {} rows into DB.",
                 num_batch, batch_size
@@ -452,18 +796,108 @@ fn insert(environment: &Environment, insert_opt: &InsertOpt) -> Result<(), Error
         }
 
         let record = try_record?;
-        buffer.append(
-            record
-                .iter()
-                .map(|field| if field.is_empty() { None } else { Some(field) }),
-        );
+        let index = buffer.num_rows();
+        buffer.set_num_rows(index + 1);
+        for (col_index, &(field_index, _, _)) in mapping.iter().enumerate() {
+            // Empty fields are inserted as NULL.
+            let field = &record[field_index];
+            let field = if field.is_empty() { None } else { Some(field) };
+            match buffer.column_mut(col_index) {
+                AnyColumnViewMut::Text(mut writer) => writer.append(index, field),
+                AnyColumnViewMut::Binary(mut writer) => {
+                    let bytes = field.map(|text| binary_encoding.decode(text)).transpose()?;
+                    writer.append(index, bytes.as_deref())
+                }
+                _ => unreachable!("Insert buffer only consists of text and binary columns."),
+            }
+        }
     }
 
-    // Insert the remainder of the buffer to the database. If buffer is empty nothing will be
-    // executed.
-    statement.execute(&buffer)?;
-    info!("Insert last batch with {} rows into DB.", batch_size);
+    // Insert the remainder of the buffer to the database. The buffer is empty if the input is, or
+    // if the number of records is a multiple of the batch size.
+    if buffer.num_rows() != 0 {
+        statement.execute(&buffer)?;
+        num_affected = add_row_count(num_affected, statement.row_count()?);
+        info!("Insert last batch with {} rows into DB.", buffer.num_rows());
+    }
+
+    if *truncate {
+        connection.commit()?;
+    }
+
+    match num_affected {
+        Some(num_affected) => eprintln!("Inserted {} rows.", num_affected),
+        None => eprintln!("The driver did not report the number of inserted rows."),
+    }
+
+    Ok(())
+}
+
+/// Adds the number of rows affected by one batch to the total. The total is `None` as soon as the
+/// driver did not report the row count for any batch.
+fn add_row_count(total: Option<usize>, batch: Option<usize>) -> Option<usize> {
+    Some(total? + batch?)
+}
+
+fn execute(environment: &Environment, execute_opt: &ExecuteOpt) -> Result<(), Error> {
+    let ExecuteOpt {
+        connect_opts,
+        sql_file,
+    } = execute_opt;
+
+    let script = read_to_string(sql_file)?;
+    let conn = open_connection(environment, connect_opts)?;
+    let num_statements = conn.execute_batch(&script)?;
+    info!("Executed {} statements.", num_statements);
+    Ok(())
+}
+
+fn count(environment: &Environment, count_opt: &CountOpt) -> Result<(), Error> {
+    let CountOpt {
+        connect_opts,
+        table,
+        query,
+    } = count_opt;
 
+    let statement_text = match (table, query) {
+        (Some(table), None) => {
+            check_table_name(table)?;
+            format!("SELECT COUNT(*) FROM {}", table)
+        }
+        // Microsoft SQL Server and MariaDB require a name for the derived table, yet some data
+        // sources (e.g. Oracle) do not accept `AS` in front of it, so the name is given bare.
+        (None, Some(query)) => format!(
+            "SELECT COUNT(*) FROM ({}) q",
+            query.trim_end().trim_end_matches(';')
+        ),
+        _ => bail!("Either table or query must be specified."),
+    };
+    info!("Count statement text: {}", statement_text);
+
+    let conn = open_connection(environment, connect_opts)?;
+    let mut cursor = match conn.execute(&statement_text, ())? {
+        Some(cursor) => cursor,
+        None => bail!("Count statement did not return a result set."),
+    };
+    // The count is read from the first column by position, the column is not named.
+    let count: i64 = cursor.fetch_one_scalar()?.unwrap_or(0);
+    println!("{}", count);
+    Ok(())
+}
+
+/// The table name is inserted into the statement text verbatim. Quoting rules differ between data
+/// sources, so rather than quoting it, we only accept names which do not require quoting at all.
+fn check_table_name(table: &str) -> Result<(), Error> {
+    let is_valid = table
+        .split('.')
+        .all(|name| !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_'));
+    if !is_valid {
+        bail!(
+            "Invalid table name '{}'. Only letters, digits and underscores are allowed in names. \
+            Use --query to count the rows of tables with other names.",
+            table
+        );
+    }
     Ok(())
 }
 
@@ -517,12 +951,12 @@ fn columns(environment: &Environment, columns_opt: &ListColumnsOpt) -> Result<()
     Ok(())
 }
 
-fn cursor_to_csv(
-    cursor: impl Cursor,
+fn cursor_to_csv<C: Cursor>(
+    cursor: C,
     writer: &mut csv::Writer<impl Write>,
     batch_size: usize,
     max_str_len: Option<usize>,
-) -> Result<(), Error> {
+) -> Result<C, Error> {
     let headline: Vec<String> = cursor.column_names()?.collect::<Result<_, _>>()?;
     writer.write_record(headline)?;
     let mut buffers = TextRowSet::for_cursor(batch_size, &cursor, max_str_len)?;
@@ -541,5 +975,106 @@ fn cursor_to_csv(
             writer.write_record(record)?;
         }
     }
+    writer.flush()?;
+    let (cursor, _buffers) = row_set_cursor.unbind()?;
+    Ok(cursor)
+}
+
+/// Like [`cursor_to_csv`], but fetches all columns as UTF-16 and converts them to UTF-8 in this
+/// application. This way no characters are lost, even if the system encoding used by the driver for
+/// narrow text is not UTF-8.
+fn wide_cursor_to_csv<C: Cursor>(
+    cursor: C,
+    writer: &mut csv::Writer<impl Write>,
+    batch_size: usize,
+    max_str_len: Option<usize>,
+) -> Result<C, Error> {
+    let headline: Vec<String> = cursor.column_names()?.collect::<Result<_, _>>()?;
+    writer.write_record(headline)?;
+    let num_cols: u16 = cursor.num_result_cols()?.try_into().unwrap();
+    let descriptions = (1..=num_cols)
+        .map(|col_index| {
+            let reported_len =
+                if let Some(encoded_len) = cursor.col_data_type(col_index)?.utf16_len() {
+                    encoded_len
+                } else {
+                    cursor.col_display_size(col_index)? as usize
+                };
+            let max_str_len = MaxStrLen::from(max_str_len).buffer_len(col_index, reported_len)?;
+            Ok(BufferDescription {
+                kind: BufferKind::WText { max_str_len },
+                nullable: true,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let buffer = buffer_from_description(batch_size, descriptions.into_iter());
+    let mut row_set_cursor = cursor.bind_buffer(buffer)?;
+    let mut num_batch = 0;
+    while let Some(buffer) = row_set_cursor.fetch()? {
+        num_batch += 1;
+        info!(
+            "Fetched batch {} with {} rows.",
+            num_batch,
+            buffer.num_rows()
+        );
+        let columns: Vec<Vec<String>> = (0..buffer.num_cols())
+            .map(|col_index| match buffer.column(col_index) {
+                AnyColumnView::WText(values) => values
+                    .map(|value| value.map(|text| text.to_string_lossy()).unwrap_or_default())
+                    .collect(),
+                _ => unreachable!("All columns are bound as wide text"),
+            })
+            .collect();
+        for row_index in 0..buffer.num_rows() {
+            let record = columns.iter().map(|column| &column[row_index]);
+            writer.write_record(record)?;
+        }
+    }
+    writer.flush()?;
+    let (cursor, _buffer) = row_set_cursor.unbind()?;
+    Ok(cursor)
+}
+
+/// Writes a JSON file describing the columns of the result set.
+fn write_schema(cursor: &impl Cursor, path: &Path) -> Result<(), Error> {
+    let num_cols = cursor.num_result_cols()?;
+    let mut columns = Vec::new();
+    let mut desc = ColumnDescription::default();
+    for column_number in 1..=(num_cols as u16) {
+        cursor.describe_col(column_number, &mut desc)?;
+        columns.push(json!({
+            "name": desc.name_to_string()?,
+            "data_type": desc.data_type_display(),
+            "column_size": desc.data_type.column_size(),
+            "decimal_digits": desc.data_type.decimal_digits(),
+            "nullability": format!("{:?}", desc.nullability),
+        }));
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut file, &columns)?;
+    writeln!(file)?;
+    file.flush()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::redact_password;
+
+    #[test]
+    fn redact_password_attributes() {
+        assert_eq!(
+            "Server=localhost;UID=SA;PWD=***;",
+            redact_password("Server=localhost;UID=SA;PWD={se;cr}}et};")
+        );
+        assert_eq!(
+            "Server=localhost;Password=***;",
+            redact_password("Server=localhost;Password=secret;")
+        );
+        assert_eq!(
+            "PASSWORD=***;Server=localhost",
+            redact_password("PASSWORD=secret;Server=localhost")
+        );
+        assert_eq!("pwd=***", redact_password("pwd=secret"));
+    }
+}
@@ -5,8 +5,9 @@ use std::{
 
 use assert_cmd::{assert::Assert, Command};
 use lazy_static::lazy_static;
-use odbc_api::{Connection, Environment};
-use tempfile::NamedTempFile;
+use odbc_api::{Connection, Cursor, Environment};
+use serde_json::{json, Value};
+use tempfile::{tempdir, NamedTempFile};
 
 const MSSQL: &str =
     "Driver={ODBC Driver 17 for SQL Server};Server=localhost;UID=SA;PWD=<YourStrong@Passw0rd>;";
@@ -250,6 +251,75 @@ fn placeholders() {
         .stdout(csv);
 }
 
+#[test]
+fn named_placeholders() {
+    let table_name = "OdbcsvNamedPlaceholders";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["INT", "INT"]).unwrap();
+    let insert = format!(
+        "INSERT INTO {}
+        (a, b)
+        Values
+        (5, 1),
+        (2, 5),
+        (3, 4);",
+        table_name
+    );
+    conn.execute(&insert, ()).unwrap();
+
+    let csv = "a,b\n\
+        5,1\n\
+        2,5\n\
+    ";
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "fetch",
+            "--connection-string",
+            MSSQL,
+            "--query",
+            &format!(
+                "SELECT a, b from {} where a = :x or b = :x ORDER BY id;",
+                table_name
+            ),
+            "--param",
+            "x=5",
+        ])
+        .assert()
+        .success()
+        .stdout(csv);
+}
+
+/// A question mark mixed with named placeholders must still be bound to a positional argument.
+#[test]
+fn named_placeholders_missing_positional_argument() {
+    let output = Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "fetch",
+            "--connection-string",
+            MSSQL,
+            "--query",
+            "SELECT :x, ?",
+            "--param",
+            "x=5",
+        ])
+        .assert()
+        .failure()
+        .get_output()
+        .stderr
+        .clone();
+
+    let stderr = String::from_utf8(output).unwrap();
+    assert!(
+        stderr.contains("placeholder '?' (parameter 2"),
+        "{}",
+        stderr
+    );
+}
+
 #[test]
 fn insert() {
     let csv = "country,population\n\
@@ -290,6 +360,199 @@ fn insert_with_nulls() {
     roundtrip(csv, "odbcsv_insert_with_nulls", 5).success();
 }
 
+/// Count the rows of a table, and of the result set of a query.
+#[test]
+fn count() {
+    let table_name = "OdbcsvCount";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["INT"]).unwrap();
+    conn.execute(
+        &format!("INSERT INTO {} (a) VALUES (1), (2), (3)", table_name),
+        (),
+    )
+    .unwrap();
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "count",
+            "--connection-string",
+            MSSQL,
+            "--table",
+            table_name,
+        ])
+        .assert()
+        .success()
+        .stdout("3\n");
+
+    let query = format!("SELECT a FROM {} WHERE a > 1;", table_name);
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "count",
+            "--connection-string",
+            MSSQL,
+            "--query",
+            &query,
+        ])
+        .assert()
+        .success()
+        .stdout("2\n");
+
+    // Table names are not quoted, so names which would require quoting are rejected.
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "count",
+            "--connection-string",
+            MSSQL,
+            "--table",
+            "OdbcsvCount; DROP TABLE OdbcsvCount",
+        ])
+        .assert()
+        .failure();
+}
+
+/// The number of inserted rows is reported on standard error.
+#[test]
+fn insert_reports_affected_rows() {
+    let table_name = "OdbcsvInsertReportsAffectedRows";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(255)", "BIGINT"]).unwrap();
+    let csv = "a,b\n\
+        Norway,5380000\n\
+        Germany,83500000\n\
+        USA,329000000\n\
+    ";
+
+    let output = Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "insert",
+            "--connection-string",
+            MSSQL,
+            "--batch-size",
+            "2",
+            table_name,
+        ])
+        .write_stdin(csv)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Inserted 3 rows."), "{}", stderr);
+}
+
+/// Insert a csv file whose headers do not match the column names of the table, replacing the rows
+/// already in the table.
+#[test]
+fn insert_with_column_mapping_and_truncate() {
+    let table_name = "OdbcsvInsertWithColumnMappingAndTruncate";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(255)", "BIGINT"]).unwrap();
+    conn.execute(
+        &format!("INSERT INTO {} (a, b) VALUES ('Old', 1)", table_name),
+        (),
+    )
+    .unwrap();
+    // Column `capital` is not mapped and therefore not inserted.
+    let csv = "population,country,capital\n\
+        5380000,Norway,Oslo\n\
+        83500000,Germany,Berlin\n\
+    ";
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "insert",
+            "--connection-string",
+            MSSQL,
+            "--columns",
+            "country=a,population=b",
+            "--truncate",
+            table_name,
+        ])
+        .write_stdin(csv)
+        .assert()
+        .success();
+
+    let query = format!("SELECT a, b FROM {} ORDER BY id", table_name);
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&["-vvvv", "query", "--connection-string", MSSQL, &query])
+        .assert()
+        .success()
+        .stdout("a,b\nNorway,5380000\nGermany,83500000\n");
+}
+
+/// If inserting fails, the rows deleted due to `--truncate` are restored.
+#[test]
+fn insert_with_truncate_rolls_back_on_error() {
+    let table_name = "OdbcsvInsertWithTruncateRollsBackOnError";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["BIGINT"]).unwrap();
+    conn.execute(&format!("INSERT INTO {} (a) VALUES (1)", table_name), ())
+        .unwrap();
+    let csv = "a\n2\nnot a number\n";
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "insert",
+            "--connection-string",
+            MSSQL,
+            "--truncate",
+            table_name,
+        ])
+        .write_stdin(csv)
+        .assert()
+        .failure();
+
+    let query = format!("SELECT a FROM {}", table_name);
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&["-vvvv", "query", "--connection-string", MSSQL, &query])
+        .assert()
+        .success()
+        .stdout("a\n1\n");
+}
+
+/// Mapping a column, which is not part of the csv headline, is an error.
+#[test]
+fn insert_with_column_mapping_missing_csv_column() {
+    let table_name = "OdbcsvInsertWithColumnMappingMissingCsvColumn";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(255)"]).unwrap();
+    let csv = "country\nNorway\n";
+
+    let output = Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "insert",
+            "--connection-string",
+            MSSQL,
+            "--columns",
+            "nation=a",
+            table_name,
+        ])
+        .write_stdin(csv)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Column 'nation' of the column mapping is not part of the csv headline."),
+        "{}",
+        stderr
+    );
+}
+
 /// An "optional" for list-drivers command. It checks for the existence of a "list-drivers.txt". If
 /// so it compares the output of the `list-drivers` command with the file content. This setup is
 /// intended to provide a test for dev container or CI setups there the installed drivers are
@@ -373,6 +636,40 @@ fn fetch_from_mssql() {
         .stdout(csv);
 }
 
+/// Characters outside of the system code page survive the round trip, if fetched as UTF-16.
+#[test]
+fn fetch_wide() {
+    let table_name = "OdbcsvFetchWide";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["NVARCHAR(50)"]).unwrap();
+    let insert = format!(
+        "INSERT INTO {} (a) VALUES (N'Ünïcødé 漢字 😀'), (NULL);",
+        table_name
+    );
+    conn.execute(&insert, ()).unwrap();
+
+    let csv = "a\nÜnïcødé 漢字 😀\n\"\"\n";
+
+    let query = format!("SELECT a FROM {} ORDER BY id", table_name);
+    let output = Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "fetch",
+            "--wide",
+            "--connection-string",
+            MSSQL,
+            "--query",
+            &query,
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(csv.as_bytes(), output.as_slice());
+}
+
 #[test]
 fn fetch_with_query_read_from_file() {
     // Fill Table with dummy data
@@ -417,3 +714,232 @@ fn fetch_with_query_read_from_file() {
         .success()
         .stdout(csv);
 }
+
+/// Execute a script mixing DDL and an insert with a semicolon inside a string literal.
+#[test]
+fn execute_sql_file() {
+    let table_name = "OdbcsvExecuteSqlFile";
+    let script = format!(
+        "DROP TABLE IF EXISTS {table};\n\
+        CREATE TABLE {table} (a VARCHAR(255));\n\
+        INSERT INTO {table} (a) VALUES ('one;two');\n",
+        table = table_name
+    );
+    let named = NamedTempFile::new().unwrap();
+    let path = named.into_temp_path();
+    fs::write(&path, script).unwrap();
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "execute",
+            "--connection-string",
+            MSSQL,
+            "--sql-file",
+            path.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let query = format!("SELECT a FROM {}", table_name);
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "fetch",
+            "--connection-string",
+            MSSQL,
+            "--query",
+            &query,
+        ])
+        .assert()
+        .success()
+        .stdout("a\none;two\n");
+}
+
+#[test]
+fn fetch_with_schema_out() {
+    let table_name = "OdbcsvFetchWithSchemaOut";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(255) NOT NULL", "INT"]).unwrap();
+
+    let named = NamedTempFile::new().unwrap();
+    let path = named.into_temp_path();
+
+    let query = format!("SELECT a, b from {}", table_name);
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "fetch",
+            "--connection-string",
+            MSSQL,
+            "--schema-out",
+            path.to_str().unwrap(),
+            "--query",
+            &query,
+        ])
+        .assert()
+        .success()
+        .stdout("a,b\n");
+
+    let expected = json!([
+        {
+            "name": "a",
+            "data_type": "VARCHAR(255)",
+            "column_size": 255,
+            "decimal_digits": 0,
+            "nullability": "NoNulls"
+        },
+        {
+            "name": "b",
+            "data_type": "INTEGER",
+            "column_size": 0,
+            "decimal_digits": 0,
+            "nullability": "Nullable"
+        }
+    ]);
+    let actual: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(expected, actual);
+}
+
+/// A stored procedure returning several result sets writes one file per result set, if the output
+/// is a directory.
+#[test]
+fn fetch_multiple_result_sets_into_directory() {
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    // The insert into the table variable creates a row count in between the result sets, which
+    // must not produce a file of its own.
+    conn.execute(
+        "CREATE OR ALTER PROCEDURE OdbcsvFetchMultipleResultSets AS \
+        BEGIN \
+            DECLARE @t TABLE (c INT); \
+            SELECT 1 AS a; \
+            INSERT INTO @t (c) VALUES (42); \
+            SELECT 'two' AS b, 3 AS c; \
+        END",
+        (),
+    )
+    .unwrap();
+
+    let dir = tempdir().unwrap();
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "fetch",
+            "--connection-string",
+            MSSQL,
+            "--output",
+            dir.path().to_str().unwrap(),
+            "--query",
+            "{CALL OdbcsvFetchMultipleResultSets}",
+        ])
+        .assert()
+        .success();
+
+    let first = fs::read_to_string(dir.path().join("result_0.csv")).unwrap();
+    assert_eq!("a\n1\n", first);
+    let second = fs::read_to_string(dir.path().join("result_1.csv")).unwrap();
+    assert_eq!("b,c\ntwo,3\n", second);
+    assert!(!dir.path().join("result_2.csv").exists());
+}
+
+/// A statement without any result set does not create a file in the output directory.
+#[test]
+fn fetch_without_result_set_into_output_directory() {
+    let table_name = "OdbcsvFetchWithoutResultSetIntoOutputDirectory";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["INT"]).unwrap();
+
+    let dir = tempdir().unwrap();
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "fetch",
+            "--connection-string",
+            MSSQL,
+            "--output",
+            dir.path().to_str().unwrap(),
+            "--query",
+            &format!("INSERT INTO {} (a) VALUES (42)", table_name),
+        ])
+        .assert()
+        .success();
+
+    assert!(!dir.path().join("result_0.csv").exists());
+}
+
+#[test]
+fn insert_binary_columns() {
+    let table_name = "OdbcsvInsertBinaryColumns";
+    let conn = ENV.connect_with_connection_string(MSSQL).unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(255)", "VARBINARY(50)"]).unwrap();
+
+    // Second blob exercises base64 padding, third row inserts NULL.
+    let csv = "a,b\n\
+        first,AAECA/7/\n\
+        second,SGVsbG8=\n\
+        third,\n\
+    ";
+
+    Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvvv",
+            "insert",
+            "--connection-string",
+            MSSQL,
+            "--binary-columns",
+            "b",
+            table_name,
+        ])
+        .write_stdin(csv)
+        .assert()
+        .success();
+
+    let query = format!("SELECT b FROM {} ORDER BY id", table_name);
+    let mut cursor = conn.execute(&query, ()).unwrap().unwrap();
+    let mut actual = Vec::new();
+    let mut buf = Vec::new();
+    while let Some(mut row) = cursor.next_row().unwrap() {
+        let is_not_null = row.get_binary(1, &mut buf).unwrap();
+        actual.push(if is_not_null { Some(buf.clone()) } else { None });
+    }
+    let expected = vec![
+        Some(vec![0u8, 1, 2, 3, 254, 255]),
+        Some(b"Hello".to_vec()),
+        None,
+    ];
+    assert_eq!(expected, actual);
+}
+
+/// The connection string completed by the driver is logged in verbose mode, without the password.
+#[test]
+fn log_completed_connection_string_without_password() {
+    let output = Command::cargo_bin("odbcsv")
+        .unwrap()
+        .args(&[
+            "-vvv",
+            "list-tables",
+            "--connection-string",
+            MSSQL,
+            "--name",
+            "OdbcsvTestLogCompletedConnectionString",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Completed connection string:"),
+        "{}",
+        stderr
+    );
+    assert!(!stderr.contains("<YourStrong@Passw0rd>"), "{}", stderr);
+}